@@ -0,0 +1,17 @@
+CREATE TYPE booking_actor AS ENUM ('system', 'writer', 'sponsor', 'admin', 'payment_provider');
+
+CREATE TABLE booking_events (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    booking_id      UUID NOT NULL REFERENCES bookings(id) ON DELETE CASCADE,
+
+    from_status     booking_status,
+    to_status       booking_status NOT NULL,
+
+    actor           booking_actor NOT NULL,
+    actor_user_id   UUID REFERENCES users(id) ON DELETE SET NULL,
+    reason          TEXT,
+
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_booking_events_booking ON booking_events(booking_id, created_at);
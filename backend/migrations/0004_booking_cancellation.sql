@@ -0,0 +1,11 @@
+ALTER TABLE writers
+    ADD COLUMN cancellation_window_days     INTEGER NOT NULL DEFAULT 7,
+    ADD COLUMN late_cancellation_refund_pct INTEGER NOT NULL DEFAULT 50;
+
+-- A cancelled, rejected or refunded booking must not keep the date reserved,
+-- and writers with more than one slot need several bookings per date.
+ALTER TABLE bookings DROP CONSTRAINT bookings_writer_id_slot_date_key;
+
+ALTER TABLE bookings
+    ADD COLUMN refunded_cents   INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN cancelled_at     TIMESTAMPTZ;
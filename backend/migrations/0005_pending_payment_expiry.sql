@@ -0,0 +1,8 @@
+ALTER TABLE bookings ADD COLUMN payment_expires_at TIMESTAMPTZ;
+
+UPDATE bookings
+SET payment_expires_at = created_at + INTERVAL '30 minutes'
+WHERE status = 'pending_payment';
+
+CREATE INDEX idx_bookings_pending_expiry ON bookings(payment_expires_at)
+    WHERE status = 'pending_payment';
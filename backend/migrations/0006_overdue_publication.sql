@@ -0,0 +1,8 @@
+-- Booking events that record a step without changing the booking status
+CREATE TYPE booking_event_type AS ENUM ('status_change', 'publication_reminder', 'publication_escalated');
+
+ALTER TABLE booking_events
+    ADD COLUMN event_type booking_event_type NOT NULL DEFAULT 'status_change';
+
+CREATE INDEX idx_bookings_awaiting_publication ON bookings(slot_date)
+    WHERE status = 'approved' AND published_at IS NULL;
@@ -0,0 +1,13 @@
+ALTER TYPE booking_status ADD VALUE IF NOT EXISTS 'changes_requested' AFTER 'paid';
+
+-- One row per round of writer feedback on the ad copy
+CREATE TABLE booking_change_requests (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    booking_id      UUID NOT NULL REFERENCES bookings(id) ON DELETE CASCADE,
+    requested_by    UUID REFERENCES users(id) ON DELETE SET NULL,
+    feedback        TEXT NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resubmitted_at  TIMESTAMPTZ
+);
+
+CREATE INDEX idx_booking_change_requests_booking ON booking_change_requests(booking_id, created_at);
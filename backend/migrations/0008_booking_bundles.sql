@@ -0,0 +1,4 @@
+-- Bookings paid for together in a single checkout share a bundle id
+ALTER TABLE bookings ADD COLUMN bundle_id UUID;
+
+CREATE INDEX idx_bookings_bundle ON bookings(bundle_id) WHERE bundle_id IS NOT NULL;
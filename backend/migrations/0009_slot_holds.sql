@@ -0,0 +1,24 @@
+CREATE TYPE slot_hold_status AS ENUM ('active', 'converted', 'released');
+
+-- A sponsor's temporary claim on a slot while they complete checkout. An active
+-- hold only counts against capacity until it expires.
+CREATE TABLE slot_holds (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    booking_id  UUID NOT NULL UNIQUE REFERENCES bookings(id) ON DELETE CASCADE,
+    writer_id   UUID NOT NULL REFERENCES writers(id) ON DELETE CASCADE,
+    sponsor_id  UUID NOT NULL REFERENCES sponsors(id) ON DELETE CASCADE,
+    slot_date   DATE NOT NULL,
+    status      slot_hold_status NOT NULL DEFAULT 'active',
+    expires_at  TIMESTAMPTZ NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    closed_at   TIMESTAMPTZ
+);
+
+CREATE INDEX idx_slot_holds_active ON slot_holds(writer_id, slot_date, expires_at)
+    WHERE status = 'active';
+
+-- Unpaid bookings keep their slot until their checkout expires
+INSERT INTO slot_holds (booking_id, writer_id, sponsor_id, slot_date, expires_at)
+SELECT id, writer_id, sponsor_id, slot_date, COALESCE(payment_expires_at, created_at + INTERVAL '30 minutes')
+FROM bookings
+WHERE status = 'pending_payment';
@@ -0,0 +1,29 @@
+ALTER TYPE booking_event_type ADD VALUE IF NOT EXISTS 'reschedule_proposed';
+ALTER TYPE booking_event_type ADD VALUE IF NOT EXISTS 'reschedule_accepted';
+ALTER TYPE booking_event_type ADD VALUE IF NOT EXISTS 'reschedule_declined';
+
+CREATE TYPE reschedule_status AS ENUM ('pending', 'accepted', 'declined', 'withdrawn');
+
+-- A proposal by the writer or the sponsor to move a booking to another date
+CREATE TABLE booking_reschedules (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    booking_id      UUID NOT NULL REFERENCES bookings(id) ON DELETE CASCADE,
+
+    proposed_by     booking_actor NOT NULL,
+    proposed_by_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    from_date       DATE NOT NULL,
+    to_date         DATE NOT NULL,
+    reason          TEXT,
+
+    status          reschedule_status NOT NULL DEFAULT 'pending',
+    responded_by_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    responded_at    TIMESTAMPTZ,
+
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_booking_reschedules_booking ON booking_reschedules(booking_id, created_at);
+
+-- Only one open proposal per booking
+CREATE UNIQUE INDEX idx_booking_reschedules_pending ON booking_reschedules(booking_id)
+    WHERE status = 'pending';
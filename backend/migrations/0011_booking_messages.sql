@@ -0,0 +1,19 @@
+-- Conversation between the writer and sponsor of a booking
+CREATE TABLE booking_messages (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    booking_id      UUID NOT NULL REFERENCES bookings(id) ON DELETE CASCADE,
+    sender_user_id  UUID REFERENCES users(id) ON DELETE SET NULL,
+    sender_role     booking_actor NOT NULL,
+    body            TEXT NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_booking_messages_booking ON booking_messages(booking_id, created_at);
+
+-- How far each participant has read a thread, for unread counts
+CREATE TABLE booking_message_reads (
+    booking_id    UUID NOT NULL REFERENCES bookings(id) ON DELETE CASCADE,
+    user_id       UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    last_read_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (booking_id, user_id)
+);
@@ -0,0 +1,4 @@
+-- Evidence the writer attaches when marking a booking as published
+ALTER TABLE bookings
+    ADD COLUMN published_url TEXT,
+    ADD COLUMN publication_proof_url TEXT;
@@ -0,0 +1,29 @@
+-- The newsletter's send schedule: the ISO weekdays it goes out on (1 = Monday),
+-- optionally only the nth of those weekdays in each month (-1 = the last one).
+-- 0 means every week.
+ALTER TABLE writers
+    ADD COLUMN issue_weekdays SMALLINT[] NOT NULL DEFAULT '{1}',
+    ADD COLUMN issue_week_of_month SMALLINT NOT NULL DEFAULT 0,
+    ADD COLUMN slots_per_issue INT NOT NULL DEFAULT 1;
+
+ALTER TABLE writers
+    ADD CONSTRAINT writers_issue_weekdays_check
+        CHECK (cardinality(issue_weekdays) > 0 AND issue_weekdays <@ '{1,2,3,4,5,6,7}'::SMALLINT[]),
+    ADD CONSTRAINT writers_issue_week_of_month_check
+        CHECK (issue_week_of_month BETWEEN -1 AND 4),
+    ADD CONSTRAINT writers_slots_per_issue_check CHECK (slots_per_issue > 0);
+
+-- Capacity used to be counted per date, so existing writers keep that per issue
+UPDATE writers SET slots_per_issue = GREATEST(slots_per_week, 1);
+
+-- and send on the weekday most of their bookings were for
+UPDATE writers w
+SET issue_weekdays = ARRAY[usual.isodow]
+FROM (
+    SELECT DISTINCT ON (writer_id)
+        writer_id, EXTRACT(ISODOW FROM slot_date)::SMALLINT AS isodow
+    FROM bookings
+    GROUP BY writer_id, isodow
+    ORDER BY writer_id, COUNT(*) DESC, isodow
+) usual
+WHERE usual.writer_id = w.id;
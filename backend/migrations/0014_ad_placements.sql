@@ -0,0 +1,35 @@
+-- The kinds of ad a newsletter sells (primary sponsor, secondary mention,
+-- classified line, ...), each with its own price, capacity and copy limits
+CREATE TABLE ad_placements (
+    id                   UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    writer_id            UUID NOT NULL REFERENCES writers(id) ON DELETE CASCADE,
+    name                 TEXT NOT NULL,
+    description          TEXT,
+    price_cents          INT NOT NULL CHECK (price_cents > 0),
+    currency             TEXT NOT NULL DEFAULT 'usd',
+    slots_per_issue      INT NOT NULL DEFAULT 1 CHECK (slots_per_issue > 0),
+    max_headline_length  INT NOT NULL DEFAULT 100 CHECK (max_headline_length > 0),
+    max_body_length      INT NOT NULL DEFAULT 500 CHECK (max_body_length > 0),
+    allows_image         BOOLEAN NOT NULL DEFAULT TRUE,
+    is_active            BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at           TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_ad_placements_writer ON ad_placements(writer_id, created_at);
+
+-- Every existing writer gets one placement matching what they sold so far
+INSERT INTO ad_placements (writer_id, name, price_cents, currency, slots_per_issue)
+SELECT id, 'Sponsored slot', price_per_slot, currency, slots_per_issue
+FROM writers;
+
+ALTER TABLE bookings ADD COLUMN placement_id UUID REFERENCES ad_placements(id);
+
+UPDATE bookings b
+SET placement_id = p.id
+FROM ad_placements p
+WHERE p.writer_id = b.writer_id;
+
+ALTER TABLE bookings ALTER COLUMN placement_id SET NOT NULL;
+
+CREATE INDEX idx_bookings_placement_slot ON bookings(placement_id, slot_date);
@@ -0,0 +1,27 @@
+-- Blackouts cover a range of days and may repeat, e.g. every year from Dec 20
+-- to Jan 3, or every other Friday. They replace the one-row-per-day table.
+CREATE TYPE blackout_recurrence AS ENUM ('once', 'weekly', 'yearly');
+
+CREATE TABLE blackouts (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    writer_id       UUID NOT NULL REFERENCES writers(id) ON DELETE CASCADE,
+    start_date      DATE NOT NULL,
+    end_date        DATE NOT NULL,
+    recurrence      blackout_recurrence NOT NULL DEFAULT 'once',
+    -- For weekly blackouts: repeat every n weeks, counted from start_date
+    interval_weeks  INT NOT NULL DEFAULT 1 CHECK (interval_weeks BETWEEN 1 AND 52),
+    repeat_until    DATE,
+    reason          VARCHAR(255),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CHECK (end_date >= start_date),
+    CHECK (repeat_until IS NULL OR repeat_until >= start_date)
+);
+
+CREATE INDEX idx_blackouts_writer ON blackouts(writer_id, start_date);
+
+INSERT INTO blackouts (writer_id, start_date, end_date, reason, created_at)
+SELECT writer_id, blocked_date, blocked_date, reason, created_at
+FROM blackout_dates;
+
+DROP TABLE blackout_dates;
@@ -0,0 +1,7 @@
+-- Secret subscription URLs for writers' iCalendar feeds. Only a hash of the
+-- token is kept; rotating the feed replaces it.
+CREATE TABLE calendar_feeds (
+    writer_id   UUID PRIMARY KEY REFERENCES writers(id) ON DELETE CASCADE,
+    token_hash  TEXT NOT NULL UNIQUE,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,4 @@
+-- IANA zone names (e.g. 'Australia/Sydney'). A writer's zone decides what
+-- "today" means for lead times and cut-offs on their newsletter.
+ALTER TABLE writers ADD COLUMN timezone TEXT NOT NULL DEFAULT 'UTC';
+ALTER TABLE sponsors ADD COLUMN timezone TEXT NOT NULL DEFAULT 'UTC';
@@ -0,0 +1,35 @@
+-- Adjustments to a placement's list price for particular dates. A fixed
+-- override for a date wins outright; otherwise the weekday multiplier and the
+-- lead time adjustment are applied to the list price in turn.
+CREATE TYPE pricing_rule_kind AS ENUM ('date_override', 'lead_time', 'weekday');
+
+CREATE TABLE pricing_rules (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    writer_id       UUID NOT NULL REFERENCES writers(id) ON DELETE CASCADE,
+    -- NULL applies the rule to every placement of the writer
+    placement_id    UUID REFERENCES ad_placements(id) ON DELETE CASCADE,
+    kind            pricing_rule_kind NOT NULL,
+    label           VARCHAR(100),
+
+    -- date_override
+    rule_date       DATE,
+    price_cents     INT CHECK (price_cents > 0),
+
+    -- lead_time: days between booking and issue, inclusive range
+    min_days_ahead  INT CHECK (min_days_ahead >= 0),
+    max_days_ahead  INT,
+    adjustment_pct  INT CHECK (adjustment_pct > -100),
+
+    -- weekday: ISO weekday, price multiplied by multiplier_pct / 100
+    weekday         SMALLINT CHECK (weekday BETWEEN 1 AND 7),
+    multiplier_pct  INT CHECK (multiplier_pct > 0),
+
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CHECK (kind <> 'date_override' OR (rule_date IS NOT NULL AND price_cents IS NOT NULL)),
+    CHECK (kind <> 'lead_time' OR (min_days_ahead IS NOT NULL AND adjustment_pct IS NOT NULL)),
+    CHECK (kind <> 'weekday' OR (weekday IS NOT NULL AND multiplier_pct IS NOT NULL)),
+    CHECK (max_days_ahead IS NULL OR max_days_ahead >= min_days_ahead)
+);
+
+CREATE INDEX idx_pricing_rules_writer ON pricing_rules(writer_id);
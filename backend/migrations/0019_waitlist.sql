@@ -0,0 +1,30 @@
+CREATE TYPE waitlist_status AS ENUM ('waiting', 'offered', 'claimed', 'expired', 'left');
+
+-- Sponsors queued for a sold-out issue. When a slot frees up the first sponsor
+-- in line is offered it: an outstanding offer counts against capacity like a
+-- slot hold until it is claimed by a booking or expires, at which point the
+-- offer moves on to the next sponsor.
+CREATE TABLE waitlist_entries (
+    id                  UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    writer_id           UUID NOT NULL REFERENCES writers(id) ON DELETE CASCADE,
+    placement_id        UUID NOT NULL REFERENCES ad_placements(id) ON DELETE CASCADE,
+    sponsor_id          UUID NOT NULL REFERENCES sponsors(id) ON DELETE CASCADE,
+    slot_date           DATE NOT NULL,
+    status              waitlist_status NOT NULL DEFAULT 'waiting',
+    offer_token_hash    TEXT UNIQUE,
+    offered_at          TIMESTAMPTZ,
+    offer_expires_at    TIMESTAMPTZ,
+    -- Set in the same transaction that inserts the booking, before the insert
+    claimed_booking_id  UUID REFERENCES bookings(id) ON DELETE SET NULL
+                        DEFERRABLE INITIALLY DEFERRED,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_waitlist_one_open_entry
+    ON waitlist_entries(sponsor_id, placement_id, slot_date)
+    WHERE status IN ('waiting', 'offered');
+
+CREATE INDEX idx_waitlist_queue
+    ON waitlist_entries(writer_id, placement_id, slot_date, created_at)
+    WHERE status IN ('waiting', 'offered');
@@ -0,0 +1,36 @@
+-- Slots claimed per placement and issue date, maintained in the same
+-- transactions that create and release claims, so availability is an indexed
+-- range read rather than a scan of the writer's bookings. A slot is claimed by
+-- a paid (or later) booking, an unreleased slot hold or an outstanding
+-- waitlist offer. A hold now counts until the reaper releases it, not only
+-- until it expires as migration 0009 describes.
+CREATE TABLE slot_inventory (
+    placement_id    UUID NOT NULL REFERENCES ad_placements(id) ON DELETE CASCADE,
+    slot_date       DATE NOT NULL,
+    writer_id       UUID NOT NULL REFERENCES writers(id) ON DELETE CASCADE,
+    -- The placement's slots per issue; the writer's per-issue and per-week caps
+    -- apply on top across placements
+    capacity        INT NOT NULL CHECK (capacity >= 0),
+    booked          INT NOT NULL DEFAULT 0 CHECK (booked >= 0),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (placement_id, slot_date)
+);
+
+CREATE INDEX idx_slot_inventory_writer_date ON slot_inventory(writer_id, slot_date);
+
+-- Holds past their expiry still count until the reaper releases them
+INSERT INTO slot_inventory (placement_id, slot_date, writer_id, capacity, booked)
+SELECT c.placement_id, c.slot_date, c.writer_id, p.slots_per_issue, COUNT(*)
+FROM (
+    SELECT placement_id, slot_date, writer_id FROM bookings
+    WHERE status NOT IN ('pending_payment', 'rejected', 'cancelled', 'refunded')
+    UNION ALL
+    SELECT b.placement_id, b.slot_date, b.writer_id FROM slot_holds h
+    JOIN bookings b ON b.id = h.booking_id
+    WHERE h.status = 'active'
+    UNION ALL
+    SELECT placement_id, slot_date, writer_id FROM waitlist_entries
+    WHERE status = 'offered'
+) c
+JOIN ad_placements p ON p.id = c.placement_id
+GROUP BY c.placement_id, c.slot_date, c.writer_id, p.slots_per_issue;
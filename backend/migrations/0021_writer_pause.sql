@@ -0,0 +1,10 @@
+-- Pause mode: while `paused`, issue dates between the optional bounds (both
+-- inclusive, open-ended when unset) are not offered to sponsors. A pause
+-- without an end stops sponsorships until the writer resumes.
+ALTER TABLE writers
+    ADD COLUMN paused BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN pause_starts_on DATE,
+    ADD COLUMN pause_ends_on DATE,
+    ADD CONSTRAINT writers_pause_range CHECK (
+        pause_starts_on IS NULL OR pause_ends_on IS NULL OR pause_ends_on >= pause_starts_on
+    );
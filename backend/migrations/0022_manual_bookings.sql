@@ -0,0 +1,16 @@
+-- Deals a writer closed off-platform, recorded so the slot is not sold twice.
+-- No payment runs through Adsloty, so there are no fees or payouts for them.
+CREATE TABLE manual_bookings (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    writer_id       UUID NOT NULL REFERENCES writers(id) ON DELETE CASCADE,
+    placement_id    UUID NOT NULL REFERENCES ad_placements(id),
+    slot_date       DATE NOT NULL,
+    sponsor_name    VARCHAR(255) NOT NULL,
+    -- What the sponsor paid the writer directly, when they want it tracked
+    amount_cents    INT CHECK (amount_cents >= 0),
+    currency        VARCHAR(3) NOT NULL,
+    notes           TEXT,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_manual_bookings_writer_date ON manual_bookings(writer_id, slot_date);
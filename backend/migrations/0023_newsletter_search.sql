@@ -0,0 +1,9 @@
+-- Full-text search for the public newsletter directory. The name weighs more
+-- than the description when ranking; queries must use the same expression.
+CREATE INDEX idx_writers_search ON writers USING GIN (
+    (setweight(to_tsvector('english', newsletter_name), 'A')
+     || setweight(to_tsvector('english', coalesce(description, '')), 'B'))
+);
+
+CREATE INDEX idx_ad_placements_writer_active_price
+    ON ad_placements(writer_id, price_cents) WHERE is_active;
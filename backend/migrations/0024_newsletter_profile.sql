@@ -0,0 +1,16 @@
+-- How sponsors find a newsletter: categories from a fixed taxonomy (kept in
+-- code), free-form tags, and who the audience is. Arrays are GIN indexed so
+-- the directory can filter on containment.
+ALTER TABLE writers
+    ADD COLUMN categories          TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN tags                TEXT[] NOT NULL DEFAULT '{}',
+    -- ISO 3166-1 alpha-2 codes
+    ADD COLUMN audience_countries  TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN audience_job_roles  TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN open_rate_pct       NUMERIC(5, 2) CHECK (open_rate_pct BETWEEN 0 AND 100);
+
+CREATE INDEX idx_writers_categories ON writers USING GIN (categories);
+CREATE INDEX idx_writers_tags ON writers USING GIN (tags);
+CREATE INDEX idx_writers_audience_countries ON writers USING GIN (audience_countries);
+CREATE INDEX idx_writers_audience_job_roles ON writers USING GIN (audience_job_roles);
+CREATE INDEX idx_writers_open_rate ON writers(open_rate_pct);
@@ -0,0 +1,13 @@
+-- Vanity slugs usable in place of a writer's id in URLs. Slugs a writer moves
+-- away from stay reserved to them, so old links keep redirecting.
+ALTER TABLE writers
+    ADD COLUMN slug VARCHAR(50) UNIQUE
+        CHECK (slug ~ '^[a-z0-9]+(-[a-z0-9]+)*$');
+
+CREATE TABLE writer_slug_history (
+    slug        VARCHAR(50) PRIMARY KEY,
+    writer_id   UUID NOT NULL REFERENCES writers(id) ON DELETE CASCADE,
+    retired_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_writer_slug_history_writer ON writer_slug_history(writer_id);
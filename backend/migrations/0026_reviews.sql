@@ -0,0 +1,24 @@
+-- Sponsor reviews of a newsletter, one per published booking. Hidden reviews
+-- are kept for the record but left out of listings and ratings.
+CREATE TABLE reviews (
+    id                  UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    booking_id          UUID NOT NULL UNIQUE REFERENCES bookings(id) ON DELETE CASCADE,
+    writer_id           UUID NOT NULL REFERENCES writers(id) ON DELETE CASCADE,
+    sponsor_id          UUID NOT NULL REFERENCES sponsors(id) ON DELETE CASCADE,
+    rating              SMALLINT NOT NULL CHECK (rating BETWEEN 1 AND 5),
+    body                TEXT NOT NULL,
+    -- How the placement performed, e.g. clicks or conversions
+    performance_note    TEXT,
+
+    writer_reply        TEXT,
+    replied_at          TIMESTAMPTZ,
+
+    hidden_at           TIMESTAMPTZ,
+    hidden_reason       TEXT,
+
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_reviews_writer_visible ON reviews(writer_id, created_at DESC)
+    WHERE hidden_at IS NULL;
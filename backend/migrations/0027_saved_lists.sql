@@ -0,0 +1,21 @@
+-- Sponsor-owned shortlists of newsletters, used to plan campaigns
+CREATE TABLE saved_lists (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    sponsor_id      UUID NOT NULL REFERENCES sponsors(id) ON DELETE CASCADE,
+    name            VARCHAR(100) NOT NULL,
+    description     TEXT,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (sponsor_id, name)
+);
+
+CREATE TABLE saved_list_members (
+    list_id         UUID NOT NULL REFERENCES saved_lists(id) ON DELETE CASCADE,
+    writer_id       UUID NOT NULL REFERENCES writers(id) ON DELETE CASCADE,
+    note            TEXT,
+    added_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (list_id, writer_id)
+);
+
+CREATE INDEX idx_saved_list_members_writer ON saved_list_members(writer_id);
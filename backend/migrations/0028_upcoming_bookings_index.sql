@@ -0,0 +1,5 @@
+-- Cover bookings awaiting revised copy, which upcoming-booking queries include
+DROP INDEX IF EXISTS idx_bookings_writer_upcoming;
+
+CREATE INDEX idx_bookings_writer_upcoming ON bookings(writer_id, slot_date, status)
+    WHERE status IN ('paid', 'changes_requested', 'approved', 'published');
@@ -0,0 +1,17 @@
+-- Active placement names are unique per newsletter, ignoring case. Clashes
+-- left by the earlier check-then-insert get a numbered suffix first.
+WITH ranked AS (
+    SELECT id, ROW_NUMBER() OVER (
+        PARTITION BY writer_id, LOWER(name) ORDER BY created_at, id
+    ) AS n
+    FROM ad_placements
+    WHERE is_active
+)
+UPDATE ad_placements p
+SET name = p.name || ' (' || ranked.n || ')'
+FROM ranked
+WHERE ranked.id = p.id AND ranked.n > 1;
+
+CREATE UNIQUE INDEX idx_ad_placements_writer_name
+    ON ad_placements(writer_id, LOWER(name))
+    WHERE is_active;
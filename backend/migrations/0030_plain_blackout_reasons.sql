@@ -0,0 +1,6 @@
+-- Blackout reasons are stored as plain text; undo the HTML escaping applied
+-- to them until now
+UPDATE blackouts
+SET reason = REPLACE(REPLACE(REPLACE(REPLACE(reason,
+        '&lt;', '<'), '&gt;', '>'), '&quot;', '"'), '&#x27;', '''')
+WHERE reason LIKE '%&%';
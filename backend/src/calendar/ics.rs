@@ -0,0 +1,342 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Serialize;
+
+use crate::models::BlackoutRecurrence;
+
+const DATE_FORMAT: &str = "%Y%m%d";
+
+/// An all-day event; `end_date` is the last day it covers, not the day after.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcsEvent {
+    pub uid: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub summary: String,
+    pub description: Option<String>,
+    pub recurrence: BlackoutRecurrence,
+    pub interval_weeks: i32,
+    pub repeat_until: Option<NaiveDate>,
+}
+
+pub fn write_calendar(name: &str, events: &[IcsEvent], generated_at: DateTime<Utc>) -> String {
+    let stamp = generated_at.format("%Y%m%dT%H%M%SZ").to_string();
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Adsloty//Newsletter calendar//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(name)),
+    ];
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", event.uid));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!(
+            "DTSTART;VALUE=DATE:{}",
+            event.start_date.format(DATE_FORMAT)
+        ));
+        lines.push(format!(
+            "DTEND;VALUE=DATE:{}",
+            (event.end_date + Duration::days(1)).format(DATE_FORMAT)
+        ));
+        if let Some(rule) = recurrence_rule(event) {
+            lines.push(format!("RRULE:{}", rule));
+        }
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+        if let Some(description) = &event.description {
+            lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+        lines.push("TRANSP:TRANSPARENT".to_string());
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+fn recurrence_rule(event: &IcsEvent) -> Option<String> {
+    let mut rule = match event.recurrence {
+        BlackoutRecurrence::Once => return None,
+        BlackoutRecurrence::Weekly => format!("FREQ=WEEKLY;INTERVAL={}", event.interval_weeks),
+        BlackoutRecurrence::Yearly => "FREQ=YEARLY".to_string(),
+    };
+    if let Some(until) = event.repeat_until {
+        rule.push_str(&format!(";UNTIL={}", until.format(DATE_FORMAT)));
+    }
+    Some(rule)
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+fn unescape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+/// RFC 5545 folds content lines longer than 75 octets
+fn fold_line(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + 8);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+    out
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SkippedEvent {
+    pub summary: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug, Default)]
+pub struct ParsedCalendar {
+    pub events: Vec<IcsEvent>,
+    pub skipped: Vec<SkippedEvent>,
+}
+
+/// Timed events and recurrence rules other than plain weekly or yearly ones are skipped.
+pub fn parse_all_day_events(input: &str) -> ParsedCalendar {
+    let mut parsed = ParsedCalendar::default();
+    let mut current: Option<Vec<(String, String)>> = None;
+
+    for line in unfold_lines(input) {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let name = name.to_ascii_uppercase();
+
+        match (name.as_str(), value.trim()) {
+            ("BEGIN", "VEVENT") => current = Some(Vec::new()),
+            ("END", "VEVENT") => {
+                if let Some(properties) = current.take() {
+                    match event_from_properties(&properties) {
+                        Ok(event) => parsed.events.push(event),
+                        Err(skipped) => parsed.skipped.push(skipped),
+                    }
+                }
+            }
+            _ => {
+                if let Some(properties) = current.as_mut() {
+                    properties.push((name, value.to_string()));
+                }
+            }
+        }
+    }
+
+    parsed
+}
+
+fn unfold_lines(input: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in input.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+fn event_from_properties(properties: &[(String, String)]) -> Result<IcsEvent, SkippedEvent> {
+    // Property names may carry parameters, e.g. DTSTART;VALUE=DATE
+    let find = |key: &str| {
+        properties
+            .iter()
+            .find(|(name, _)| name == key || name.starts_with(&format!("{};", key)))
+    };
+
+    let summary = find("SUMMARY").map(|(_, v)| unescape_text(v.trim()));
+    let skip = |reason: &str| SkippedEvent {
+        summary: summary.clone(),
+        reason: reason.to_string(),
+    };
+
+    let (_, start_value) = find("DTSTART").ok_or_else(|| skip("Event has no start date"))?;
+    let start_date =
+        parse_date(start_value).ok_or_else(|| skip("Only all-day events can be imported"))?;
+
+    // DTEND is exclusive; an all-day event without one lasts a single day
+    let end_date = match find("DTEND") {
+        Some((_, value)) => {
+            parse_date(value).ok_or_else(|| skip("Only all-day events can be imported"))?
+                - Duration::days(1)
+        }
+        None => start_date,
+    };
+    if end_date < start_date {
+        return Err(skip("Event ends before it starts"));
+    }
+
+    let (recurrence, interval_weeks, repeat_until) = match find("RRULE") {
+        None => (BlackoutRecurrence::Once, 1, None),
+        Some((_, rule)) => parse_rule(rule).ok_or_else(|| skip("Unsupported recurrence rule"))?,
+    };
+
+    Ok(IcsEvent {
+        uid: find("UID")
+            .map(|(_, v)| v.trim().to_string())
+            .unwrap_or_default(),
+        start_date,
+        end_date,
+        summary: summary.clone().unwrap_or_default(),
+        description: find("DESCRIPTION").map(|(_, v)| unescape_text(v.trim())),
+        recurrence,
+        interval_weeks,
+        repeat_until,
+    })
+}
+
+/// Parses a DATE value; DATE-TIME values (timed events) yield `None`.
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+    if value.len() != 8 {
+        return None;
+    }
+    NaiveDate::parse_from_str(value, DATE_FORMAT).ok()
+}
+
+fn parse_rule(rule: &str) -> Option<(BlackoutRecurrence, i32, Option<NaiveDate>)> {
+    let mut recurrence = None;
+    let mut interval = 1;
+    let mut until = None;
+
+    for part in rule.trim().split(';') {
+        let (key, value) = part.split_once('=')?;
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => {
+                recurrence = match value.to_ascii_uppercase().as_str() {
+                    "WEEKLY" => Some(BlackoutRecurrence::Weekly),
+                    "YEARLY" => Some(BlackoutRecurrence::Yearly),
+                    _ => return None,
+                }
+            }
+            "INTERVAL" => interval = value.parse().ok()?,
+            // UNTIL may be a date or a date-time; only the day matters here
+            "UNTIL" => until = Some(NaiveDate::parse_from_str(value.get(..8)?, DATE_FORMAT).ok()?),
+            "WKST" => {}
+            _ => return None,
+        }
+    }
+
+    let recurrence = recurrence?;
+    if recurrence == BlackoutRecurrence::Yearly && interval != 1 {
+        return None;
+    }
+    Some((recurrence, interval, until))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_write_calendar_all_day_event() {
+        let event = IcsEvent {
+            uid: "booking-1@adsloty".into(),
+            start_date: date("2025-03-04"),
+            end_date: date("2025-03-04"),
+            summary: "Sponsor: Acme, Inc.".into(),
+            description: None,
+            recurrence: BlackoutRecurrence::Once,
+            interval_weeks: 1,
+            repeat_until: None,
+        };
+        let ics = write_calendar("The Weekly", &[event], Utc::now());
+
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250304\r\n"));
+        assert!(ics.contains("DTEND;VALUE=DATE:20250305\r\n"));
+        assert!(ics.contains("SUMMARY:Sponsor: Acme\\, Inc.\r\n"));
+        assert!(ics.lines().all(|l| l.len() <= 75));
+    }
+
+    #[test]
+    fn test_long_lines_are_folded() {
+        let folded = fold_line(&"a".repeat(160));
+        assert_eq!(folded.split("\r\n").filter(|l| !l.is_empty()).count(), 3);
+        assert_eq!(unfold_lines(&folded)[0], "a".repeat(160));
+    }
+
+    #[test]
+    fn test_parse_all_day_events() {
+        let ics = "BEGIN:VCALENDAR\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:holiday\r\n\
+            SUMMARY:Winter\r\n  break\r\n\
+            DTSTART;VALUE=DATE:20251220\r\n\
+            DTEND;VALUE=DATE:20260104\r\n\
+            RRULE:FREQ=YEARLY\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            SUMMARY:Call\r\n\
+            DTSTART:20250304T100000Z\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            SUMMARY:Every day\r\n\
+            DTSTART;VALUE=DATE:20250304\r\n\
+            RRULE:FREQ=DAILY\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n";
+
+        let parsed = parse_all_day_events(ics);
+
+        assert_eq!(parsed.events.len(), 1);
+        let holiday = &parsed.events[0];
+        assert_eq!(holiday.summary, "Winter break");
+        assert_eq!(holiday.start_date, date("2025-12-20"));
+        assert_eq!(holiday.end_date, date("2026-01-03"));
+        assert_eq!(holiday.recurrence, BlackoutRecurrence::Yearly);
+
+        assert_eq!(parsed.skipped.len(), 2);
+        assert_eq!(
+            parsed.skipped[0].reason,
+            "Only all-day events can be imported"
+        );
+        assert_eq!(parsed.skipped[1].reason, "Unsupported recurrence rule");
+    }
+
+    #[test]
+    fn test_parse_every_other_week_until() {
+        assert_eq!(
+            parse_rule("FREQ=WEEKLY;INTERVAL=2;UNTIL=20251231T235959Z"),
+            Some((BlackoutRecurrence::Weekly, 2, Some(date("2025-12-31"))))
+        );
+    }
+}
@@ -0,0 +1,406 @@
+pub mod ics;
+
+use std::collections::HashMap;
+
+use chrono::{Datelike, Duration, Months, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::models::{AdPlacement, Blackout, BlackoutRecurrence, PublishingFrequency, Writer};
+
+/// Bounds the search so a schedule with no issue dates cannot loop forever
+const MAX_LOOKAHEAD_DAYS: usize = 5 * 366;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishingSchedule {
+    weekdays: Vec<u32>,
+    /// 0 = every week, 1-4 = only the nth of those weekdays in the month, -1 = the last
+    week_of_month: i16,
+}
+
+impl PublishingSchedule {
+    pub fn new(weekdays: &[i16], week_of_month: i16) -> Self {
+        Self {
+            weekdays: weekdays.iter().map(|&d| d as u32).collect(),
+            week_of_month,
+        }
+    }
+
+    pub fn for_writer(writer: &Writer) -> Self {
+        Self::new(&writer.issue_weekdays, writer.issue_week_of_month)
+    }
+
+    pub fn is_issue_date(&self, date: NaiveDate) -> bool {
+        if !self.weekdays.contains(&date.weekday().number_from_monday()) {
+            return false;
+        }
+
+        match self.week_of_month {
+            0 => true,
+            -1 => (date + Duration::days(7)).month() != date.month(),
+            nth => (date.day0() / 7 + 1) as i16 == nth,
+        }
+    }
+
+    pub fn issue_dates(&self, from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        from.iter_days()
+            .take_while(|date| *date <= to)
+            .filter(|date| self.is_issue_date(*date))
+            .collect()
+    }
+
+    pub fn frequency(&self) -> PublishingFrequency {
+        match (self.week_of_month, self.weekdays.len()) {
+            (0, n) if n >= 5 => PublishingFrequency::Daily,
+            (0, n) if n > 1 => PublishingFrequency::SeveralTimesAWeek,
+            (0, _) => PublishingFrequency::Weekly,
+            _ => PublishingFrequency::Monthly,
+        }
+    }
+
+    pub fn next_issue_dates(&self, from: NaiveDate, count: usize) -> Vec<NaiveDate> {
+        from.iter_days()
+            .take(MAX_LOOKAHEAD_DAYS)
+            .filter(|date| self.is_issue_date(*date))
+            .take(count)
+            .collect()
+    }
+}
+
+/// Unknown names fall back to UTC; they are validated when stored.
+pub fn timezone(name: &str) -> Tz {
+    name.parse().unwrap_or(Tz::UTC)
+}
+
+pub fn today_in(timezone_name: &str) -> NaiveDate {
+    Utc::now()
+        .with_timezone(&timezone(timezone_name))
+        .date_naive()
+}
+
+/// Adds when the day starts in the recipient's own zone if it differs from the writer's.
+pub fn format_writer_date(
+    date: NaiveDate,
+    writer_timezone: &str,
+    recipient_timezone: &str,
+) -> String {
+    let formatted = date.format("%A, %-d %B %Y").to_string();
+
+    let writer_tz = timezone(writer_timezone);
+    let recipient_tz = timezone(recipient_timezone);
+    if writer_tz == recipient_tz {
+        return formatted;
+    }
+
+    let Some(starts_at) = writer_tz
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default())
+        .earliest()
+    else {
+        return formatted;
+    };
+    let local = starts_at.with_timezone(&recipient_tz);
+
+    format!(
+        "{} ({} time, from {} {} your time)",
+        formatted,
+        writer_tz.name(),
+        local.format("%a %-d %b %H:%M"),
+        local.format("%Z")
+    )
+}
+
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TakenSlots {
+    pub all: HashMap<NaiveDate, i64>,
+    pub in_placement: HashMap<NaiveDate, i64>,
+    /// From the slot inventory; dates without one use the placement's current capacity
+    pub placement_capacity: HashMap<NaiveDate, i64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SlotCapacity {
+    pub per_issue: i32,
+    pub per_week: i32,
+    pub placement_per_issue: i32,
+}
+
+impl SlotCapacity {
+    pub fn new(writer: &Writer, placement: &AdPlacement) -> Self {
+        Self {
+            per_issue: writer.slots_per_issue,
+            per_week: writer.slots_per_week,
+            placement_per_issue: placement.slots_per_issue,
+        }
+    }
+
+    /// `taken` must cover the whole week of `date` for the weekly cap to hold.
+    pub fn remaining(&self, date: NaiveDate, taken: &TakenSlots) -> i32 {
+        let count = |map: &HashMap<NaiveDate, i64>, d: NaiveDate| map.get(&d).copied().unwrap_or(0);
+
+        let start = week_start(date);
+        let taken_in_week: i64 = (0..7)
+            .map(|i| count(&taken.all, start + Duration::days(i)))
+            .sum();
+
+        let placement_capacity = taken
+            .placement_capacity
+            .get(&date)
+            .copied()
+            .unwrap_or(self.placement_per_issue as i64);
+
+        let placement_left = placement_capacity - count(&taken.in_placement, date);
+        let issue_left = self.per_issue as i64 - count(&taken.all, date);
+        let week_left = self.per_week as i64 - taken_in_week;
+
+        placement_left.min(issue_left).min(week_left).max(0) as i32
+    }
+}
+
+pub fn blackout_covers(blackout: &Blackout, date: NaiveDate) -> bool {
+    if date < blackout.start_date {
+        return false;
+    }
+
+    let span = blackout.end_date - blackout.start_date;
+
+    match blackout.recurrence {
+        BlackoutRecurrence::Once => date <= blackout.end_date,
+        _ if blackout.repeat_until.is_some_and(|until| date > until) => false,
+        BlackoutRecurrence::Weekly => {
+            let cycle = 7 * blackout.interval_weeks.max(1) as i64;
+            (date - blackout.start_date).num_days() % cycle <= span.num_days()
+        }
+        BlackoutRecurrence::Yearly => {
+            // The occurrence starting this year, or one that began last year and
+            // runs over New Year
+            let years = (date.year() - blackout.start_date.year()) as u32;
+            [years.saturating_sub(1), years].into_iter().any(|n| {
+                blackout
+                    .start_date
+                    .checked_add_months(Months::new(12 * n))
+                    .is_some_and(|start| start <= date && date <= start + span)
+            })
+        }
+    }
+}
+
+pub fn is_blacked_out(blackouts: &[Blackout], date: NaiveDate) -> bool {
+    blackouts.iter().any(|b| blackout_covers(b, date))
+}
+
+pub fn pause_covers(
+    starts_on: Option<NaiveDate>,
+    ends_on: Option<NaiveDate>,
+    date: NaiveDate,
+) -> bool {
+    starts_on.is_none_or(|start| date >= start) && ends_on.is_none_or(|end| date <= end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_publishing_frequency() {
+        let frequency = |weekdays: &[i16], week_of_month| {
+            PublishingSchedule::new(weekdays, week_of_month).frequency()
+        };
+        assert_eq!(frequency(&[1, 2, 3, 4, 5], 0), PublishingFrequency::Daily);
+        assert_eq!(
+            frequency(&[2, 5], 0),
+            PublishingFrequency::SeveralTimesAWeek
+        );
+        assert_eq!(frequency(&[4], 0), PublishingFrequency::Weekly);
+        assert_eq!(frequency(&[1, 4], 1), PublishingFrequency::Monthly);
+        assert_eq!(frequency(&[5], -1), PublishingFrequency::Monthly);
+    }
+
+    #[test]
+    fn test_weekly_schedule_on_several_weekdays() {
+        // Tuesdays and Fridays
+        let schedule = PublishingSchedule::new(&[2, 5], 0);
+        assert_eq!(
+            schedule.issue_dates(date("2025-03-03"), date("2025-03-12")),
+            vec![date("2025-03-04"), date("2025-03-07"), date("2025-03-11")]
+        );
+    }
+
+    #[test]
+    fn test_first_monday_of_the_month() {
+        let schedule = PublishingSchedule::new(&[1], 1);
+        assert_eq!(
+            schedule.next_issue_dates(date("2025-03-04"), 3),
+            vec![date("2025-04-07"), date("2025-05-05"), date("2025-06-02")]
+        );
+    }
+
+    #[test]
+    fn test_last_friday_of_the_month() {
+        let schedule = PublishingSchedule::new(&[5], -1);
+        assert!(schedule.is_issue_date(date("2025-02-28")));
+        assert!(!schedule.is_issue_date(date("2025-02-21")));
+    }
+
+    #[test]
+    fn test_empty_schedule_has_no_issues() {
+        let schedule = PublishingSchedule::new(&[], 0);
+        assert!(schedule.next_issue_dates(date("2025-03-03"), 1).is_empty());
+    }
+
+    fn blackout(
+        start: &str,
+        end: &str,
+        recurrence: BlackoutRecurrence,
+        interval_weeks: i32,
+    ) -> Blackout {
+        Blackout {
+            id: uuid::Uuid::nil(),
+            writer_id: uuid::Uuid::nil(),
+            start_date: date(start),
+            end_date: date(end),
+            recurrence,
+            interval_weeks,
+            repeat_until: None,
+            reason: None,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_yearly_blackout_over_new_year() {
+        let holiday = blackout("2025-12-20", "2026-01-03", BlackoutRecurrence::Yearly, 1);
+        assert!(blackout_covers(&holiday, date("2025-12-24")));
+        assert!(blackout_covers(&holiday, date("2027-01-02")));
+        assert!(blackout_covers(&holiday, date("2028-12-20")));
+        assert!(!blackout_covers(&holiday, date("2027-01-04")));
+        assert!(!blackout_covers(&holiday, date("2025-01-02")));
+    }
+
+    #[test]
+    fn test_every_other_friday() {
+        let mut fridays = blackout("2025-03-07", "2025-03-07", BlackoutRecurrence::Weekly, 2);
+        assert!(blackout_covers(&fridays, date("2025-03-21")));
+        assert!(!blackout_covers(&fridays, date("2025-03-14")));
+        assert!(!blackout_covers(&fridays, date("2025-03-22")));
+
+        fridays.repeat_until = Some(date("2025-03-31"));
+        assert!(!blackout_covers(&fridays, date("2025-04-04")));
+    }
+
+    #[test]
+    fn test_one_off_range() {
+        let trip = blackout("2025-07-01", "2025-07-14", BlackoutRecurrence::Once, 1);
+        assert!(blackout_covers(&trip, date("2025-07-14")));
+        assert!(!blackout_covers(&trip, date("2026-07-01")));
+    }
+
+    #[test]
+    fn test_format_writer_date_across_zones() {
+        let day = date("2025-03-04");
+        assert_eq!(
+            format_writer_date(day, "Europe/Berlin", "Europe/Berlin"),
+            "Tuesday, 4 March 2025"
+        );
+        assert_eq!(
+            format_writer_date(day, "Australia/Sydney", "America/Los_Angeles"),
+            "Tuesday, 4 March 2025 (Australia/Sydney time, from Mon 3 Mar 05:00 PST your time)"
+        );
+    }
+
+    #[test]
+    fn test_weekly_cap_applies_across_issues() {
+        let capacity = SlotCapacity {
+            per_issue: 2,
+            per_week: 3,
+            placement_per_issue: 2,
+        };
+        let taken = TakenSlots {
+            all: HashMap::from([(date("2025-03-04"), 2)]),
+            in_placement: HashMap::from([(date("2025-03-04"), 2)]),
+            ..Default::default()
+        };
+
+        // Tuesday is full, Friday only has the one slot left in the week
+        assert_eq!(capacity.remaining(date("2025-03-04"), &taken), 0);
+        assert_eq!(capacity.remaining(date("2025-03-07"), &taken), 1);
+        // The following week starts fresh
+        assert_eq!(capacity.remaining(date("2025-03-11"), &taken), 2);
+    }
+
+    #[test]
+    fn test_bundle_dates_in_one_week_share_the_weekly_cap() {
+        let capacity = SlotCapacity {
+            per_issue: 1,
+            per_week: 1,
+            placement_per_issue: 1,
+        };
+        let mut taken = TakenSlots::default();
+        assert_eq!(capacity.remaining(date("2025-03-04"), &taken), 1);
+
+        // Claiming Tuesday's slot leaves nothing for Friday of the same bundle
+        taken.all.insert(date("2025-03-04"), 1);
+        taken.in_placement.insert(date("2025-03-04"), 1);
+        assert_eq!(capacity.remaining(date("2025-03-07"), &taken), 0);
+        assert_eq!(capacity.remaining(date("2025-03-11"), &taken), 1);
+    }
+
+    #[test]
+    fn test_placements_fill_up_independently() {
+        let capacity = SlotCapacity {
+            per_issue: 5,
+            per_week: 10,
+            placement_per_issue: 1,
+        };
+        // Another placement has two ads in the issue, this one has none
+        let taken = TakenSlots {
+            all: HashMap::from([(date("2025-03-04"), 2)]),
+            ..Default::default()
+        };
+        assert_eq!(capacity.remaining(date("2025-03-04"), &taken), 1);
+
+        let taken = TakenSlots {
+            all: HashMap::from([(date("2025-03-04"), 3)]),
+            in_placement: HashMap::from([(date("2025-03-04"), 1)]),
+            ..Default::default()
+        };
+        assert_eq!(capacity.remaining(date("2025-03-04"), &taken), 0);
+    }
+
+    #[test]
+    fn test_pause_covers_open_ended_ranges() {
+        let (start, end) = (date("2025-08-01"), date("2025-08-31"));
+
+        assert!(pause_covers(Some(start), Some(end), date("2025-08-01")));
+        assert!(pause_covers(Some(start), Some(end), date("2025-08-31")));
+        assert!(!pause_covers(Some(start), Some(end), date("2025-09-01")));
+
+        // Until further notice
+        assert!(pause_covers(Some(start), None, date("2030-01-01")));
+        assert!(!pause_covers(Some(start), None, date("2025-07-31")));
+        // Already under way, back in September
+        assert!(pause_covers(None, Some(end), date("2025-03-04")));
+    }
+
+    #[test]
+    fn test_recorded_placement_capacity_is_used() {
+        let capacity = SlotCapacity {
+            per_issue: 5,
+            per_week: 10,
+            placement_per_issue: 1,
+        };
+        let taken = TakenSlots {
+            all: HashMap::from([(date("2025-03-04"), 1)]),
+            in_placement: HashMap::from([(date("2025-03-04"), 1)]),
+            placement_capacity: HashMap::from([(date("2025-03-04"), 3)]),
+        };
+        assert_eq!(capacity.remaining(date("2025-03-04"), &taken), 2);
+        assert_eq!(capacity.remaining(date("2025-03-05"), &taken), 1);
+    }
+}
@@ -59,18 +59,24 @@ pub struct ServerConfig {
     pub host: String,
     pub port: u16,
     pub frontend_url: String,
+    /// Base of absolute links such as calendar feed URLs
+    pub public_url: String,
 }
 
 impl ServerConfig {
     pub fn from_env() -> Self {
+        let port = env::var("PORT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(3000);
+
         Self {
             host: env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
-            port: env::var("PORT")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(3000),
+            port,
             frontend_url: env::var("FRONTEND_URL")
                 .unwrap_or_else(|_| "http://localhost:5173".to_string()),
+            public_url: env::var("PUBLIC_URL")
+                .unwrap_or_else(|_| format!("http://localhost:{}", port)),
         }
     }
 
@@ -138,6 +144,49 @@ impl JwtConfig {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct BookingConfig {
+    pub checkout_expiry_minutes: i64,
+    pub reaper_interval_secs: u64,
+    /// Counted from the overdue reminder
+    pub overdue_escalation_days: i64,
+    /// Counted from the escalation
+    pub overdue_refund_days: i64,
+    pub overdue_check_interval_secs: u64,
+    pub waitlist_offer_hours: i64,
+}
+
+impl BookingConfig {
+    pub fn from_env() -> Self {
+        Self {
+            checkout_expiry_minutes: env::var("CHECKOUT_EXPIRY_MINUTES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(30),
+            reaper_interval_secs: env::var("BOOKING_REAPER_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(60),
+            overdue_escalation_days: env::var("OVERDUE_ESCALATION_DAYS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(2),
+            overdue_refund_days: env::var("OVERDUE_REFUND_DAYS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5),
+            overdue_check_interval_secs: env::var("OVERDUE_CHECK_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3600),
+            waitlist_offer_hours: env::var("WAITLIST_OFFER_HOURS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(24),
+        }
+    }
+}
+
 /// Application configuration (combines all configs)
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -146,6 +195,7 @@ pub struct Config {
     pub server: ServerConfig,
     pub cors: CorsConfig,
     pub jwt: JwtConfig,
+    pub bookings: BookingConfig,
 }
 
 impl Config {
@@ -160,6 +210,7 @@ impl Config {
             server: ServerConfig::from_env(),
             cors: CorsConfig::from_env(env),
             jwt: JwtConfig::from_env(env),
+            bookings: BookingConfig::from_env(),
         }
     }
 
@@ -0,0 +1,149 @@
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::models::{AdPlacement, CreateAdPlacement, UpdateAdPlacement};
+
+pub async fn create_placement(
+    conn: &mut PgConnection,
+    writer_id: Uuid,
+    input: &CreateAdPlacement,
+) -> Result<AdPlacement, sqlx::Error> {
+    sqlx::query_as!(
+        AdPlacement,
+        r#"
+        INSERT INTO ad_placements (writer_id, name, description, price_cents, currency,
+                                   slots_per_issue, max_headline_length, max_body_length, allows_image)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING *
+        "#,
+        writer_id,
+        input.name,
+        input.description,
+        input.price_cents,
+        input.currency,
+        input.slots_per_issue,
+        input.max_headline_length,
+        input.max_body_length,
+        input.allows_image
+    )
+    .fetch_one(conn)
+    .await
+}
+
+pub async fn get_placement_by_id(
+    pool: &PgPool,
+    id: Uuid,
+) -> Result<Option<AdPlacement>, sqlx::Error> {
+    sqlx::query_as!(AdPlacement, "SELECT * FROM ad_placements WHERE id = $1", id)
+        .fetch_optional(pool)
+        .await
+}
+
+pub async fn get_placement_by_id_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    id: Uuid,
+) -> Result<Option<AdPlacement>, sqlx::Error> {
+    sqlx::query_as!(AdPlacement, "SELECT * FROM ad_placements WHERE id = $1", id)
+        .fetch_optional(&mut **tx)
+        .await
+}
+
+pub async fn get_writer_placements(
+    pool: &PgPool,
+    writer_id: Uuid,
+    include_inactive: bool,
+) -> Result<Vec<AdPlacement>, sqlx::Error> {
+    sqlx::query_as!(
+        AdPlacement,
+        r#"
+        SELECT * FROM ad_placements
+        WHERE writer_id = $1 AND (is_active OR $2)
+        ORDER BY created_at, id
+        "#,
+        writer_id,
+        include_inactive
+    )
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn get_active_placements_for_writers(
+    pool: &PgPool,
+    writer_ids: &[Uuid],
+) -> Result<Vec<AdPlacement>, sqlx::Error> {
+    sqlx::query_as!(
+        AdPlacement,
+        r#"
+        SELECT * FROM ad_placements
+        WHERE writer_id = ANY($1) AND is_active
+        ORDER BY writer_id, created_at, id
+        "#,
+        writer_ids
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// The writer's oldest active placement.
+pub async fn get_default_placement(
+    pool: &PgPool,
+    writer_id: Uuid,
+) -> Result<Option<AdPlacement>, sqlx::Error> {
+    sqlx::query_as!(
+        AdPlacement,
+        r#"
+        SELECT * FROM ad_placements
+        WHERE writer_id = $1 AND is_active
+        ORDER BY created_at, id
+        LIMIT 1
+        "#,
+        writer_id
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn update_placement(
+    conn: &mut PgConnection,
+    id: Uuid,
+    input: &UpdateAdPlacement,
+) -> Result<AdPlacement, sqlx::Error> {
+    sqlx::query_as!(
+        AdPlacement,
+        r#"
+        UPDATE ad_placements
+        SET name = COALESCE($1, name),
+            description = COALESCE($2, description),
+            price_cents = COALESCE($3, price_cents),
+            slots_per_issue = COALESCE($4, slots_per_issue),
+            max_headline_length = COALESCE($5, max_headline_length),
+            max_body_length = COALESCE($6, max_body_length),
+            allows_image = COALESCE($7, allows_image),
+            is_active = COALESCE($8, is_active),
+            updated_at = NOW()
+        WHERE id = $9
+        RETURNING *
+        "#,
+        input.name,
+        input.description,
+        input.price_cents,
+        input.slots_per_issue,
+        input.max_headline_length,
+        input.max_body_length,
+        input.allows_image,
+        input.is_active,
+        id
+    )
+    .fetch_one(conn)
+    .await
+}
+
+pub async fn deactivate_placement(pool: &PgPool, id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE ad_placements SET is_active = FALSE, updated_at = NOW() WHERE id = $1",
+        id
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
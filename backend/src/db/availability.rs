@@ -1,75 +1,229 @@
-use chrono::NaiveDate;
-use sqlx::PgPool;
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDate};
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
-use crate::models::AvailableSlot;
+use crate::calendar::{self, PublishingSchedule, SlotCapacity, TakenSlots};
+use crate::db;
+use crate::models::{AdPlacement, AvailableSlot, Blackout, PricingRule, Writer};
+use crate::pricing;
+
+pub async fn slots_remaining(
+    conn: &mut PgConnection,
+    writer: &Writer,
+    placement: &AdPlacement,
+    slot_date: NaiveDate,
+) -> Result<i32, sqlx::Error> {
+    if !PublishingSchedule::for_writer(writer).is_issue_date(slot_date)
+        || writer.is_paused_on(slot_date)
+    {
+        return Ok(0);
+    }
+
+    let blackouts =
+        db::blackout::get_blackouts_between(conn, writer.id, slot_date, slot_date).await?;
+    if calendar::is_blacked_out(&blackouts, slot_date) {
+        return Ok(0);
+    }
+
+    let week_start = calendar::week_start(slot_date);
+    let taken = db::slot_inventory::get_taken_slots(
+        conn,
+        writer.id,
+        placement.id,
+        week_start,
+        week_start + Duration::days(6),
+    )
+    .await?;
+
+    Ok(SlotCapacity::new(writer, placement).remaining(slot_date, &taken))
+}
+
+fn bookable_issue_dates(writer: &Writer, weeks_ahead: i32) -> (NaiveDate, Vec<NaiveDate>) {
+    let today = calendar::today_in(&writer.timezone);
+    let from = today + Duration::days(writer.lead_time_days as i64);
+    let to = today + Duration::weeks(weeks_ahead as i64);
+
+    let issue_dates = PublishingSchedule::for_writer(writer)
+        .issue_dates(from, to)
+        .into_iter()
+        .filter(|date| !writer.is_paused_on(*date))
+        .collect();
+    (today, issue_dates)
+}
+
+/// Whole weeks, so dates at either end still see the full weekly count.
+fn inventory_range(issue_dates: &[NaiveDate]) -> Option<(NaiveDate, NaiveDate)> {
+    let (first, last) = (issue_dates.first()?, issue_dates.last()?);
+    Some((
+        calendar::week_start(*first),
+        calendar::week_start(*last) + Duration::days(6),
+    ))
+}
+
+fn open_slots(
+    writer: &Writer,
+    placement: &AdPlacement,
+    issue_dates: &[NaiveDate],
+    taken: &TakenSlots,
+    blackouts: &[Blackout],
+    rules: &[PricingRule],
+    today: NaiveDate,
+) -> Vec<AvailableSlot> {
+    let capacity = SlotCapacity::new(writer, placement);
+
+    issue_dates
+        .iter()
+        .copied()
+        .filter(|date| !calendar::is_blacked_out(blackouts, *date))
+        .map(|date| AvailableSlot {
+            available_date: date,
+            slots_remaining: capacity.remaining(date, taken),
+            price_cents: pricing::effective_price(placement, rules, date, today),
+        })
+        .filter(|slot| slot.slots_remaining > 0)
+        .collect()
+}
 
 pub async fn get_writer_availability(
     pool: &PgPool,
-    writer_id: Uuid,
+    writer: &Writer,
+    placement: &AdPlacement,
     weeks_ahead: i32,
 ) -> Result<Vec<AvailableSlot>, sqlx::Error> {
-    sqlx::query_as!(
-        AvailableSlot,
-        r#"
-        WITH date_range AS (
-            SELECT generate_series(
-                CURRENT_DATE + (SELECT lead_time_days FROM writers WHERE id = $1) * INTERVAL '1 day',
-                CURRENT_DATE + $2 * INTERVAL '1 week',
-                INTERVAL '1 week'
-            )::DATE AS week_start
+    let (today, issue_dates) = bookable_issue_dates(writer, weeks_ahead);
+    let Some((from, to)) = inventory_range(&issue_dates) else {
+        return Ok(Vec::new());
+    };
+
+    let mut conn = pool.acquire().await?;
+
+    let taken =
+        db::slot_inventory::get_taken_slots(&mut conn, writer.id, placement.id, from, to).await?;
+    let blackouts = db::blackout::get_blackouts_between(&mut conn, writer.id, from, to).await?;
+    let rules = db::pricing_rule::get_placement_rules(&mut conn, writer.id, placement.id).await?;
+
+    Ok(open_slots(
+        writer,
+        placement,
+        &issue_dates,
+        &taken,
+        &blackouts,
+        &rules,
+        today,
+    ))
+}
+
+#[derive(Debug)]
+pub struct PlacementAvailability {
+    pub placement: AdPlacement,
+    pub available_slots: Vec<AvailableSlot>,
+}
+
+/// One query per kind of data for all writers, not per writer.
+pub async fn get_availability_for_writers(
+    pool: &PgPool,
+    writers: &[Writer],
+    weeks_ahead: i32,
+) -> Result<HashMap<Uuid, Vec<PlacementAvailability>>, sqlx::Error> {
+    let writer_ids: Vec<Uuid> = writers.iter().map(|w| w.id).collect();
+    let schedules: HashMap<Uuid, (NaiveDate, Vec<NaiveDate>)> = writers
+        .iter()
+        .map(|w| (w.id, bookable_issue_dates(w, weeks_ahead)))
+        .collect();
+
+    let ranges: Vec<(NaiveDate, NaiveDate)> = schedules
+        .values()
+        .filter_map(|(_, dates)| inventory_range(dates))
+        .collect();
+
+    let placements = db::ad_placement::get_active_placements_for_writers(pool, &writer_ids).await?;
+
+    let mut conn = pool.acquire().await?;
+    // Without a bookable date there is nothing to look up, but the placements
+    // are still listed, with no open slots
+    let (usage, blackouts) = match (
+        ranges.iter().map(|r| r.0).min(),
+        ranges.iter().map(|r| r.1).max(),
+    ) {
+        (Some(from), Some(to)) => (
+            db::slot_inventory::get_slot_usage(&mut conn, &writer_ids, from, to).await?,
+            db::blackout::get_blackouts_for_writers(&mut conn, &writer_ids, from, to).await?,
         ),
-        booked_counts AS (
-            SELECT slot_date, COUNT(*)::INT AS booked
-            FROM bookings
-            WHERE writer_id = $1
-              AND status NOT IN ('rejected', 'cancelled', 'refunded')
-            GROUP BY slot_date
-        )
-        SELECT
-            d.week_start AS "available_date!",
-            (w.slots_per_week - COALESCE(bc.booked, 0))::INT AS "slots_remaining!"
-        FROM date_range d
-        CROSS JOIN writers w
-        LEFT JOIN booked_counts bc ON bc.slot_date = d.week_start
-        LEFT JOIN blackout_dates bl ON bl.writer_id = $1 AND bl.blocked_date = d.week_start
-        WHERE w.id = $1
-          AND bl.blocked_date IS NULL
-          AND (w.slots_per_week - COALESCE(bc.booked, 0)) > 0
-        ORDER BY d.week_start
-        "#,
-        writer_id,
-        weeks_ahead as f64
-    )
-    .fetch_all(pool)
-    .await
+        _ => (Vec::new(), Vec::new()),
+    };
+    let rules = db::pricing_rule::get_rules_for_writers(&mut conn, &writer_ids).await?;
+
+    let mut availability: HashMap<Uuid, Vec<PlacementAvailability>> = HashMap::new();
+    for writer in writers {
+        let (today, issue_dates) = &schedules[&writer.id];
+        let writer_usage: Vec<_> = usage.iter().filter(|u| u.writer_id == writer.id).collect();
+        let writer_blackouts: Vec<Blackout> = blackouts
+            .iter()
+            .filter(|b| b.writer_id == writer.id)
+            .cloned()
+            .collect();
+        let writer_rules: Vec<PricingRule> = rules
+            .iter()
+            .filter(|r| r.writer_id == writer.id)
+            .cloned()
+            .collect();
+
+        for placement in placements.iter().filter(|p| p.writer_id == writer.id) {
+            let taken = db::slot_inventory::taken_slots(writer_usage.iter().copied(), placement.id);
+            availability
+                .entry(writer.id)
+                .or_default()
+                .push(PlacementAvailability {
+                    placement: placement.clone(),
+                    available_slots: open_slots(
+                        writer,
+                        placement,
+                        issue_dates,
+                        &taken,
+                        &writer_blackouts,
+                        &writer_rules,
+                        *today,
+                    ),
+                });
+        }
+    }
+
+    Ok(availability)
+}
+
+pub async fn get_slot_prices(
+    pool: &PgPool,
+    writer: &Writer,
+    placement: &AdPlacement,
+    slot_dates: &[NaiveDate],
+) -> Result<Vec<i32>, sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    let rules = db::pricing_rule::get_placement_rules(&mut conn, writer.id, placement.id).await?;
+    let today = calendar::today_in(&writer.timezone);
+
+    Ok(slot_dates
+        .iter()
+        .map(|&date| pricing::effective_price(placement, &rules, date, today))
+        .collect())
+}
+
+pub async fn get_slots_remaining(
+    pool: &PgPool,
+    writer: &Writer,
+    placement: &AdPlacement,
+    slot_date: NaiveDate,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    slots_remaining(&mut conn, writer, placement, slot_date).await
 }
 
 pub async fn is_slot_available(
     pool: &PgPool,
-    writer_id: Uuid,
+    writer: &Writer,
+    placement: &AdPlacement,
     slot_date: NaiveDate,
 ) -> Result<bool, sqlx::Error> {
-    let result = sqlx::query!(
-        r#"
-        SELECT
-            CASE
-                WHEN EXISTS (SELECT 1 FROM blackout_dates WHERE writer_id = $1 AND blocked_date = $2) THEN FALSE
-                WHEN (
-                    SELECT slots_per_week FROM writers WHERE id = $1
-                ) > (
-                    SELECT COUNT(*) FROM bookings
-                    WHERE writer_id = $1 AND slot_date = $2
-                    AND status NOT IN ('rejected', 'cancelled', 'refunded')
-                ) THEN TRUE
-                ELSE FALSE
-            END AS "available!"
-        "#,
-        writer_id,
-        slot_date
-    )
-    .fetch_one(pool)
-    .await?;
-
-    Ok(result.available)
+    Ok(get_slots_remaining(pool, writer, placement, slot_date).await? > 0)
 }
@@ -1,53 +1,235 @@
 use chrono::NaiveDate;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
 use uuid::Uuid;
 
-use crate::models::{BlackoutDate, CreateBlackoutDate};
+use crate::calendar;
+use crate::models::{
+    Blackout, BlackoutRecurrence, BookingStatus, ConflictingBooking, CreateBlackout,
+};
 
-pub async fn create_blackout_date(
-    pool: &PgPool,
+pub async fn create_blackout(
+    conn: &mut PgConnection,
     writer_id: Uuid,
-    input: &CreateBlackoutDate,
-) -> Result<BlackoutDate, sqlx::Error> {
+    input: &CreateBlackout,
+) -> Result<Blackout, sqlx::Error> {
     sqlx::query_as!(
-        BlackoutDate,
+        Blackout,
         r#"
-        INSERT INTO blackout_dates (writer_id, blocked_date, reason)
-        VALUES ($1, $2, $3)
-        RETURNING *
+        INSERT INTO blackouts
+            (writer_id, start_date, end_date, recurrence, interval_weeks, repeat_until, reason)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id, writer_id, start_date, end_date,
+                  recurrence as "recurrence: BlackoutRecurrence",
+                  interval_weeks, repeat_until, reason, created_at
         "#,
         writer_id,
-        input.blocked_date,
+        input.start_date,
+        input.end_date.unwrap_or(input.start_date),
+        input.recurrence as BlackoutRecurrence,
+        input.interval_weeks,
+        input.repeat_until,
         input.reason
     )
-    .fetch_one(pool)
+    .fetch_one(conn)
+    .await
+}
+
+pub async fn get_writer_blackouts(
+    pool: &PgPool,
+    writer_id: Uuid,
+) -> Result<Vec<Blackout>, sqlx::Error> {
+    sqlx::query_as!(
+        Blackout,
+        r#"
+        SELECT id, writer_id, start_date, end_date,
+               recurrence as "recurrence: BlackoutRecurrence",
+               interval_weeks, repeat_until, reason, created_at
+        FROM blackouts
+        WHERE writer_id = $1
+        ORDER BY start_date, created_at
+        "#,
+        writer_id
+    )
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn get_blackouts_for_writers(
+    conn: &mut PgConnection,
+    writer_ids: &[Uuid],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<Blackout>, sqlx::Error> {
+    sqlx::query_as!(
+        Blackout,
+        r#"
+        SELECT id, writer_id, start_date, end_date,
+               recurrence as "recurrence: BlackoutRecurrence",
+               interval_weeks, repeat_until, reason, created_at
+        FROM blackouts
+        WHERE writer_id = ANY($1)
+          AND start_date <= $3
+          AND CASE recurrence
+                WHEN 'once' THEN end_date >= $2
+                ELSE repeat_until IS NULL OR repeat_until >= $2
+              END
+        "#,
+        writer_ids,
+        from,
+        to
+    )
+    .fetch_all(conn)
+    .await
+}
+
+/// Recurring ones still need expanding with `calendar::blackout_covers`.
+pub async fn get_blackouts_between(
+    conn: &mut PgConnection,
+    writer_id: Uuid,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<Blackout>, sqlx::Error> {
+    sqlx::query_as!(
+        Blackout,
+        r#"
+        SELECT id, writer_id, start_date, end_date,
+               recurrence as "recurrence: BlackoutRecurrence",
+               interval_weeks, repeat_until, reason, created_at
+        FROM blackouts
+        WHERE writer_id = $1
+          AND start_date <= $3
+          AND CASE recurrence
+                WHEN 'once' THEN end_date >= $2
+                ELSE repeat_until IS NULL OR repeat_until >= $2
+              END
+        "#,
+        writer_id,
+        from,
+        to
+    )
+    .fetch_all(conn)
     .await
 }
 
-pub async fn delete_blackout_date(
+pub async fn delete_blackout(
     pool: &PgPool,
     writer_id: Uuid,
-    blocked_date: NaiveDate,
+    id: Uuid,
 ) -> Result<bool, sqlx::Error> {
     let result = sqlx::query!(
-        "DELETE FROM blackout_dates WHERE writer_id = $1 AND blocked_date = $2",
-        writer_id,
-        blocked_date
+        "DELETE FROM blackouts WHERE id = $1 AND writer_id = $2",
+        id,
+        writer_id
     )
     .execute(pool)
     .await?;
     Ok(result.rows_affected() > 0)
 }
 
-pub async fn get_writer_blackout_dates(
-    pool: &PgPool,
+/// Trims or splits one-off blackouts; recurring ones can only be removed as a whole.
+pub async fn clear_blackout_range(
+    tx: &mut Transaction<'_, Postgres>,
     writer_id: Uuid,
-) -> Result<Vec<BlackoutDate>, sqlx::Error> {
-    sqlx::query_as!(
-        BlackoutDate,
-        "SELECT * FROM blackout_dates WHERE writer_id = $1 ORDER BY blocked_date",
-        writer_id
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<u64, sqlx::Error> {
+    let overlapping = sqlx::query!(
+        r#"
+        SELECT id, start_date, end_date, reason FROM blackouts
+        WHERE writer_id = $1 AND recurrence = 'once'
+          AND start_date <= $3 AND end_date >= $2
+        FOR UPDATE
+        "#,
+        writer_id,
+        from,
+        to
     )
-    .fetch_all(pool)
-    .await
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let day = chrono::Duration::days(1);
+
+    for blackout in &overlapping {
+        let keeps_head = blackout.start_date < from;
+        let keeps_tail = blackout.end_date > to;
+
+        match (keeps_head, keeps_tail) {
+            (false, false) => {
+                sqlx::query!("DELETE FROM blackouts WHERE id = $1", blackout.id)
+                    .execute(&mut **tx)
+                    .await?;
+            }
+            (true, _) => {
+                sqlx::query!(
+                    "UPDATE blackouts SET end_date = $2 WHERE id = $1",
+                    blackout.id,
+                    from - day
+                )
+                .execute(&mut **tx)
+                .await?;
+
+                if keeps_tail {
+                    sqlx::query!(
+                        r#"
+                        INSERT INTO blackouts (writer_id, start_date, end_date, reason)
+                        VALUES ($1, $2, $3, $4)
+                        "#,
+                        writer_id,
+                        to + day,
+                        blackout.end_date,
+                        blackout.reason
+                    )
+                    .execute(&mut **tx)
+                    .await?;
+                }
+            }
+            (false, true) => {
+                sqlx::query!(
+                    "UPDATE blackouts SET start_date = $2 WHERE id = $1",
+                    blackout.id,
+                    to + day
+                )
+                .execute(&mut **tx)
+                .await?;
+            }
+        }
+    }
+
+    Ok(overlapping.len() as u64)
+}
+
+pub async fn get_conflicting_bookings(
+    conn: &mut PgConnection,
+    blackout: &Blackout,
+) -> Result<Vec<ConflictingBooking>, sqlx::Error> {
+    let last_date = match blackout.recurrence {
+        BlackoutRecurrence::Once => Some(blackout.end_date),
+        _ => blackout.repeat_until,
+    };
+
+    let bookings = sqlx::query_as!(
+        ConflictingBooking,
+        r#"
+        SELECT b.id as booking_id, b.slot_date,
+               b.status as "status: BookingStatus",
+               s.company_name, b.ad_headline
+        FROM bookings b
+        JOIN sponsors s ON s.id = b.sponsor_id
+        WHERE b.writer_id = $1
+          AND b.status IN ('paid', 'changes_requested', 'approved')
+          AND b.slot_date >= $2
+          AND ($3::date IS NULL OR b.slot_date <= $3)
+        ORDER BY b.slot_date
+        "#,
+        blackout.writer_id,
+        blackout.start_date,
+        last_date
+    )
+    .fetch_all(conn)
+    .await?;
+
+    Ok(bookings
+        .into_iter()
+        .filter(|b| calendar::blackout_covers(blackout, b.slot_date))
+        .collect())
 }
@@ -0,0 +1,60 @@
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::models::BookingChangeRequest;
+
+pub async fn insert_change_request(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+    requested_by: Uuid,
+    feedback: &str,
+) -> Result<BookingChangeRequest, sqlx::Error> {
+    sqlx::query_as!(
+        BookingChangeRequest,
+        r#"
+        INSERT INTO booking_change_requests (booking_id, requested_by, feedback)
+        VALUES ($1, $2, $3)
+        RETURNING id, booking_id, requested_by, feedback, created_at, resubmitted_at
+        "#,
+        booking_id,
+        requested_by,
+        feedback
+    )
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub async fn mark_change_requests_resubmitted(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE booking_change_requests
+        SET resubmitted_at = NOW()
+        WHERE booking_id = $1 AND resubmitted_at IS NULL
+        "#,
+        booking_id
+    )
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+pub async fn get_change_requests(
+    pool: &PgPool,
+    booking_id: Uuid,
+) -> Result<Vec<BookingChangeRequest>, sqlx::Error> {
+    sqlx::query_as!(
+        BookingChangeRequest,
+        r#"
+        SELECT id, booking_id, requested_by, feedback, created_at, resubmitted_at
+        FROM booking_change_requests
+        WHERE booking_id = $1
+        ORDER BY created_at, id
+        "#,
+        booking_id
+    )
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,87 @@
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::models::{BookingActor, BookingEvent, BookingEventType, BookingStatus};
+
+pub struct NewBookingEvent<'a> {
+    pub booking_id: Uuid,
+    pub event_type: BookingEventType,
+    pub from_status: Option<BookingStatus>,
+    pub to_status: BookingStatus,
+    pub actor: BookingActor,
+    pub actor_user_id: Option<Uuid>,
+    pub reason: Option<&'a str>,
+}
+
+pub async fn insert_booking_event(
+    tx: &mut Transaction<'_, Postgres>,
+    event: &NewBookingEvent<'_>,
+) -> Result<BookingEvent, sqlx::Error> {
+    sqlx::query_as!(
+        BookingEvent,
+        r#"
+        INSERT INTO booking_events
+            (booking_id, event_type, from_status, to_status, actor, actor_user_id, reason)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id, booking_id,
+                  event_type as "event_type: BookingEventType",
+                  from_status as "from_status: BookingStatus",
+                  to_status as "to_status: BookingStatus",
+                  actor as "actor: BookingActor",
+                  actor_user_id, reason, created_at
+        "#,
+        event.booking_id,
+        event.event_type as BookingEventType,
+        event.from_status as Option<BookingStatus>,
+        event.to_status as BookingStatus,
+        event.actor as BookingActor,
+        event.actor_user_id,
+        event.reason
+    )
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub async fn was_cancelled_unpaid(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM booking_events
+            WHERE booking_id = $1
+              AND event_type = 'status_change'
+              AND from_status = 'pending_payment'
+              AND to_status = 'cancelled'
+              AND actor = 'system'
+        ) as "exists!"
+        "#,
+        booking_id
+    )
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub async fn get_booking_events(
+    pool: &PgPool,
+    booking_id: Uuid,
+) -> Result<Vec<BookingEvent>, sqlx::Error> {
+    sqlx::query_as!(
+        BookingEvent,
+        r#"
+        SELECT id, booking_id,
+               event_type as "event_type: BookingEventType",
+               from_status as "from_status: BookingStatus",
+               to_status as "to_status: BookingStatus",
+               actor as "actor: BookingActor",
+               actor_user_id, reason, created_at
+        FROM booking_events
+        WHERE booking_id = $1
+        ORDER BY created_at, id
+        "#,
+        booking_id
+    )
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::{BookingActor, BookingMessage};
+
+pub async fn insert_message(
+    pool: &PgPool,
+    booking_id: Uuid,
+    sender_user_id: Uuid,
+    sender_role: BookingActor,
+    body: &str,
+) -> Result<BookingMessage, sqlx::Error> {
+    sqlx::query_as!(
+        BookingMessage,
+        r#"
+        INSERT INTO booking_messages (booking_id, sender_user_id, sender_role, body)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, booking_id, sender_user_id,
+                  sender_role as "sender_role: BookingActor", body, created_at
+        "#,
+        booking_id,
+        sender_user_id,
+        sender_role as BookingActor,
+        body
+    )
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn get_messages(
+    pool: &PgPool,
+    booking_id: Uuid,
+) -> Result<Vec<BookingMessage>, sqlx::Error> {
+    sqlx::query_as!(
+        BookingMessage,
+        r#"
+        SELECT id, booking_id, sender_user_id,
+               sender_role as "sender_role: BookingActor", body, created_at
+        FROM booking_messages
+        WHERE booking_id = $1
+        ORDER BY created_at, id
+        "#,
+        booking_id
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Messages that arrived after `read_up_to` stay unread.
+pub async fn mark_read(
+    pool: &PgPool,
+    booking_id: Uuid,
+    user_id: Uuid,
+    read_up_to: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO booking_message_reads (booking_id, user_id, last_read_at)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (booking_id, user_id) DO UPDATE
+        SET last_read_at = GREATEST(booking_message_reads.last_read_at, EXCLUDED.last_read_at)
+        "#,
+        booking_id,
+        user_id,
+        read_up_to
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use chrono::NaiveDate;
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::models::{BookingActor, BookingReschedule, RescheduleStatus};
+
+pub async fn create_reschedule(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+    proposed_by: BookingActor,
+    proposed_by_user_id: Uuid,
+    from_date: NaiveDate,
+    to_date: NaiveDate,
+    reason: Option<&str>,
+) -> Result<BookingReschedule, sqlx::Error> {
+    sqlx::query_as!(
+        BookingReschedule,
+        r#"
+        INSERT INTO booking_reschedules
+            (booking_id, proposed_by, proposed_by_user_id, from_date, to_date, reason)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, booking_id,
+                  proposed_by as "proposed_by: BookingActor",
+                  proposed_by_user_id, from_date, to_date, reason,
+                  status as "status: RescheduleStatus",
+                  responded_by_user_id, responded_at, created_at
+        "#,
+        booking_id,
+        proposed_by as BookingActor,
+        proposed_by_user_id,
+        from_date,
+        to_date,
+        reason
+    )
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub async fn lock_pending_reschedule(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+) -> Result<Option<BookingReschedule>, sqlx::Error> {
+    sqlx::query_as!(
+        BookingReschedule,
+        r#"
+        SELECT id, booking_id,
+               proposed_by as "proposed_by: BookingActor",
+               proposed_by_user_id, from_date, to_date, reason,
+               status as "status: RescheduleStatus",
+               responded_by_user_id, responded_at, created_at
+        FROM booking_reschedules
+        WHERE booking_id = $1 AND status = 'pending'
+        FOR UPDATE
+        "#,
+        booking_id
+    )
+    .fetch_optional(&mut **tx)
+    .await
+}
+
+pub async fn resolve_reschedule(
+    tx: &mut Transaction<'_, Postgres>,
+    reschedule_id: Uuid,
+    status: RescheduleStatus,
+    responded_by_user_id: Uuid,
+) -> Result<BookingReschedule, sqlx::Error> {
+    sqlx::query_as!(
+        BookingReschedule,
+        r#"
+        UPDATE booking_reschedules
+        SET status = $2, responded_by_user_id = $3, responded_at = NOW()
+        WHERE id = $1
+        RETURNING id, booking_id,
+                  proposed_by as "proposed_by: BookingActor",
+                  proposed_by_user_id, from_date, to_date, reason,
+                  status as "status: RescheduleStatus",
+                  responded_by_user_id, responded_at, created_at
+        "#,
+        reschedule_id,
+        status as RescheduleStatus,
+        responded_by_user_id
+    )
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub async fn get_reschedules(
+    pool: &PgPool,
+    booking_id: Uuid,
+) -> Result<Vec<BookingReschedule>, sqlx::Error> {
+    sqlx::query_as!(
+        BookingReschedule,
+        r#"
+        SELECT id, booking_id,
+               proposed_by as "proposed_by: BookingActor",
+               proposed_by_user_id, from_date, to_date, reason,
+               status as "status: RescheduleStatus",
+               responded_by_user_id, responded_at, created_at
+        FROM booking_reschedules
+        WHERE booking_id = $1
+        ORDER BY created_at, id
+        "#,
+        booking_id
+    )
+    .fetch_all(pool)
+    .await
+}
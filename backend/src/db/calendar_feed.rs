@@ -0,0 +1,41 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub async fn set_feed_token(
+    pool: &PgPool,
+    writer_id: Uuid,
+    token_hash: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO calendar_feeds (writer_id, token_hash)
+        VALUES ($1, $2)
+        ON CONFLICT (writer_id) DO UPDATE
+        SET token_hash = EXCLUDED.token_hash, created_at = NOW()
+        "#,
+        writer_id,
+        token_hash
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn delete_feed_token(pool: &PgPool, writer_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM calendar_feeds WHERE writer_id = $1", writer_id)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn get_writer_id_by_token_hash(
+    pool: &PgPool,
+    token_hash: &str,
+) -> Result<Option<Uuid>, sqlx::Error> {
+    sqlx::query_scalar!(
+        "SELECT writer_id FROM calendar_feeds WHERE token_hash = $1",
+        token_hash
+    )
+    .fetch_optional(pool)
+    .await
+}
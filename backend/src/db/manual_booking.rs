@@ -0,0 +1,102 @@
+use chrono::NaiveDate;
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::db;
+use crate::models::{CreateManualBooking, ManualBooking};
+
+/// Callers must hold the week lock from `lock_slots_remaining_tx` and have checked there is room.
+pub async fn create_manual_booking_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    writer_id: Uuid,
+    placement_id: Uuid,
+    currency: &str,
+    input: &CreateManualBooking,
+) -> Result<ManualBooking, sqlx::Error> {
+    let booking = sqlx::query_as!(
+        ManualBooking,
+        r#"
+        WITH inserted AS (
+            INSERT INTO manual_bookings
+                (writer_id, placement_id, slot_date, sponsor_name, amount_cents, currency, notes)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING *
+        )
+        SELECT m.id, m.writer_id, m.placement_id, p.name as placement_name, m.slot_date,
+               m.sponsor_name, m.amount_cents, m.currency, m.notes, m.created_at
+        FROM inserted m
+        JOIN ad_placements p ON p.id = m.placement_id
+        "#,
+        writer_id,
+        placement_id,
+        input.slot_date,
+        input.sponsor_name,
+        input.amount_cents,
+        currency,
+        input.notes
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+
+    db::slot_inventory::adjust_tx(tx, writer_id, placement_id, input.slot_date, 1).await?;
+
+    Ok(booking)
+}
+
+pub async fn get_writer_manual_bookings(
+    pool: &PgPool,
+    writer_id: Uuid,
+    from: NaiveDate,
+) -> Result<Vec<ManualBooking>, sqlx::Error> {
+    sqlx::query_as!(
+        ManualBooking,
+        r#"
+        SELECT m.id, m.writer_id, m.placement_id, p.name as placement_name, m.slot_date,
+               m.sponsor_name, m.amount_cents, m.currency, m.notes, m.created_at
+        FROM manual_bookings m
+        JOIN ad_placements p ON p.id = m.placement_id
+        WHERE m.writer_id = $1 AND m.slot_date >= $2
+        ORDER BY m.slot_date, m.created_at
+        "#,
+        writer_id,
+        from
+    )
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn delete_manual_booking(
+    pool: &PgPool,
+    writer_id: Uuid,
+    booking_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let Some(deleted) = sqlx::query!(
+        r#"
+        DELETE FROM manual_bookings
+        WHERE id = $1 AND writer_id = $2
+        RETURNING placement_id, slot_date
+        "#,
+        booking_id,
+        writer_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    else {
+        return Ok(false);
+    };
+
+    db::slot_inventory::lock_week_tx(&mut tx, writer_id, deleted.slot_date).await?;
+    db::slot_inventory::adjust_tx(
+        &mut tx,
+        writer_id,
+        deleted.placement_id,
+        deleted.slot_date,
+        -1,
+    )
+    .await?;
+
+    tx.commit().await?;
+    Ok(true)
+}
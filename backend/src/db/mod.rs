@@ -1,13 +1,27 @@
 use sqlx::postgres::{PgPool, PgPoolOptions};
 use std::time::Duration;
 
+pub mod ad_placement;
 pub mod availability;
 pub mod blackout;
+pub mod booking_change_request;
+pub mod booking_event;
+pub mod booking_message;
+pub mod booking_reschedule;
+pub mod calendar_feed;
+pub mod manual_booking;
 pub mod payout;
+pub mod pricing_rule;
+pub mod review;
+pub mod saved_list;
+pub mod slot_hold;
+pub mod slot_inventory;
 pub mod sponsor;
 pub mod token;
 pub mod user;
+pub mod waitlist;
 pub mod writer;
+pub mod writer_slug;
 
 use crate::config::DatabaseConfig;
 pub type DbPool = PgPool;
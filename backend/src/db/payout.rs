@@ -103,12 +103,14 @@ pub async fn get_eligible_bookings_for_payout(
                status as "status: BookingStatus",
                amount_cents, platform_fee_cents, writer_payout_cents, currency,
                lemon_order_id,
-               created_at, paid_at, approved_at, rejected_at, published_at
+               created_at, paid_at, approved_at, rejected_at, published_at,
+               refunded_cents, cancelled_at, payment_expires_at, bundle_id,
+               published_url, publication_proof_url, placement_id
         FROM bookings
         WHERE writer_id = $1
-          AND status = 'published'
+          AND (status = 'published' OR (status = 'cancelled' AND writer_payout_cents > 0))
           AND id NOT IN (SELECT UNNEST(booking_ids) FROM payouts WHERE status IN ('processing', 'paid'))
-        ORDER BY published_at
+        ORDER BY COALESCE(published_at, cancelled_at)
         "#,
         writer_id
     )
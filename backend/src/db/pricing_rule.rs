@@ -0,0 +1,140 @@
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::models::{CreatePricingRule, PricingRule, PricingRuleKind};
+
+pub async fn create_pricing_rule(
+    pool: &PgPool,
+    writer_id: Uuid,
+    input: &CreatePricingRule,
+) -> Result<PricingRule, sqlx::Error> {
+    sqlx::query_as!(
+        PricingRule,
+        r#"
+        INSERT INTO pricing_rules
+            (writer_id, placement_id, kind, label, rule_date, price_cents,
+             min_days_ahead, max_days_ahead, adjustment_pct, weekday, multiplier_pct)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        RETURNING id, writer_id, placement_id, kind as "kind: PricingRuleKind", label,
+                  rule_date, price_cents, min_days_ahead, max_days_ahead, adjustment_pct,
+                  weekday, multiplier_pct, created_at
+        "#,
+        writer_id,
+        input.placement_id,
+        input.kind as PricingRuleKind,
+        input.label,
+        input.rule_date,
+        input.price_cents,
+        input.min_days_ahead,
+        input.max_days_ahead,
+        input.adjustment_pct,
+        input.weekday,
+        input.multiplier_pct
+    )
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn get_writer_pricing_rules(
+    pool: &PgPool,
+    writer_id: Uuid,
+) -> Result<Vec<PricingRule>, sqlx::Error> {
+    sqlx::query_as!(
+        PricingRule,
+        r#"
+        SELECT id, writer_id, placement_id, kind as "kind: PricingRuleKind", label,
+               rule_date, price_cents, min_days_ahead, max_days_ahead, adjustment_pct,
+               weekday, multiplier_pct, created_at
+        FROM pricing_rules
+        WHERE writer_id = $1
+        ORDER BY kind, rule_date, weekday, min_days_ahead, created_at
+        "#,
+        writer_id
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Includes the writer's rules for all placements.
+pub async fn get_placement_rules(
+    conn: &mut PgConnection,
+    writer_id: Uuid,
+    placement_id: Uuid,
+) -> Result<Vec<PricingRule>, sqlx::Error> {
+    sqlx::query_as!(
+        PricingRule,
+        r#"
+        SELECT id, writer_id, placement_id, kind as "kind: PricingRuleKind", label,
+               rule_date, price_cents, min_days_ahead, max_days_ahead, adjustment_pct,
+               weekday, multiplier_pct, created_at
+        FROM pricing_rules
+        WHERE writer_id = $1 AND (placement_id IS NULL OR placement_id = $2)
+        "#,
+        writer_id,
+        placement_id
+    )
+    .fetch_all(conn)
+    .await
+}
+
+pub async fn get_rules_for_writers(
+    conn: &mut PgConnection,
+    writer_ids: &[Uuid],
+) -> Result<Vec<PricingRule>, sqlx::Error> {
+    sqlx::query_as!(
+        PricingRule,
+        r#"
+        SELECT id, writer_id, placement_id, kind as "kind: PricingRuleKind", label,
+               rule_date, price_cents, min_days_ahead, max_days_ahead, adjustment_pct,
+               weekday, multiplier_pct, created_at
+        FROM pricing_rules
+        WHERE writer_id = ANY($1)
+        "#,
+        writer_ids
+    )
+    .fetch_all(conn)
+    .await
+}
+
+pub async fn has_duplicate_rule(
+    pool: &PgPool,
+    writer_id: Uuid,
+    input: &CreatePricingRule,
+) -> Result<bool, sqlx::Error> {
+    let exists = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM pricing_rules
+            WHERE writer_id = $1
+              AND placement_id IS NOT DISTINCT FROM $2
+              AND kind = $3
+              AND rule_date IS NOT DISTINCT FROM $4
+              AND weekday IS NOT DISTINCT FROM $5
+              AND kind <> 'lead_time'
+        ) as "exists!"
+        "#,
+        writer_id,
+        input.placement_id,
+        input.kind as PricingRuleKind,
+        input.rule_date,
+        input.weekday
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(exists)
+}
+
+pub async fn delete_pricing_rule(
+    pool: &PgPool,
+    writer_id: Uuid,
+    id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        "DELETE FROM pricing_rules WHERE id = $1 AND writer_id = $2",
+        id,
+        writer_id
+    )
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,166 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::{Booking, CreateReview, RatingSummary, Review};
+
+/// `None` if the booking was already reviewed.
+pub async fn create_review(
+    pool: &PgPool,
+    booking: &Booking,
+    input: &CreateReview,
+) -> Result<Option<Review>, sqlx::Error> {
+    sqlx::query_as!(
+        Review,
+        r#"
+        WITH inserted AS (
+            INSERT INTO reviews (booking_id, writer_id, sponsor_id, rating, body, performance_note)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (booking_id) DO NOTHING
+            RETURNING *
+        )
+        SELECT r.id, r.booking_id, r.writer_id, r.sponsor_id, s.company_name,
+               r.rating, r.body, r.performance_note, r.writer_reply, r.replied_at,
+               r.hidden_at, r.hidden_reason, r.created_at
+        FROM inserted r
+        JOIN sponsors s ON s.id = r.sponsor_id
+        "#,
+        booking.id,
+        booking.writer_id,
+        booking.sponsor_id,
+        input.rating,
+        input.body,
+        input.performance_note
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn get_writer_reviews(
+    pool: &PgPool,
+    writer_id: Uuid,
+    limit: i32,
+    offset: i32,
+) -> Result<Vec<Review>, sqlx::Error> {
+    sqlx::query_as!(
+        Review,
+        r#"
+        SELECT r.id, r.booking_id, r.writer_id, r.sponsor_id, s.company_name,
+               r.rating, r.body, r.performance_note, r.writer_reply, r.replied_at,
+               r.hidden_at, r.hidden_reason, r.created_at
+        FROM reviews r
+        JOIN sponsors s ON s.id = r.sponsor_id
+        WHERE r.writer_id = $1 AND r.hidden_at IS NULL
+        ORDER BY r.created_at DESC, r.id DESC
+        LIMIT $2 OFFSET $3
+        "#,
+        writer_id,
+        limit as i64,
+        offset as i64
+    )
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn get_rating_summary(
+    pool: &PgPool,
+    writer_id: Uuid,
+) -> Result<RatingSummary, sqlx::Error> {
+    sqlx::query_as!(
+        RatingSummary,
+        r#"
+        SELECT COUNT(*) as "review_count!", ROUND(AVG(rating), 2) as average_rating
+        FROM reviews
+        WHERE writer_id = $1 AND hidden_at IS NULL
+        "#,
+        writer_id
+    )
+    .fetch_one(pool)
+    .await
+}
+
+/// `None` if the review is not one of the writer's.
+pub async fn set_reply(
+    pool: &PgPool,
+    writer_id: Uuid,
+    review_id: Uuid,
+    reply: &str,
+) -> Result<Option<Review>, sqlx::Error> {
+    sqlx::query_as!(
+        Review,
+        r#"
+        WITH updated AS (
+            UPDATE reviews
+            SET writer_reply = $3, replied_at = NOW(), updated_at = NOW()
+            WHERE id = $2 AND writer_id = $1
+            RETURNING *
+        )
+        SELECT r.id, r.booking_id, r.writer_id, r.sponsor_id, s.company_name,
+               r.rating, r.body, r.performance_note, r.writer_reply, r.replied_at,
+               r.hidden_at, r.hidden_reason, r.created_at
+        FROM updated r
+        JOIN sponsors s ON s.id = r.sponsor_id
+        "#,
+        writer_id,
+        review_id,
+        reply
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn get_reviews_for_moderation(
+    pool: &PgPool,
+    hidden_only: bool,
+    limit: i32,
+    offset: i32,
+) -> Result<Vec<Review>, sqlx::Error> {
+    sqlx::query_as!(
+        Review,
+        r#"
+        SELECT r.id, r.booking_id, r.writer_id, r.sponsor_id, s.company_name,
+               r.rating, r.body, r.performance_note, r.writer_reply, r.replied_at,
+               r.hidden_at, r.hidden_reason, r.created_at
+        FROM reviews r
+        JOIN sponsors s ON s.id = r.sponsor_id
+        WHERE NOT $1 OR r.hidden_at IS NOT NULL
+        ORDER BY r.created_at DESC, r.id DESC
+        LIMIT $2 OFFSET $3
+        "#,
+        hidden_only,
+        limit as i64,
+        offset as i64
+    )
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn set_hidden(
+    pool: &PgPool,
+    review_id: Uuid,
+    hidden: bool,
+    reason: Option<&str>,
+) -> Result<Option<Review>, sqlx::Error> {
+    sqlx::query_as!(
+        Review,
+        r#"
+        WITH updated AS (
+            UPDATE reviews
+            SET hidden_at = CASE WHEN $2 THEN COALESCE(hidden_at, NOW()) END,
+                hidden_reason = CASE WHEN $2 THEN $3 END,
+                updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+        )
+        SELECT r.id, r.booking_id, r.writer_id, r.sponsor_id, s.company_name,
+               r.rating, r.body, r.performance_note, r.writer_reply, r.replied_at,
+               r.hidden_at, r.hidden_reason, r.created_at
+        FROM updated r
+        JOIN sponsors s ON s.id = r.sponsor_id
+        "#,
+        review_id,
+        hidden,
+        reason
+    )
+    .fetch_optional(pool)
+    .await
+}
@@ -0,0 +1,209 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::models::{
+    CreateSavedList, RatingSummary, SavedList, SavedListMember, UpdateSavedList, Writer,
+};
+
+pub async fn get_sponsor_lists(
+    pool: &PgPool,
+    sponsor_id: Uuid,
+) -> Result<Vec<SavedList>, sqlx::Error> {
+    sqlx::query_as!(
+        SavedList,
+        r#"
+        SELECT l.id, l.sponsor_id, l.name, l.description,
+               (SELECT COUNT(*) FROM saved_list_members m WHERE m.list_id = l.id) as "member_count!",
+               l.created_at, l.updated_at
+        FROM saved_lists l
+        WHERE l.sponsor_id = $1
+        ORDER BY l.updated_at DESC, l.id
+        "#,
+        sponsor_id
+    )
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn get_saved_list_by_id(
+    pool: &PgPool,
+    id: Uuid,
+) -> Result<Option<SavedList>, sqlx::Error> {
+    sqlx::query_as!(
+        SavedList,
+        r#"
+        SELECT l.id, l.sponsor_id, l.name, l.description,
+               (SELECT COUNT(*) FROM saved_list_members m WHERE m.list_id = l.id) as "member_count!",
+               l.created_at, l.updated_at
+        FROM saved_lists l
+        WHERE l.id = $1
+        "#,
+        id
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn create_saved_list(
+    pool: &PgPool,
+    sponsor_id: Uuid,
+    input: &CreateSavedList,
+) -> Result<SavedList, sqlx::Error> {
+    sqlx::query_as!(
+        SavedList,
+        r#"
+        INSERT INTO saved_lists (sponsor_id, name, description)
+        VALUES ($1, $2, $3)
+        RETURNING id, sponsor_id, name, description, 0::int8 as "member_count!",
+                  created_at, updated_at
+        "#,
+        sponsor_id,
+        input.name,
+        input.description
+    )
+    .fetch_one(pool)
+    .await
+}
+
+/// `None` leaves a field as it is; an empty description clears it.
+pub async fn update_saved_list(
+    pool: &PgPool,
+    id: Uuid,
+    input: &UpdateSavedList,
+) -> Result<SavedList, sqlx::Error> {
+    sqlx::query_as!(
+        SavedList,
+        r#"
+        UPDATE saved_lists l
+        SET name = COALESCE($2, name),
+            description = CASE WHEN $3::text IS NULL THEN description ELSE NULLIF($3, '') END,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, sponsor_id, name, description,
+                  (SELECT COUNT(*) FROM saved_list_members m WHERE m.list_id = l.id) as "member_count!",
+                  created_at, updated_at
+        "#,
+        id,
+        input.name,
+        input.description
+    )
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn delete_saved_list(pool: &PgPool, id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM saved_lists WHERE id = $1", id)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn is_member(pool: &PgPool, list_id: Uuid, writer_id: Uuid) -> Result<bool, sqlx::Error> {
+    let exists = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM saved_list_members WHERE list_id = $1 AND writer_id = $2
+        ) as "exists!"
+        "#,
+        list_id,
+        writer_id
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(exists)
+}
+
+pub async fn save_member(
+    pool: &PgPool,
+    list_id: Uuid,
+    writer_id: Uuid,
+    note: Option<&str>,
+) -> Result<SavedListMember, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let member = sqlx::query_as!(
+        SavedListMember,
+        r#"
+        INSERT INTO saved_list_members (list_id, writer_id, note)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (list_id, writer_id)
+        DO UPDATE SET note = EXCLUDED.note, updated_at = NOW()
+        RETURNING list_id, writer_id, note, added_at
+        "#,
+        list_id,
+        writer_id,
+        note
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    touch_list(&mut tx, list_id).await?;
+    tx.commit().await?;
+
+    Ok(member)
+}
+
+pub async fn remove_member(
+    pool: &PgPool,
+    list_id: Uuid,
+    writer_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let result = sqlx::query!(
+        "DELETE FROM saved_list_members WHERE list_id = $1 AND writer_id = $2",
+        list_id,
+        writer_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    touch_list(&mut tx, list_id).await?;
+    tx.commit().await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+async fn touch_list(tx: &mut Transaction<'_, Postgres>, list_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE saved_lists SET updated_at = NOW() WHERE id = $1",
+        list_id
+    )
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+#[derive(Debug, FromRow)]
+pub struct SavedListMemberRow {
+    #[sqlx(flatten)]
+    pub writer: Writer,
+    #[sqlx(flatten)]
+    pub rating: RatingSummary,
+    pub note: Option<String>,
+    pub added_at: DateTime<Utc>,
+}
+
+pub async fn get_list_members(
+    pool: &PgPool,
+    list_id: Uuid,
+) -> Result<Vec<SavedListMemberRow>, sqlx::Error> {
+    sqlx::query_as::<_, SavedListMemberRow>(
+        r#"
+        SELECT w.*, rv.review_count, rv.average_rating, m.note, m.added_at
+        FROM saved_list_members m
+        JOIN writers w ON w.id = m.writer_id
+        CROSS JOIN LATERAL (
+            SELECT COUNT(*) as review_count, ROUND(AVG(rating), 2) as average_rating
+            FROM reviews
+            WHERE writer_id = w.id AND hidden_at IS NULL
+        ) rv
+        WHERE m.list_id = $1
+        ORDER BY m.added_at, w.id
+        "#,
+    )
+    .bind(list_id)
+    .fetch_all(pool)
+    .await
+}
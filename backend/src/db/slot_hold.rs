@@ -0,0 +1,101 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::db;
+use crate::models::SlotHoldStatus;
+
+pub async fn create_hold(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+    writer_id: Uuid,
+    sponsor_id: Uuid,
+    slot_date: NaiveDate,
+    expires_at: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO slot_holds (booking_id, writer_id, sponsor_id, slot_date, expires_at)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+        booking_id,
+        writer_id,
+        sponsor_id,
+        slot_date,
+        expires_at
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    db::slot_inventory::adjust_for_booking_tx(tx, booking_id, 1).await
+}
+
+/// A hold keeps its slot past expiry until the reaper releases it.
+pub async fn has_active_hold(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM slot_holds
+            WHERE booking_id = $1 AND status = 'active'
+        ) as "exists!"
+        "#,
+        booking_id
+    )
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub async fn close_hold(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+    status: SlotHoldStatus,
+) -> Result<(), sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE slot_holds
+        SET status = $2, closed_at = NOW()
+        WHERE booking_id = $1 AND status = 'active'
+        "#,
+        booking_id,
+        status as SlotHoldStatus
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    if result.rows_affected() > 0 {
+        db::slot_inventory::adjust_for_booking_tx(tx, booking_id, -1).await?;
+    }
+    Ok(())
+}
+
+/// Bookings stay pending so a late payment can still reclaim a slot nobody took.
+pub async fn release_expired_holds(
+    tx: &mut Transaction<'_, Postgres>,
+    limit: i64,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let released = sqlx::query_scalar!(
+        r#"
+        UPDATE slot_holds
+        SET status = 'released', closed_at = NOW()
+        WHERE id IN (
+            SELECT id FROM slot_holds
+            WHERE status = 'active' AND expires_at <= NOW()
+            ORDER BY expires_at
+            LIMIT $1
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING booking_id
+        "#,
+        limit
+    )
+    .fetch_all(&mut **tx)
+    .await?;
+
+    for &booking_id in &released {
+        db::slot_inventory::adjust_for_booking_tx(tx, booking_id, -1).await?;
+    }
+    Ok(released)
+}
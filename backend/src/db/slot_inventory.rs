@@ -0,0 +1,148 @@
+use chrono::NaiveDate;
+use sqlx::{PgConnection, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::calendar::{self, TakenSlots};
+
+/// Per ISO week, the narrowest lock the weekly cap allows; take it before counting what is left.
+pub async fn lock_week_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    writer_id: Uuid,
+    slot_date: NaiveDate,
+) -> Result<(), sqlx::Error> {
+    let key = format!(
+        "slot_inventory:{}:{}",
+        writer_id,
+        calendar::week_start(slot_date)
+    );
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+        .bind(key)
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
+/// A negative `delta` releases slots.
+pub async fn adjust_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    writer_id: Uuid,
+    placement_id: Uuid,
+    slot_date: NaiveDate,
+    delta: i32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO slot_inventory (placement_id, slot_date, writer_id, capacity, booked)
+        SELECT id, $2, $3, slots_per_issue, $4 FROM ad_placements WHERE id = $1
+        ON CONFLICT (placement_id, slot_date)
+        DO UPDATE SET booked = slot_inventory.booked + $4, updated_at = NOW()
+        "#,
+        placement_id,
+        slot_date,
+        writer_id,
+        delta
+    )
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+pub async fn adjust_for_booking_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+    delta: i32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO slot_inventory (placement_id, slot_date, writer_id, capacity, booked)
+        SELECT b.placement_id, b.slot_date, b.writer_id, p.slots_per_issue, $2
+        FROM bookings b
+        JOIN ad_placements p ON p.id = b.placement_id
+        WHERE b.id = $1
+        ON CONFLICT (placement_id, slot_date)
+        DO UPDATE SET booked = slot_inventory.booked + $2, updated_at = NOW()
+        "#,
+        booking_id,
+        delta
+    )
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct SlotUsage {
+    pub writer_id: Uuid,
+    pub placement_id: Uuid,
+    pub slot_date: NaiveDate,
+    pub capacity: i32,
+    pub booked: i32,
+}
+
+pub async fn get_taken_slots(
+    conn: &mut PgConnection,
+    writer_id: Uuid,
+    placement_id: Uuid,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<TakenSlots, sqlx::Error> {
+    let usage = get_slot_usage(conn, &[writer_id], from, to).await?;
+    Ok(taken_slots(&usage, placement_id))
+}
+
+pub async fn get_slot_usage(
+    conn: &mut PgConnection,
+    writer_ids: &[Uuid],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<SlotUsage>, sqlx::Error> {
+    sqlx::query_as!(
+        SlotUsage,
+        r#"
+        SELECT writer_id, placement_id, slot_date, capacity, booked
+        FROM slot_inventory
+        WHERE writer_id = ANY($1) AND slot_date BETWEEN $2 AND $3
+        "#,
+        writer_ids,
+        from,
+        to
+    )
+    .fetch_all(conn)
+    .await
+}
+
+pub fn taken_slots<'a>(
+    usage: impl IntoIterator<Item = &'a SlotUsage>,
+    placement_id: Uuid,
+) -> TakenSlots {
+    let mut taken = TakenSlots::default();
+    for row in usage {
+        *taken.all.entry(row.slot_date).or_default() += row.booked as i64;
+        if row.placement_id == placement_id {
+            taken.in_placement.insert(row.slot_date, row.booked as i64);
+            taken
+                .placement_capacity
+                .insert(row.slot_date, row.capacity as i64);
+        }
+    }
+    taken
+}
+
+/// Only upcoming dates take the new capacity.
+pub async fn set_capacity(
+    conn: &mut PgConnection,
+    placement_id: Uuid,
+    capacity: i32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE slot_inventory SET capacity = $2, updated_at = NOW()
+        WHERE placement_id = $1 AND slot_date >= CURRENT_DATE - 1
+        "#,
+        placement_id,
+        capacity
+    )
+    .execute(conn)
+    .await?;
+    Ok(())
+}
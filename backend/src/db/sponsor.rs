@@ -1,15 +1,20 @@
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::{PgPool, Postgres, Transaction};
 use uuid::Uuid;
 
+use crate::db;
+use crate::db::booking_event::NewBookingEvent;
 use crate::models::{
-    Booking, BookingStatus, BookingWithDetails, CreateSponsor, Sponsor, UpdateSponsor, Writer,
+    AdPlacement, Booking, BookingActor, BookingEventType, BookingStatus, BookingWithDetails,
+    CreateSponsor, Sponsor, UpdateSponsor, Writer,
 };
 use crate::validation::SanitizedBookingInput;
 
 #[derive(Debug)]
 pub enum CreateBookingError {
-    SlotNotAvailable,
+    SlotNotAvailable(NaiveDate),
+    /// The waitlist offer being redeemed expired or was withdrawn
+    OfferExpired,
     Database(sqlx::Error),
 }
 
@@ -27,15 +32,16 @@ pub async fn create_sponsor(
     sqlx::query_as!(
         Sponsor,
         r#"
-        INSERT INTO sponsors (user_id, company_name, website_url, logo_url, billing_email)
-        VALUES ($1, $2, $3, $4, $5)
+        INSERT INTO sponsors (user_id, company_name, website_url, logo_url, billing_email, timezone)
+        VALUES ($1, $2, $3, $4, $5, $6)
         RETURNING *
         "#,
         user_id,
         input.company_name,
         input.website_url,
         input.logo_url,
-        input.billing_email
+        input.billing_email,
+        input.timezone
     )
     .fetch_one(pool)
     .await
@@ -73,115 +79,172 @@ pub async fn update_sponsor(
             website_url = COALESCE($2, website_url),
             logo_url = COALESCE($3, logo_url),
             billing_email = COALESCE($4, billing_email),
+            timezone = COALESCE($5, timezone),
             updated_at = NOW()
-        WHERE id = $5
+        WHERE id = $6
         RETURNING *
         "#,
         input.company_name,
         input.website_url,
         input.logo_url,
         input.billing_email,
+        input.timezone,
         sponsor_id
     )
     .fetch_one(pool)
     .await
 }
 
-async fn check_slot_available_tx(
+/// Whatever the caller claims must be recorded in the slot inventory in the same transaction.
+pub async fn lock_slots_remaining_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    writer_id: Uuid,
+    placement_id: Uuid,
+    slot_date: NaiveDate,
+) -> Result<(Writer, i32), sqlx::Error> {
+    db::slot_inventory::lock_week_tx(tx, writer_id, slot_date).await?;
+
+    let writer = sqlx::query_as!(Writer, "SELECT * FROM writers WHERE id = $1", writer_id)
+        .fetch_one(&mut **tx)
+        .await?;
+
+    let Some(placement) = db::ad_placement::get_placement_by_id_tx(tx, placement_id).await? else {
+        return Ok((writer, 0));
+    };
+
+    let remaining = db::availability::slots_remaining(tx, &writer, &placement, slot_date).await?;
+    Ok((writer, remaining))
+}
+
+pub async fn check_slot_available_tx(
     tx: &mut Transaction<'_, Postgres>,
     writer_id: Uuid,
+    placement_id: Uuid,
     slot_date: NaiveDate,
 ) -> Result<bool, sqlx::Error> {
-    let result: (i32, i64, bool) = sqlx::query_as(
-        r#"
-        SELECT
-            w.slots_per_week,
-            (
-                SELECT COUNT(*) FROM bookings b
-                WHERE b.writer_id = $1 AND b.slot_date = $2
-                AND b.status NOT IN ('rejected', 'cancelled', 'refunded')
-            ),
-            EXISTS (
-                SELECT 1 FROM blackout_dates bl
-                WHERE bl.writer_id = $1 AND bl.blocked_date = $2
-            )
-        FROM writers w
-        WHERE w.id = $1
-        FOR UPDATE OF w
-        "#,
-    )
-    .bind(writer_id)
-    .bind(slot_date)
-    .fetch_one(&mut **tx)
-    .await?;
+    let (_, remaining) = lock_slots_remaining_tx(tx, writer_id, placement_id, slot_date).await?;
+    Ok(remaining > 0)
+}
 
-    let (slots_per_week, booked_count, is_blackout) = result;
-    Ok(!is_blackout && (booked_count as i32) < slots_per_week)
+#[derive(Debug)]
+pub struct NewBooking<'a> {
+    pub sponsor_id: Uuid,
+    pub writer: &'a Writer,
+    pub placement: &'a AdPlacement,
+    /// Booking id, slot date and the effective price frozen as its amount
+    pub slots: &'a [(Uuid, NaiveDate, i32)],
+    pub bundle_id: Option<Uuid>,
+    pub waitlist_entry_id: Option<Uuid>,
+    pub ad_content: &'a SanitizedBookingInput,
+    pub lemon_order_id: &'a str,
+    pub payment_expires_at: DateTime<Utc>,
+    pub created_by: Uuid,
 }
 
-pub async fn create_booking_with_availability_check(
+/// All or nothing: if any date is no longer available none are created.
+pub async fn create_bookings_with_availability_check(
     pool: &PgPool,
-    sponsor_id: Uuid,
-    writer: &Writer,
-    slot_date: NaiveDate,
-    ad_content: &SanitizedBookingInput,
-    lemon_order_id: &str,
-) -> Result<Booking, CreateBookingError> {
+    new: &NewBooking<'_>,
+) -> Result<Vec<Booking>, CreateBookingError> {
+    let writer = new.writer;
+    let placement = new.placement;
+    let ad_content = new.ad_content;
+
     let mut tx = pool.begin().await?;
 
-    let available = check_slot_available_tx(&mut tx, writer.id, slot_date).await?;
-    if !available {
-        tx.rollback().await?;
-        return Err(CreateBookingError::SlotNotAvailable);
+    if let Some(entry_id) = new.waitlist_entry_id {
+        db::slot_inventory::lock_week_tx(&mut tx, writer.id, new.slots[0].1).await?;
+        if !db::waitlist::claim_offer_tx(&mut tx, entry_id, new.slots[0].0).await? {
+            tx.rollback().await?;
+            return Err(CreateBookingError::OfferExpired);
+        }
     }
 
-    let amount_cents = writer.price_per_slot;
-    let platform_fee_cents = (amount_cents as f64
-        * (writer
-            .platform_fee_pct
-            .to_string()
-            .parse::<f64>()
-            .unwrap_or(10.0)
-            / 100.0)) as i32;
-    let writer_payout_cents = amount_cents - platform_fee_cents;
-
-    let booking = sqlx::query_as!(
-        Booking,
-        r#"
-        INSERT INTO bookings (
-            writer_id, sponsor_id, slot_date, ad_headline, ad_body,
-            ad_cta_text, ad_cta_url, ad_image_url, status,
-            amount_cents, platform_fee_cents, writer_payout_cents, currency,
-            lemon_order_id
+    let mut bookings = Vec::with_capacity(new.slots.len());
+
+    // Each date is claimed before the next is checked, so dates in the same
+    // week or issue count against each other
+    for &(booking_id, slot_date, amount_cents) in new.slots {
+        let available =
+            check_slot_available_tx(&mut tx, writer.id, placement.id, slot_date).await?;
+        if !available {
+            tx.rollback().await?;
+            return Err(CreateBookingError::SlotNotAvailable(slot_date));
+        }
+
+        let platform_fee_cents = writer.platform_fee_cents(amount_cents);
+        let writer_payout_cents = amount_cents - platform_fee_cents;
+
+        let booking = sqlx::query_as!(
+            Booking,
+            r#"
+            INSERT INTO bookings (
+                id, writer_id, sponsor_id, slot_date, ad_headline, ad_body,
+                ad_cta_text, ad_cta_url, ad_image_url, status,
+                amount_cents, platform_fee_cents, writer_payout_cents, currency,
+                lemon_order_id, payment_expires_at, bundle_id, placement_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, 'pending_payment', $10, $11, $12, $13, $14, $15, $16, $17)
+            RETURNING id, writer_id, sponsor_id, slot_date, ad_headline, ad_body,
+                      ad_cta_text, ad_cta_url, ad_image_url,
+                      status as "status: BookingStatus",
+                      amount_cents, platform_fee_cents, writer_payout_cents, currency,
+                      lemon_order_id,
+                      created_at, paid_at, approved_at, rejected_at, published_at,
+                      refunded_cents, cancelled_at, payment_expires_at, bundle_id,
+                      published_url, publication_proof_url, placement_id
+            "#,
+            booking_id,
+            writer.id,
+            new.sponsor_id,
+            slot_date,
+            ad_content.ad_headline,
+            ad_content.ad_body,
+            ad_content.ad_cta_text,
+            ad_content.ad_cta_url,
+            ad_content.ad_image_url,
+            amount_cents,
+            platform_fee_cents,
+            writer_payout_cents,
+            placement.currency,
+            new.lemon_order_id,
+            new.payment_expires_at,
+            new.bundle_id,
+            placement.id
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, 'pending_payment', $9, $10, $11, $12, $13)
-        RETURNING id, writer_id, sponsor_id, slot_date, ad_headline, ad_body,
-                  ad_cta_text, ad_cta_url, ad_image_url,
-                  status as "status: BookingStatus",
-                  amount_cents, platform_fee_cents, writer_payout_cents, currency,
-                  lemon_order_id,
-                  created_at, paid_at, approved_at, rejected_at, published_at
-        "#,
-        writer.id,
-        sponsor_id,
-        slot_date,
-        ad_content.ad_headline,
-        ad_content.ad_body,
-        ad_content.ad_cta_text,
-        ad_content.ad_cta_url,
-        ad_content.ad_image_url,
-        amount_cents,
-        platform_fee_cents,
-        writer_payout_cents,
-        writer.currency,
-        lemon_order_id
-    )
-    .fetch_one(&mut *tx)
-    .await?;
+        .fetch_one(&mut *tx)
+        .await?;
+
+        db::slot_hold::create_hold(
+            &mut tx,
+            booking.id,
+            writer.id,
+            new.sponsor_id,
+            slot_date,
+            new.payment_expires_at,
+        )
+        .await?;
+
+        db::booking_event::insert_booking_event(
+            &mut tx,
+            &NewBookingEvent {
+                booking_id: booking.id,
+                event_type: BookingEventType::StatusChange,
+                from_status: None,
+                to_status: BookingStatus::PendingPayment,
+                actor: BookingActor::Sponsor,
+                actor_user_id: Some(new.created_by),
+                reason: None,
+            },
+        )
+        .await?;
+
+        bookings.push(booking);
+    }
 
     tx.commit().await?;
 
-    Ok(booking)
+    Ok(bookings)
 }
 
 pub async fn get_booking_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Booking>, sqlx::Error> {
@@ -193,7 +256,9 @@ pub async fn get_booking_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Booking
                status as "status: BookingStatus",
                amount_cents, platform_fee_cents, writer_payout_cents, currency,
                lemon_order_id,
-               created_at, paid_at, approved_at, rejected_at, published_at
+               created_at, paid_at, approved_at, rejected_at, published_at,
+               refunded_cents, cancelled_at, payment_expires_at, bundle_id,
+               published_url, publication_proof_url, placement_id
         FROM bookings WHERE id = $1
         "#,
         id
@@ -202,10 +267,10 @@ pub async fn get_booking_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Booking
     .await
 }
 
-pub async fn get_booking_by_lemon_order(
+pub async fn get_bookings_by_lemon_order(
     pool: &PgPool,
     lemon_order_id: &str,
-) -> Result<Option<Booking>, sqlx::Error> {
+) -> Result<Vec<Booking>, sqlx::Error> {
     sqlx::query_as!(
         Booking,
         r#"
@@ -214,54 +279,261 @@ pub async fn get_booking_by_lemon_order(
                status as "status: BookingStatus",
                amount_cents, platform_fee_cents, writer_payout_cents, currency,
                lemon_order_id,
-               created_at, paid_at, approved_at, rejected_at, published_at
+               created_at, paid_at, approved_at, rejected_at, published_at,
+               refunded_cents, cancelled_at, payment_expires_at, bundle_id,
+               published_url, publication_proof_url, placement_id
         FROM bookings WHERE lemon_order_id = $1
+        ORDER BY slot_date
         "#,
         lemon_order_id
     )
-    .fetch_optional(pool)
+    .fetch_all(pool)
     .await
 }
 
-pub async fn update_booking_status(
+pub async fn get_bundle_bookings(
     pool: &PgPool,
+    bundle_id: Uuid,
+) -> Result<Vec<Booking>, sqlx::Error> {
+    sqlx::query_as!(
+        Booking,
+        r#"
+        SELECT id, writer_id, sponsor_id, slot_date, ad_headline, ad_body,
+               ad_cta_text, ad_cta_url, ad_image_url,
+               status as "status: BookingStatus",
+               amount_cents, platform_fee_cents, writer_payout_cents, currency,
+               lemon_order_id,
+               created_at, paid_at, approved_at, rejected_at, published_at,
+               refunded_cents, cancelled_at, payment_expires_at, bundle_id,
+               published_url, publication_proof_url, placement_id
+        FROM bookings WHERE bundle_id = $1
+        ORDER BY slot_date
+        "#,
+        bundle_id
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Locks in id order so concurrent webhooks for the same order cannot deadlock.
+pub async fn lock_bookings_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_ids: &[Uuid],
+) -> Result<Vec<Booking>, sqlx::Error> {
+    sqlx::query_as!(
+        Booking,
+        r#"
+        SELECT id, writer_id, sponsor_id, slot_date, ad_headline, ad_body,
+               ad_cta_text, ad_cta_url, ad_image_url,
+               status as "status: BookingStatus",
+               amount_cents, platform_fee_cents, writer_payout_cents, currency,
+               lemon_order_id,
+               created_at, paid_at, approved_at, rejected_at, published_at,
+               refunded_cents, cancelled_at, payment_expires_at, bundle_id,
+               published_url, publication_proof_url, placement_id
+        FROM bookings WHERE id = ANY($1)
+        ORDER BY id
+        FOR UPDATE
+        "#,
+        booking_ids
+    )
+    .fetch_all(&mut **tx)
+    .await
+}
+
+pub async fn lock_booking_status(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+) -> Result<Option<BookingStatus>, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"SELECT status as "status: BookingStatus" FROM bookings WHERE id = $1 FOR UPDATE"#,
+        booking_id
+    )
+    .fetch_optional(&mut **tx)
+    .await
+}
+
+// Only the lifecycle module should call this; it validates the transition and records the event
+pub async fn set_booking_status(
+    tx: &mut Transaction<'_, Postgres>,
     booking_id: Uuid,
     status: BookingStatus,
-) -> Result<(), sqlx::Error> {
+) -> Result<Booking, sqlx::Error> {
     let timestamp_field = match status {
-        BookingStatus::Paid => "paid_at",
-        BookingStatus::Approved => "approved_at",
-        BookingStatus::Rejected => "rejected_at",
-        BookingStatus::Published => "published_at",
+        // Resubmitted copy returns to `paid`; keep the original payment time. A
+        // lapsed checkout reinstated by a late payment is no longer cancelled.
+        BookingStatus::Paid => ", paid_at = COALESCE(paid_at, NOW()), cancelled_at = NULL",
+        BookingStatus::Approved => ", approved_at = NOW()",
+        BookingStatus::Rejected => ", rejected_at = NOW()",
+        BookingStatus::Published => ", published_at = NOW()",
+        BookingStatus::Cancelled => ", cancelled_at = NOW()",
         _ => "",
     };
 
-    if timestamp_field.is_empty() {
-        sqlx::query!(
-            "UPDATE bookings SET status = $1 WHERE id = $2",
-            status as BookingStatus,
-            booking_id
-        )
-        .execute(pool)
-        .await?;
-    } else {
-        // Use raw query for dynamic column
-        let query = format!(
-            "UPDATE bookings SET status = $1, {} = NOW() WHERE id = $2",
-            timestamp_field
-        );
-        sqlx::query(&query)
-            .bind(status as BookingStatus)
-            .bind(booking_id)
-            .execute(pool)
-            .await?;
-    }
+    let query = format!(
+        r#"
+        UPDATE bookings SET status = $1{}
+        WHERE id = $2
+        RETURNING id, writer_id, sponsor_id, slot_date, ad_headline, ad_body,
+                  ad_cta_text, ad_cta_url, ad_image_url,
+                  status, amount_cents, platform_fee_cents, writer_payout_cents, currency,
+                  lemon_order_id,
+                  created_at, paid_at, approved_at, rejected_at, published_at,
+                  refunded_cents, cancelled_at, payment_expires_at, bundle_id,
+                  published_url, publication_proof_url, placement_id
+        "#,
+        timestamp_field
+    );
+
+    sqlx::query_as::<_, Booking>(&query)
+        .bind(status)
+        .bind(booking_id)
+        .fetch_one(&mut **tx)
+        .await
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ExpiredPendingBooking {
+    pub id: Uuid,
+    pub writer_id: Uuid,
+    pub slot_date: NaiveDate,
+    pub payment_expires_at: Option<DateTime<Utc>>,
+}
+
+// SKIP LOCKED lets several instances reap concurrently without picking the same rows
+pub async fn lock_expired_pending_bookings(
+    tx: &mut Transaction<'_, Postgres>,
+    expired_before: DateTime<Utc>,
+    limit: i64,
+) -> Result<Vec<ExpiredPendingBooking>, sqlx::Error> {
+    sqlx::query_as!(
+        ExpiredPendingBooking,
+        r#"
+        SELECT id, writer_id, slot_date, payment_expires_at
+        FROM bookings
+        WHERE status = 'pending_payment'
+          AND payment_expires_at < $1
+        ORDER BY payment_expires_at
+        LIMIT $2
+        FOR UPDATE SKIP LOCKED
+        "#,
+        expired_before,
+        limit
+    )
+    .fetch_all(&mut **tx)
+    .await
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct OverdueBooking {
+    pub id: Uuid,
+    pub slot_date: NaiveDate,
+    pub ad_headline: String,
+    pub amount_cents: i32,
+    pub currency: String,
+    pub newsletter_name: String,
+    pub writer_email: String,
+    pub writer_timezone: String,
+    pub company_name: String,
+    pub billing_email: Option<String>,
+    pub sponsor_timezone: String,
+    pub reminded: bool,
+    pub escalated: bool,
+}
+
+/// Due steps: a reminder once late in the writer's timezone, then escalation and refund.
+pub async fn lock_next_overdue_booking(
+    tx: &mut Transaction<'_, Postgres>,
+    escalation_days: i32,
+    refund_days: i32,
+    exclude: &[Uuid],
+) -> Result<Option<OverdueBooking>, sqlx::Error> {
+    sqlx::query_as!(
+        OverdueBooking,
+        r#"
+        SELECT b.id, b.slot_date, b.ad_headline, b.amount_cents, b.currency,
+               w.newsletter_name, u.email as writer_email, w.timezone as writer_timezone,
+               s.company_name, s.billing_email, s.timezone as sponsor_timezone,
+               r.booking_id IS NOT NULL as "reminded!",
+               x.booking_id IS NOT NULL as "escalated!"
+        FROM bookings b
+        JOIN writers w ON w.id = b.writer_id
+        JOIN users u ON u.id = w.user_id
+        JOIN sponsors s ON s.id = b.sponsor_id
+        -- Reminders sent before the booking was moved to a new date don't count
+        CROSS JOIN LATERAL (
+            SELECT COALESCE(MAX(e.created_at), '-infinity') as rescheduled_at
+            FROM booking_events e
+            WHERE e.booking_id = b.id AND e.event_type = 'reschedule_accepted'
+        ) rs
+        LEFT JOIN LATERAL (
+            SELECT e.booking_id, e.created_at FROM booking_events e
+            WHERE e.booking_id = b.id AND e.event_type = 'publication_reminder'
+              AND e.created_at > rs.rescheduled_at
+            ORDER BY e.created_at
+            LIMIT 1
+        ) r ON TRUE
+        LEFT JOIN LATERAL (
+            SELECT e.booking_id, e.created_at FROM booking_events e
+            WHERE e.booking_id = b.id AND e.event_type = 'publication_escalated'
+              AND e.created_at > rs.rescheduled_at
+            ORDER BY e.created_at
+            LIMIT 1
+        ) x ON TRUE
+        CROSS JOIN LATERAL (
+            SELECT (NOW() AT TIME ZONE w.timezone)::date as today
+        ) wt
+        WHERE b.status = 'approved'
+          AND b.published_at IS NULL
+          AND b.slot_date < wt.today
+          AND NOT (b.id = ANY($3))
+          AND (
+              r.booking_id IS NULL
+              OR (x.booking_id IS NULL AND r.created_at <= NOW() - make_interval(days => $1))
+              OR (x.booking_id IS NOT NULL AND x.created_at <= NOW() - make_interval(days => $2))
+          )
+        ORDER BY b.slot_date, b.id
+        LIMIT 1
+        FOR UPDATE OF b SKIP LOCKED
+        "#,
+        escalation_days,
+        refund_days,
+        exclude
+    )
+    .fetch_optional(&mut **tx)
+    .await
+}
+
+// Splits what the sponsor was not refunded between the platform and the writer
+pub async fn record_booking_refund(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+    refunded_cents: i32,
+    platform_fee_cents: i32,
+    writer_payout_cents: i32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE bookings
+        SET refunded_cents = $2,
+            platform_fee_cents = $3,
+            writer_payout_cents = $4
+        WHERE id = $1
+        "#,
+        booking_id,
+        refunded_cents,
+        platform_fee_cents,
+        writer_payout_cents
+    )
+    .execute(&mut **tx)
+    .await?;
     Ok(())
 }
 
 pub async fn get_writer_bookings(
     pool: &PgPool,
     writer_id: Uuid,
+    viewer_id: Uuid,
 ) -> Result<Vec<BookingWithDetails>, sqlx::Error> {
     sqlx::query_as!(
         BookingWithDetails,
@@ -270,16 +542,28 @@ pub async fn get_writer_bookings(
             b.id, b.slot_date, b.ad_headline, b.ad_body, b.ad_cta_text, b.ad_cta_url, b.ad_image_url,
             b.status as "status: BookingStatus",
             b.amount_cents, b.platform_fee_cents, b.writer_payout_cents, b.currency,
-            b.created_at, b.paid_at, b.approved_at, b.published_at,
+            b.created_at, b.paid_at, b.approved_at, b.published_at, b.bundle_id,
+            b.published_url, b.publication_proof_url,
+            b.placement_id, p.name as placement_name,
             b.writer_id, w.newsletter_name,
-            b.sponsor_id, s.company_name, s.logo_url as sponsor_logo_url
+            b.sponsor_id, s.company_name, s.logo_url as sponsor_logo_url,
+            (
+                SELECT COUNT(*)
+                FROM booking_messages m
+                LEFT JOIN booking_message_reads r ON r.booking_id = m.booking_id AND r.user_id = $2
+                WHERE m.booking_id = b.id
+                  AND m.sender_user_id IS DISTINCT FROM $2
+                  AND (r.last_read_at IS NULL OR m.created_at > r.last_read_at)
+            ) as "unread_messages!"
         FROM bookings b
         JOIN writers w ON w.id = b.writer_id
         JOIN sponsors s ON s.id = b.sponsor_id
+        JOIN ad_placements p ON p.id = b.placement_id
         WHERE b.writer_id = $1
         ORDER BY b.slot_date DESC
         "#,
-        writer_id
+        writer_id,
+        viewer_id
     )
     .fetch_all(pool)
     .await
@@ -289,6 +573,7 @@ pub async fn get_writer_upcoming_bookings(
     pool: &PgPool,
     writer_id: Uuid,
     weeks: i32,
+    viewer_id: Uuid,
 ) -> Result<Vec<BookingWithDetails>, sqlx::Error> {
     sqlx::query_as!(
         BookingWithDetails,
@@ -297,20 +582,32 @@ pub async fn get_writer_upcoming_bookings(
             b.id, b.slot_date, b.ad_headline, b.ad_body, b.ad_cta_text, b.ad_cta_url, b.ad_image_url,
             b.status as "status: BookingStatus",
             b.amount_cents, b.platform_fee_cents, b.writer_payout_cents, b.currency,
-            b.created_at, b.paid_at, b.approved_at, b.published_at,
+            b.created_at, b.paid_at, b.approved_at, b.published_at, b.bundle_id,
+            b.published_url, b.publication_proof_url,
+            b.placement_id, p.name as placement_name,
             b.writer_id, w.newsletter_name,
-            b.sponsor_id, s.company_name, s.logo_url as sponsor_logo_url
+            b.sponsor_id, s.company_name, s.logo_url as sponsor_logo_url,
+            (
+                SELECT COUNT(*)
+                FROM booking_messages m
+                LEFT JOIN booking_message_reads r ON r.booking_id = m.booking_id AND r.user_id = $3
+                WHERE m.booking_id = b.id
+                  AND m.sender_user_id IS DISTINCT FROM $3
+                  AND (r.last_read_at IS NULL OR m.created_at > r.last_read_at)
+            ) as "unread_messages!"
         FROM bookings b
         JOIN writers w ON w.id = b.writer_id
         JOIN sponsors s ON s.id = b.sponsor_id
+        JOIN ad_placements p ON p.id = b.placement_id
         WHERE b.writer_id = $1
-          AND b.slot_date >= CURRENT_DATE
-          AND b.slot_date <= CURRENT_DATE + ($2 || ' weeks')::INTERVAL
-          AND b.status IN ('paid', 'approved', 'published')
+          AND b.slot_date >= (NOW() AT TIME ZONE w.timezone)::date
+          AND b.slot_date <= (NOW() AT TIME ZONE w.timezone)::date + ($2 || ' weeks')::INTERVAL
+          AND b.status IN ('paid', 'changes_requested', 'approved', 'published')
         ORDER BY b.slot_date ASC
         "#,
         writer_id,
-        weeks.to_string()
+        weeks.to_string(),
+        viewer_id
     )
     .fetch_all(pool)
     .await
@@ -319,6 +616,7 @@ pub async fn get_writer_upcoming_bookings(
 pub async fn get_sponsor_bookings(
     pool: &PgPool,
     sponsor_id: Uuid,
+    viewer_id: Uuid,
 ) -> Result<Vec<BookingWithDetails>, sqlx::Error> {
     sqlx::query_as!(
         BookingWithDetails,
@@ -327,16 +625,28 @@ pub async fn get_sponsor_bookings(
             b.id, b.slot_date, b.ad_headline, b.ad_body, b.ad_cta_text, b.ad_cta_url, b.ad_image_url,
             b.status as "status: BookingStatus",
             b.amount_cents, b.platform_fee_cents, b.writer_payout_cents, b.currency,
-            b.created_at, b.paid_at, b.approved_at, b.published_at,
+            b.created_at, b.paid_at, b.approved_at, b.published_at, b.bundle_id,
+            b.published_url, b.publication_proof_url,
+            b.placement_id, p.name as placement_name,
             b.writer_id, w.newsletter_name,
-            b.sponsor_id, s.company_name, s.logo_url as sponsor_logo_url
+            b.sponsor_id, s.company_name, s.logo_url as sponsor_logo_url,
+            (
+                SELECT COUNT(*)
+                FROM booking_messages m
+                LEFT JOIN booking_message_reads r ON r.booking_id = m.booking_id AND r.user_id = $2
+                WHERE m.booking_id = b.id
+                  AND m.sender_user_id IS DISTINCT FROM $2
+                  AND (r.last_read_at IS NULL OR m.created_at > r.last_read_at)
+            ) as "unread_messages!"
         FROM bookings b
         JOIN writers w ON w.id = b.writer_id
         JOIN sponsors s ON s.id = b.sponsor_id
+        JOIN ad_placements p ON p.id = b.placement_id
         WHERE b.sponsor_id = $1
         ORDER BY b.slot_date DESC
         "#,
-        sponsor_id
+        sponsor_id,
+        viewer_id
     )
     .fetch_all(pool)
     .await
@@ -373,6 +683,7 @@ pub async fn get_writer_bookings_paginated(
     offset: i32,
     filters: &BookingFilters,
     sort_by: BookingSortBy,
+    viewer_id: Uuid,
 ) -> Result<PaginatedBookings, sqlx::Error> {
     let order_clause = match sort_by {
         BookingSortBy::SlotDateDesc => "b.slot_date DESC",
@@ -419,12 +730,23 @@ pub async fn get_writer_bookings_paginated(
                 b.id, b.slot_date, b.ad_headline, b.ad_body, b.ad_cta_text, b.ad_cta_url, b.ad_image_url,
                 b.status as "status: BookingStatus",
                 b.amount_cents, b.platform_fee_cents, b.writer_payout_cents, b.currency,
-                b.created_at, b.paid_at, b.approved_at, b.published_at,
+                b.created_at, b.paid_at, b.approved_at, b.published_at, b.bundle_id,
+                b.published_url, b.publication_proof_url,
+                b.placement_id, p.name as placement_name,
                 b.writer_id, w.newsletter_name,
-                b.sponsor_id, s.company_name, s.logo_url as sponsor_logo_url
+                b.sponsor_id, s.company_name, s.logo_url as sponsor_logo_url,
+                (
+                    SELECT COUNT(*)
+                    FROM booking_messages m
+                    LEFT JOIN booking_message_reads r ON r.booking_id = m.booking_id AND r.user_id = $7
+                    WHERE m.booking_id = b.id
+                      AND m.sender_user_id IS DISTINCT FROM $7
+                      AND (r.last_read_at IS NULL OR m.created_at > r.last_read_at)
+                ) as unread_messages
             FROM bookings b
             JOIN writers w ON w.id = b.writer_id
             JOIN sponsors s ON s.id = b.sponsor_id
+            JOIN ad_placements p ON p.id = b.placement_id
             WHERE b.writer_id = $1
             {}
             {}
@@ -444,6 +766,7 @@ pub async fn get_writer_bookings_paginated(
     .bind(filters.status.map(|s| format!("{:?}", s).to_lowercase()))
     .bind(filters.from_date)
     .bind(filters.to_date)
+    .bind(viewer_id)
     .fetch_all(pool)
     .await?;
 
@@ -457,6 +780,7 @@ pub async fn get_sponsor_bookings_paginated(
     offset: i32,
     filters: &BookingFilters,
     sort_by: BookingSortBy,
+    viewer_id: Uuid,
 ) -> Result<PaginatedBookings, sqlx::Error> {
     let order_clause = match sort_by {
         BookingSortBy::SlotDateDesc => "b.slot_date DESC",
@@ -503,12 +827,23 @@ pub async fn get_sponsor_bookings_paginated(
                 b.id, b.slot_date, b.ad_headline, b.ad_body, b.ad_cta_text, b.ad_cta_url, b.ad_image_url,
                 b.status as "status: BookingStatus",
                 b.amount_cents, b.platform_fee_cents, b.writer_payout_cents, b.currency,
-                b.created_at, b.paid_at, b.approved_at, b.published_at,
+                b.created_at, b.paid_at, b.approved_at, b.published_at, b.bundle_id,
+                b.published_url, b.publication_proof_url,
+                b.placement_id, p.name as placement_name,
                 b.writer_id, w.newsletter_name,
-                b.sponsor_id, s.company_name, s.logo_url as sponsor_logo_url
+                b.sponsor_id, s.company_name, s.logo_url as sponsor_logo_url,
+                (
+                    SELECT COUNT(*)
+                    FROM booking_messages m
+                    LEFT JOIN booking_message_reads r ON r.booking_id = m.booking_id AND r.user_id = $7
+                    WHERE m.booking_id = b.id
+                      AND m.sender_user_id IS DISTINCT FROM $7
+                      AND (r.last_read_at IS NULL OR m.created_at > r.last_read_at)
+                ) as unread_messages
             FROM bookings b
             JOIN writers w ON w.id = b.writer_id
             JOIN sponsors s ON s.id = b.sponsor_id
+            JOIN ad_placements p ON p.id = b.placement_id
             WHERE b.sponsor_id = $1
             {}
             {}
@@ -528,6 +863,7 @@ pub async fn get_sponsor_bookings_paginated(
     .bind(filters.status.map(|s| format!("{:?}", s).to_lowercase()))
     .bind(filters.from_date)
     .bind(filters.to_date)
+    .bind(viewer_id)
     .fetch_all(pool)
     .await?;
 
@@ -552,7 +888,9 @@ pub async fn update_booking_ad_content(
                   ad_cta_text, ad_cta_url, ad_image_url,
                   status, amount_cents, platform_fee_cents, writer_payout_cents, currency,
                   lemon_order_id,
-                  created_at, paid_at, approved_at, rejected_at, published_at
+                  created_at, paid_at, approved_at, rejected_at, published_at,
+                  refunded_cents, cancelled_at, payment_expires_at, bundle_id,
+                  published_url, publication_proof_url, placement_id
     "#;
 
     sqlx::query_as::<_, Booking>(sql)
@@ -565,3 +903,41 @@ pub async fn update_booking_ad_content(
         .fetch_one(pool)
         .await
 }
+
+/// Carries the booking's claim in the slot inventory to the new date.
+pub async fn move_booking_slot(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+    slot_date: NaiveDate,
+) -> Result<(), sqlx::Error> {
+    db::slot_inventory::adjust_for_booking_tx(tx, booking_id, -1).await?;
+    sqlx::query!(
+        "UPDATE bookings SET slot_date = $2 WHERE id = $1",
+        booking_id,
+        slot_date
+    )
+    .execute(&mut **tx)
+    .await?;
+    db::slot_inventory::adjust_for_booking_tx(tx, booking_id, 1).await
+}
+
+pub async fn set_publication_proof(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+    published_url: Option<&str>,
+    publication_proof_url: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE bookings
+        SET published_url = $2, publication_proof_url = $3
+        WHERE id = $1
+        "#,
+        booking_id,
+        published_url,
+        publication_proof_url
+    )
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
@@ -97,3 +97,9 @@ pub async fn reset_password(
     .await?;
     Ok(result.rows_affected() > 0)
 }
+
+pub async fn get_admin_emails(pool: &PgPool) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar!("SELECT email FROM users WHERE role = 'admin' ORDER BY created_at")
+        .fetch_all(pool)
+        .await
+}
@@ -0,0 +1,293 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::db;
+use crate::models::{WaitlistEntry, WaitlistEntryWithDetails, WaitlistStatus};
+
+pub async fn join_waitlist(
+    pool: &PgPool,
+    sponsor_id: Uuid,
+    writer_id: Uuid,
+    placement_id: Uuid,
+    slot_date: NaiveDate,
+) -> Result<Uuid, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"
+        INSERT INTO waitlist_entries (sponsor_id, writer_id, placement_id, slot_date)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+        "#,
+        sponsor_id,
+        writer_id,
+        placement_id,
+        slot_date
+    )
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn is_on_waitlist(
+    pool: &PgPool,
+    sponsor_id: Uuid,
+    placement_id: Uuid,
+    slot_date: NaiveDate,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM waitlist_entries
+            WHERE sponsor_id = $1 AND placement_id = $2 AND slot_date = $3
+              AND status IN ('waiting', 'offered')
+        ) as "exists!"
+        "#,
+        sponsor_id,
+        placement_id,
+        slot_date
+    )
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn get_sponsor_entries(
+    pool: &PgPool,
+    sponsor_id: Uuid,
+) -> Result<Vec<WaitlistEntryWithDetails>, sqlx::Error> {
+    sqlx::query_as!(
+        WaitlistEntryWithDetails,
+        r#"
+        SELECT e.id, e.writer_id, w.newsletter_name, e.placement_id, p.name as placement_name,
+               e.slot_date, e.status as "status: WaitlistStatus",
+               CASE WHEN e.status = 'waiting' THEN (
+                   SELECT COUNT(*) FROM waitlist_entries ahead
+                   WHERE ahead.placement_id = e.placement_id
+                     AND ahead.slot_date = e.slot_date
+                     AND ahead.status = 'waiting'
+                     AND ahead.created_at <= e.created_at
+               ) END as position,
+               e.offer_expires_at, e.created_at
+        FROM waitlist_entries e
+        JOIN writers w ON w.id = e.writer_id
+        JOIN ad_placements p ON p.id = e.placement_id
+        WHERE e.sponsor_id = $1 AND e.status IN ('waiting', 'offered')
+        ORDER BY e.slot_date, e.created_at
+        "#,
+        sponsor_id
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Leaving with an outstanding offer releases its slot.
+pub async fn leave_waitlist(
+    pool: &PgPool,
+    sponsor_id: Uuid,
+    id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let entry = sqlx::query!(
+        r#"
+        SELECT writer_id, placement_id, slot_date, status as "status: WaitlistStatus"
+        FROM waitlist_entries
+        WHERE id = $1 AND sponsor_id = $2 AND status IN ('waiting', 'offered')
+        FOR UPDATE
+        "#,
+        id,
+        sponsor_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(entry) = entry else {
+        return Ok(false);
+    };
+
+    sqlx::query!(
+        "UPDATE waitlist_entries SET status = 'left', updated_at = NOW() WHERE id = $1",
+        id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if entry.status == WaitlistStatus::Offered {
+        db::slot_inventory::adjust_tx(
+            &mut tx,
+            entry.writer_id,
+            entry.placement_id,
+            entry.slot_date,
+            -1,
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(true)
+}
+
+pub async fn get_offer_by_token_hash(
+    pool: &PgPool,
+    token_hash: &str,
+) -> Result<Option<WaitlistEntry>, sqlx::Error> {
+    sqlx::query_as!(
+        WaitlistEntry,
+        r#"
+        SELECT id, writer_id, placement_id, sponsor_id, slot_date,
+               status as "status: WaitlistStatus", offered_at,
+               offer_expires_at, claimed_booking_id, created_at, updated_at
+        FROM waitlist_entries
+        WHERE offer_token_hash = $1
+        "#,
+        token_hash
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+/// Caller holds the week lock and claims before checking the slot. False if the offer lapsed.
+pub async fn claim_offer_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    id: Uuid,
+    booking_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let claimed = sqlx::query!(
+        r#"
+        UPDATE waitlist_entries
+        SET status = 'claimed', claimed_booking_id = $2, updated_at = NOW()
+        WHERE id = $1 AND status = 'offered' AND offer_expires_at > NOW()
+        RETURNING writer_id, placement_id, slot_date
+        "#,
+        id,
+        booking_id
+    )
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    let Some(claimed) = claimed else {
+        return Ok(false);
+    };
+    db::slot_inventory::adjust_tx(
+        tx,
+        claimed.writer_id,
+        claimed.placement_id,
+        claimed.slot_date,
+        -1,
+    )
+    .await?;
+    Ok(true)
+}
+
+pub async fn expire_offers(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let expired = sqlx::query!(
+        r#"
+        UPDATE waitlist_entries
+        SET status = 'expired', updated_at = NOW()
+        WHERE status = 'offered' AND offer_expires_at <= NOW()
+        RETURNING writer_id, placement_id, slot_date
+        "#
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    for offer in &expired {
+        db::slot_inventory::adjust_tx(
+            &mut tx,
+            offer.writer_id,
+            offer.placement_id,
+            offer.slot_date,
+            -1,
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(expired.len() as u64)
+}
+
+#[derive(Debug)]
+pub struct WaitlistedSlot {
+    pub writer_id: Uuid,
+    pub placement_id: Uuid,
+    pub slot_date: NaiveDate,
+}
+
+pub async fn get_waitlisted_slots(pool: &PgPool) -> Result<Vec<WaitlistedSlot>, sqlx::Error> {
+    sqlx::query_as!(
+        WaitlistedSlot,
+        r#"
+        SELECT DISTINCT writer_id, placement_id, slot_date
+        FROM waitlist_entries
+        WHERE status = 'waiting' AND slot_date >= CURRENT_DATE - 1
+        ORDER BY slot_date
+        "#
+    )
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn lock_waiting_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    placement_id: Uuid,
+    slot_date: NaiveDate,
+) -> Result<Vec<WaitlistEntry>, sqlx::Error> {
+    sqlx::query_as!(
+        WaitlistEntry,
+        r#"
+        SELECT id, writer_id, placement_id, sponsor_id, slot_date,
+               status as "status: WaitlistStatus", offered_at,
+               offer_expires_at, claimed_booking_id, created_at, updated_at
+        FROM waitlist_entries
+        WHERE placement_id = $1 AND slot_date = $2 AND status = 'waiting'
+        FOR UPDATE SKIP LOCKED
+        "#,
+        placement_id,
+        slot_date
+    )
+    .fetch_all(&mut **tx)
+    .await
+}
+
+/// Caller holds the week lock and has checked there is room.
+pub async fn make_offer_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    entry: &WaitlistEntry,
+    token_hash: &str,
+    expires_at: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE waitlist_entries
+        SET status = 'offered', offer_token_hash = $2, offered_at = NOW(),
+            offer_expires_at = $3, updated_at = NOW()
+        WHERE id = $1
+        "#,
+        entry.id,
+        token_hash,
+        expires_at
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    db::slot_inventory::adjust_tx(tx, entry.writer_id, entry.placement_id, entry.slot_date, 1).await
+}
+
+pub async fn expire_waiting_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    placement_id: Uuid,
+    slot_date: NaiveDate,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE waitlist_entries
+        SET status = 'expired', updated_at = NOW()
+        WHERE placement_id = $1 AND slot_date = $2 AND status = 'waiting'
+        "#,
+        placement_id,
+        slot_date
+    )
+    .execute(&mut **tx)
+    .await?;
+    Ok(result.rows_affected())
+}
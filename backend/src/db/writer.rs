@@ -1,10 +1,17 @@
-use sqlx::PgPool;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::{FromRow, PgConnection, PgPool};
 use uuid::Uuid;
 
-use crate::models::{CreateWriter, UpdateWriter, Writer, WriterStats};
+use crate::directory::{Cursor, NewsletterSort};
+
+use crate::models::{
+    BookingStatus, ConflictingBooking, CreateWriter, PublishingFrequency, RatingSummary,
+    UpdateWriter, Writer, WriterStats,
+};
 
 pub async fn create_writer(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     user_id: Uuid,
     input: &CreateWriter,
 ) -> Result<Writer, sqlx::Error> {
@@ -12,8 +19,9 @@ pub async fn create_writer(
         Writer,
         r#"
         INSERT INTO writers (user_id, newsletter_name, newsletter_url, description,
-                            subscriber_count, price_per_slot, currency, lead_time_days, slots_per_week)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                            subscriber_count, price_per_slot, currency, lead_time_days, slots_per_week,
+                            issue_weekdays, issue_week_of_month, slots_per_issue, timezone)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
         RETURNING *
         "#,
         user_id,
@@ -24,9 +32,13 @@ pub async fn create_writer(
         input.price_per_slot,
         input.currency,
         input.lead_time_days,
-        input.slots_per_week
+        input.slots_per_week,
+        &input.issue_weekdays,
+        input.issue_week_of_month,
+        input.slots_per_issue,
+        input.timezone
     )
-    .fetch_one(pool)
+    .fetch_one(conn)
     .await
 }
 
@@ -62,8 +74,19 @@ pub async fn update_writer(
             lead_time_days = COALESCE($6, lead_time_days),
             slots_per_week = COALESCE($7, slots_per_week),
             auto_approve = COALESCE($8, auto_approve),
+            cancellation_window_days = COALESCE($9, cancellation_window_days),
+            late_cancellation_refund_pct = COALESCE($10, late_cancellation_refund_pct),
+            issue_weekdays = COALESCE($11, issue_weekdays),
+            issue_week_of_month = COALESCE($12, issue_week_of_month),
+            slots_per_issue = COALESCE($13, slots_per_issue),
+            timezone = COALESCE($14, timezone),
+            categories = COALESCE($16, categories),
+            tags = COALESCE($17, tags),
+            audience_countries = COALESCE($18, audience_countries),
+            audience_job_roles = COALESCE($19, audience_job_roles),
+            open_rate_pct = COALESCE($20, open_rate_pct),
             updated_at = NOW()
-        WHERE id = $9
+        WHERE id = $15
         RETURNING *
         "#,
         input.newsletter_name,
@@ -74,7 +97,18 @@ pub async fn update_writer(
         input.lead_time_days,
         input.slots_per_week,
         input.auto_approve,
-        writer_id
+        input.cancellation_window_days,
+        input.late_cancellation_refund_pct,
+        input.issue_weekdays.as_deref(),
+        input.issue_week_of_month,
+        input.slots_per_issue,
+        input.timezone,
+        writer_id,
+        input.categories.as_deref(),
+        input.tags.as_deref(),
+        input.audience_countries.as_deref(),
+        input.audience_job_roles.as_deref(),
+        input.open_rate_pct
     )
     .fetch_one(pool)
     .await
@@ -86,9 +120,13 @@ pub async fn get_writer_stats(pool: &PgPool, writer_id: Uuid) -> Result<WriterSt
         r#"
         SELECT
             COUNT(*) FILTER (WHERE status = 'published') as "total_published!",
-            COUNT(*) FILTER (WHERE status IN ('paid', 'approved')) as "pending_bookings!",
+            COUNT(*) FILTER (WHERE status IN ('paid', 'changes_requested', 'approved')) as "pending_bookings!",
             COALESCE(SUM(writer_payout_cents) FILTER (WHERE status = 'published'), 0) as "total_revenue_cents!",
-            COALESCE(SUM(writer_payout_cents) FILTER (WHERE status IN ('paid', 'approved')), 0) as "pending_revenue_cents!"
+            COALESCE(SUM(writer_payout_cents) FILTER (WHERE status IN ('paid', 'changes_requested', 'approved')), 0) as "pending_revenue_cents!",
+            (SELECT COUNT(*) FROM manual_bookings WHERE writer_id = $1) as "off_platform_bookings!",
+            (SELECT COALESCE(SUM(m.amount_cents), 0) FROM manual_bookings m
+             JOIN writers w ON w.id = m.writer_id
+             WHERE m.writer_id = $1 AND LOWER(m.currency) = LOWER(w.currency)) as "off_platform_revenue_cents!"
         FROM bookings
         WHERE writer_id = $1
         "#,
@@ -97,3 +135,343 @@ pub async fn get_writer_stats(pool: &PgPool, writer_id: Uuid) -> Result<WriterSt
     .fetch_one(pool)
     .await
 }
+
+pub async fn set_pause(
+    pool: &PgPool,
+    writer_id: Uuid,
+    starts_on: Option<NaiveDate>,
+    ends_on: Option<NaiveDate>,
+) -> Result<Writer, sqlx::Error> {
+    sqlx::query_as!(
+        Writer,
+        r#"
+        UPDATE writers
+        SET paused = TRUE, pause_starts_on = $2, pause_ends_on = $3, updated_at = NOW()
+        WHERE id = $1
+        RETURNING *
+        "#,
+        writer_id,
+        starts_on,
+        ends_on
+    )
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn clear_pause(pool: &PgPool, writer_id: Uuid) -> Result<Writer, sqlx::Error> {
+    sqlx::query_as!(
+        Writer,
+        r#"
+        UPDATE writers
+        SET paused = FALSE, pause_starts_on = NULL, pause_ends_on = NULL, updated_at = NOW()
+        WHERE id = $1
+        RETURNING *
+        "#,
+        writer_id
+    )
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn get_paid_bookings_between(
+    pool: &PgPool,
+    writer_id: Uuid,
+    from: NaiveDate,
+    to: Option<NaiveDate>,
+) -> Result<Vec<ConflictingBooking>, sqlx::Error> {
+    sqlx::query_as!(
+        ConflictingBooking,
+        r#"
+        SELECT b.id as booking_id, b.slot_date,
+               b.status as "status: BookingStatus",
+               s.company_name, b.ad_headline
+        FROM bookings b
+        JOIN sponsors s ON s.id = b.sponsor_id
+        WHERE b.writer_id = $1
+          AND b.status IN ('paid', 'changes_requested', 'approved')
+          AND b.slot_date >= $2
+          AND ($3::date IS NULL OR b.slot_date <= $3)
+        ORDER BY b.slot_date
+        "#,
+        writer_id,
+        from,
+        to
+    )
+    .fetch_all(pool)
+    .await
+}
+
+#[derive(Debug, Default)]
+pub struct NewsletterFilters {
+    /// Web search syntax
+    pub query: Option<String>,
+    pub currency: Option<String>,
+    pub min_price_cents: Option<i32>,
+    pub max_price_cents: Option<i32>,
+    pub min_subscribers: Option<i32>,
+    pub max_subscribers: Option<i32>,
+    pub category: Option<String>,
+    pub tag: Option<String>,
+    pub country: Option<String>,
+    pub job_role: Option<String>,
+    pub min_open_rate_pct: Option<Decimal>,
+    pub frequency: Option<PublishingFrequency>,
+}
+
+#[derive(Debug, FromRow)]
+pub struct NewsletterRow {
+    #[sqlx(flatten)]
+    pub writer: Writer,
+    pub price_from_cents: i32,
+    pub price_currency: String,
+    #[sqlx(flatten)]
+    pub rating: RatingSummary,
+    pub sort_key: f64,
+}
+
+/// Must match the expression of `idx_writers_search` for the index to be used
+const SEARCH_VECTOR: &str = "(setweight(to_tsvector('english', w.newsletter_name), 'A') \
+     || setweight(to_tsvector('english', coalesce(w.description, '')), 'B'))";
+
+/// Same rules as `PublishingSchedule::frequency`
+const FREQUENCY: &str = "(CASE \
+     WHEN w.issue_week_of_month <> 0 THEN 'monthly' \
+     WHEN cardinality(w.issue_weekdays) >= 5 THEN 'daily' \
+     WHEN cardinality(w.issue_weekdays) > 1 THEN 'several_times_a_week' \
+     ELSE 'weekly' END)";
+
+/// Prices are those of the cheapest active placement.
+pub async fn search_newsletters(
+    pool: &PgPool,
+    filters: &NewsletterFilters,
+    sort: NewsletterSort,
+    after: Option<Cursor>,
+    limit: i64,
+) -> Result<Vec<NewsletterRow>, sqlx::Error> {
+    let sort_key = match sort {
+        NewsletterSort::Relevance => format!(
+            "ts_rank({}, websearch_to_tsquery('english', $1))::float8",
+            SEARCH_VECTOR
+        ),
+        NewsletterSort::PriceAsc | NewsletterSort::PriceDesc => "p.price_cents::float8".into(),
+        NewsletterSort::Audience => "COALESCE(w.subscriber_count, 0)::float8".into(),
+    };
+    let (direction, comparison) = if sort.ascending() {
+        ("ASC", ">")
+    } else {
+        ("DESC", "<")
+    };
+
+    sqlx::query_as(&format!(
+        r#"
+        SELECT * FROM (
+            SELECT w.*, p.price_cents as price_from_cents, p.currency as price_currency,
+                   rv.review_count, rv.average_rating, {sort_key} as sort_key
+            FROM writers w
+            JOIN LATERAL (
+                SELECT price_cents, currency
+                FROM ad_placements
+                WHERE writer_id = w.id
+                  AND is_active
+                  AND ($2::text IS NULL OR lower(currency) = lower($2))
+                ORDER BY price_cents
+                LIMIT 1
+            ) p ON TRUE
+            CROSS JOIN LATERAL (
+                SELECT COUNT(*) as review_count, ROUND(AVG(rating), 2) as average_rating
+                FROM reviews
+                WHERE writer_id = w.id AND hidden_at IS NULL
+            ) rv
+            WHERE ($1::text IS NULL OR {SEARCH_VECTOR} @@ websearch_to_tsquery('english', $1))
+              AND ($3::int IS NULL OR p.price_cents >= $3)
+              AND ($4::int IS NULL OR p.price_cents <= $4)
+              AND ($5::int IS NULL OR w.subscriber_count >= $5)
+              AND ($6::int IS NULL OR w.subscriber_count <= $6)
+              AND ($10::text IS NULL OR w.categories @> ARRAY[$10])
+              AND ($11::text IS NULL OR w.tags @> ARRAY[$11])
+              AND ($12::text IS NULL OR w.audience_countries @> ARRAY[$12])
+              AND ($13::text IS NULL OR w.audience_job_roles @> ARRAY[$13])
+              AND ($14::numeric IS NULL OR w.open_rate_pct >= $14)
+              AND ($15::text IS NULL OR {FREQUENCY} = $15)
+        ) d
+        WHERE ($7::float8 IS NULL OR (d.sort_key, d.id) {comparison} ($7, $8::uuid))
+        ORDER BY d.sort_key {direction}, d.id {direction}
+        LIMIT $9
+        "#
+    ))
+    .bind(&filters.query)
+    .bind(&filters.currency)
+    .bind(filters.min_price_cents)
+    .bind(filters.max_price_cents)
+    .bind(filters.min_subscribers)
+    .bind(filters.max_subscribers)
+    .bind(after.map(|c| c.sort_key))
+    .bind(after.map(|c| c.id))
+    .bind(limit)
+    .bind(&filters.category)
+    .bind(&filters.tag)
+    .bind(&filters.country)
+    .bind(&filters.job_role)
+    .bind(filters.min_open_rate_pct)
+    .bind(filters.frequency.map(PublishingFrequency::as_str))
+    .fetch_all(pool)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db;
+    use crate::models::CreateAdPlacement;
+
+    async fn add_writer(
+        pool: &PgPool,
+        name: &str,
+        description: &str,
+        subscribers: i32,
+        price_cents: i32,
+    ) -> Uuid {
+        let user_id: Uuid = sqlx::query_scalar(
+            r#"
+            INSERT INTO users (email, first_name, last_name, password_hash, role)
+            VALUES ($1, 'Test', 'Writer', 'unused', 'writer')
+            RETURNING id
+            "#,
+        )
+        .bind(format!(
+            "{}@example.com",
+            name.to_lowercase().replace(' ', ".")
+        ))
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+        let mut conn = pool.acquire().await.unwrap();
+        let writer = create_writer(
+            &mut conn,
+            user_id,
+            &CreateWriter {
+                newsletter_name: name.into(),
+                newsletter_url: None,
+                description: Some(description.into()),
+                subscriber_count: Some(subscribers),
+                price_per_slot: price_cents,
+                currency: "usd".into(),
+                lead_time_days: 7,
+                slots_per_week: 1,
+                issue_weekdays: vec![1],
+                issue_week_of_month: 0,
+                slots_per_issue: 1,
+                timezone: "UTC".into(),
+            },
+        )
+        .await
+        .unwrap();
+        db::ad_placement::create_placement(
+            &mut conn,
+            writer.id,
+            &CreateAdPlacement {
+                name: "Sponsored slot".into(),
+                description: None,
+                price_cents,
+                currency: "usd".into(),
+                slots_per_issue: 1,
+                max_headline_length: 100,
+                max_body_length: 500,
+                allows_image: true,
+            },
+        )
+        .await
+        .unwrap();
+        writer.id
+    }
+
+    async fn search(
+        pool: &PgPool,
+        filters: &NewsletterFilters,
+        sort: NewsletterSort,
+        after: Option<Cursor>,
+        limit: i64,
+    ) -> Vec<NewsletterRow> {
+        search_newsletters(pool, filters, sort, after, limit)
+            .await
+            .unwrap()
+    }
+
+    fn names(rows: &[NewsletterRow]) -> Vec<&str> {
+        rows.iter()
+            .map(|r| r.writer.newsletter_name.as_str())
+            .collect()
+    }
+
+    // Runs against a scratch database: DATABASE_URL=... cargo test -- --ignored
+    #[sqlx::test]
+    #[ignore = "needs a Postgres server in DATABASE_URL"]
+    async fn test_search_newsletters(pool: PgPool) {
+        add_writer(&pool, "Rust Weekly", "Systems programming news", 5000, 3000).await;
+        add_writer(
+            &pool,
+            "Design Digest",
+            "A writer's notes on design",
+            20000,
+            1000,
+        )
+        .await;
+
+        let matching = |q: &str| NewsletterFilters {
+            query: Some(q.into()),
+            ..Default::default()
+        };
+        let rows = search(
+            &pool,
+            &matching("rust"),
+            NewsletterSort::Relevance,
+            None,
+            10,
+        )
+        .await;
+        assert_eq!(names(&rows), ["Rust Weekly"]);
+        let rows = search(
+            &pool,
+            &matching("writer's"),
+            NewsletterSort::Relevance,
+            None,
+            10,
+        )
+        .await;
+        assert_eq!(names(&rows), ["Design Digest"]);
+
+        let pricier = NewsletterFilters {
+            min_price_cents: Some(2000),
+            ..Default::default()
+        };
+        let rows = search(&pool, &pricier, NewsletterSort::PriceAsc, None, 10).await;
+        assert_eq!(names(&rows), ["Rust Weekly"]);
+        assert_eq!(rows[0].price_from_cents, 3000);
+
+        let weekly = NewsletterFilters {
+            frequency: Some(PublishingFrequency::Weekly),
+            ..Default::default()
+        };
+        let rows = search(&pool, &weekly, NewsletterSort::PriceAsc, None, 10).await;
+        assert_eq!(names(&rows), ["Design Digest", "Rust Weekly"]);
+        let daily = NewsletterFilters {
+            frequency: Some(PublishingFrequency::Daily),
+            ..Default::default()
+        };
+        assert!(search(&pool, &daily, NewsletterSort::PriceAsc, None, 10)
+            .await
+            .is_empty());
+
+        // Keyset paging picks up after the cursor
+        let all = NewsletterFilters::default();
+        let first = search(&pool, &all, NewsletterSort::Audience, None, 1).await;
+        assert_eq!(names(&first), ["Design Digest"]);
+        let after = Cursor {
+            sort_key: first[0].sort_key,
+            id: first[0].writer.id,
+        };
+        let second = search(&pool, &all, NewsletterSort::Audience, Some(after), 1).await;
+        assert_eq!(names(&second), ["Rust Weekly"]);
+    }
+}
@@ -0,0 +1,96 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::Writer;
+
+#[derive(Debug)]
+pub struct SlugMatch {
+    pub writer_id: Uuid,
+    pub current_slug: Option<String>,
+    /// Given up for `current_slug`, so it redirects
+    pub retired: bool,
+}
+
+pub async fn resolve_slug(pool: &PgPool, slug: &str) -> Result<Option<SlugMatch>, sqlx::Error> {
+    sqlx::query_as!(
+        SlugMatch,
+        r#"
+        SELECT id as "writer_id!", slug as current_slug, FALSE as "retired!"
+        FROM writers
+        WHERE slug = $1
+        UNION ALL
+        SELECT w.id, w.slug, TRUE
+        FROM writer_slug_history h
+        JOIN writers w ON w.id = h.writer_id
+        WHERE h.slug = $1
+        LIMIT 1
+        "#,
+        slug
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+/// Retired slugs stay taken.
+pub async fn is_slug_taken(
+    pool: &PgPool,
+    slug: &str,
+    writer_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM writers WHERE slug = $1 AND id <> $2
+            UNION ALL
+            SELECT 1 FROM writer_slug_history WHERE slug = $1 AND writer_id <> $2
+        ) as "taken!"
+        "#,
+        slug,
+        writer_id
+    )
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn set_slug(pool: &PgPool, writer_id: Uuid, slug: &str) -> Result<Writer, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO writer_slug_history (slug, writer_id)
+        SELECT slug, id FROM writers
+        WHERE id = $1 AND slug IS NOT NULL AND slug <> $2
+        ON CONFLICT (slug) DO UPDATE SET retired_at = NOW()
+        "#,
+        writer_id,
+        slug
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Taking back an old slug makes it current again
+    sqlx::query!(
+        "DELETE FROM writer_slug_history WHERE slug = $1 AND writer_id = $2",
+        slug,
+        writer_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let writer = sqlx::query_as!(
+        Writer,
+        r#"
+        UPDATE writers
+        SET slug = $2, updated_at = NOW()
+        WHERE id = $1
+        RETURNING *
+        "#,
+        writer_id,
+        slug
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(writer)
+}
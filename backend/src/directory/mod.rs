@@ -0,0 +1,167 @@
+//! Keyset pagination is on the sort key with the writer id as tie-breaker.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Category {
+    pub slug: &'static str,
+    pub name: &'static str,
+}
+
+/// Slugs are stored on writers, so existing ones must not be renamed
+pub const CATEGORIES: &[Category] = &[
+    category("business", "Business"),
+    category("careers", "Careers"),
+    category("crypto", "Crypto & Web3"),
+    category("culture", "Culture"),
+    category("design", "Design"),
+    category("education", "Education"),
+    category("finance", "Finance & Investing"),
+    category("food", "Food & Drink"),
+    category("gaming", "Gaming"),
+    category("health", "Health & Fitness"),
+    category("marketing", "Marketing"),
+    category("news", "News & Politics"),
+    category("parenting", "Parenting"),
+    category("product", "Product Management"),
+    category("science", "Science"),
+    category("software", "Software Engineering"),
+    category("sports", "Sports"),
+    category("startups", "Startups"),
+    category("technology", "Technology"),
+    category("travel", "Travel"),
+];
+
+const fn category(slug: &'static str, name: &'static str) -> Category {
+    Category { slug, name }
+}
+
+pub fn is_category(slug: &str) -> bool {
+    CATEGORIES.iter().any(|c| c.slug == slug)
+}
+
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+pub const MAX_PAGE_SIZE: i64 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NewsletterSort {
+    /// Best full-text match first; needs a search query
+    Relevance,
+    PriceAsc,
+    PriceDesc,
+    Audience,
+}
+
+impl NewsletterSort {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Relevance => "relevance",
+            Self::PriceAsc => "price_asc",
+            Self::PriceDesc => "price_desc",
+            Self::Audience => "audience",
+        }
+    }
+
+    pub fn ascending(self) -> bool {
+        matches!(self, Self::PriceAsc)
+    }
+}
+
+pub const MAX_QUERY_LENGTH: usize = 200;
+
+/// Not HTML-escaped: it is bound to `websearch_to_tsquery`, which would search for entities as words.
+pub fn search_query(q: Option<&str>) -> AppResult<Option<String>> {
+    let query = q.map(str::trim).filter(|q| !q.is_empty());
+    if query.is_some_and(|q| q.chars().count() > MAX_QUERY_LENGTH) {
+        return Err(AppError::Validation(format!(
+            "Search query exceeds maximum length of {} characters",
+            MAX_QUERY_LENGTH
+        )));
+    }
+    Ok(query.map(str::to_string))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cursor {
+    pub sort_key: f64,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    pub fn encode(&self, sort: NewsletterSort) -> String {
+        URL_SAFE_NO_PAD.encode(format!("{}|{}|{}", sort.as_str(), self.sort_key, self.id))
+    }
+
+    pub fn decode(cursor: &str, sort: NewsletterSort) -> AppResult<Self> {
+        let invalid = || AppError::BadRequest("Invalid cursor".into());
+
+        let bytes = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+        let text = String::from_utf8(bytes).map_err(|_| invalid())?;
+
+        let mut parts = text.splitn(3, '|');
+        let (Some(cursor_sort), Some(sort_key), Some(id)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+
+        if cursor_sort != sort.as_str() {
+            return Err(AppError::BadRequest(
+                "Cursor belongs to a different sort order".into(),
+            ));
+        }
+
+        Ok(Self {
+            sort_key: sort_key
+                .parse::<f64>()
+                .ok()
+                .filter(|k| k.is_finite())
+                .ok_or_else(invalid)?,
+            id: id.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = Cursor {
+            sort_key: 0.0607927,
+            id: Uuid::now_v7(),
+        };
+
+        let encoded = cursor.encode(NewsletterSort::Relevance);
+        assert_eq!(
+            Cursor::decode(&encoded, NewsletterSort::Relevance).unwrap(),
+            cursor
+        );
+        assert!(Cursor::decode(&encoded, NewsletterSort::Audience).is_err());
+    }
+
+    #[test]
+    fn test_search_query_is_kept_as_typed() {
+        assert_eq!(
+            search_query(Some("  writer's \"deep dive\" -crypto ")).unwrap(),
+            Some(r#"writer's "deep dive" -crypto"#.to_string())
+        );
+        assert_eq!(search_query(Some("   ")).unwrap(), None);
+        assert_eq!(search_query(None).unwrap(), None);
+        assert!(search_query(Some(&"a".repeat(MAX_QUERY_LENGTH + 1))).is_err());
+    }
+
+    #[test]
+    fn test_cursor_rejects_garbage() {
+        assert!(Cursor::decode("not a cursor", NewsletterSort::PriceAsc).is_err());
+        let tampered = URL_SAFE_NO_PAD.encode("price_asc|NaN|00000000-0000-0000-0000-000000000000");
+        assert!(Cursor::decode(&tampered, NewsletterSort::PriceAsc).is_err());
+    }
+}
@@ -1,9 +1,14 @@
+use chrono::NaiveDate;
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use crate::calendar::{self, PublishingSchedule};
 use crate::db;
 use crate::error::{AppError, AppResult};
-use crate::models::{Booking, Sponsor, Writer};
+use crate::models::{
+    AdPlacement, Booking, SavedList, Sponsor, UserRole, WaitlistEntry, WaitlistStatus, Writer,
+};
+use crate::services::AuthenticatedUser;
 
 pub async fn get_writer_or_404(pool: &PgPool, id: Uuid) -> AppResult<Writer> {
     db::writer::get_writer_by_id(pool, id)
@@ -35,6 +40,102 @@ pub async fn get_booking_or_404(pool: &PgPool, id: Uuid) -> AppResult<Booking> {
         .ok_or_else(|| AppError::NotFound("Booking not found".into()))
 }
 
+pub async fn get_placement_or_404(pool: &PgPool, id: Uuid) -> AppResult<AdPlacement> {
+    db::ad_placement::get_placement_by_id(pool, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Ad placement not found".into()))
+}
+
+/// Falls back to the writer's default placement.
+pub async fn get_offered_placement(
+    pool: &PgPool,
+    writer: &Writer,
+    placement_id: Option<Uuid>,
+) -> AppResult<AdPlacement> {
+    let placement = match placement_id {
+        Some(id) => Some(get_placement_or_404(pool, id).await?),
+        None => db::ad_placement::get_default_placement(pool, writer.id).await?,
+    };
+
+    match placement {
+        Some(placement) if placement.writer_id == writer.id && placement.is_active => Ok(placement),
+        Some(_) => Err(AppError::BadRequest(
+            "This ad placement is not offered by the newsletter".into(),
+        )),
+        None => Err(AppError::BadRequest(
+            "This newsletter has no ad placements available".into(),
+        )),
+    }
+}
+
+pub async fn get_saved_list_or_404(
+    pool: &PgPool,
+    id: Uuid,
+    sponsor_id: Uuid,
+) -> AppResult<SavedList> {
+    db::saved_list::get_saved_list_by_id(pool, id)
+        .await?
+        .filter(|list| list.sponsor_id == sponsor_id)
+        .ok_or_else(|| AppError::NotFound("Saved list not found".into()))
+}
+
+pub async fn get_waitlist_offer_or_404(
+    pool: &PgPool,
+    token_hash: &str,
+    sponsor_id: Uuid,
+) -> AppResult<WaitlistEntry> {
+    let entry = db::waitlist::get_offer_by_token_hash(pool, token_hash)
+        .await?
+        .filter(|e| e.sponsor_id == sponsor_id)
+        .ok_or_else(|| AppError::NotFound("Waitlist offer not found".into()))?;
+
+    let outstanding = entry.status == WaitlistStatus::Offered
+        && entry
+            .offer_expires_at
+            .is_some_and(|at| at > chrono::Utc::now());
+    if !outstanding {
+        return Err(AppError::Conflict("This waitlist offer has expired".into()));
+    }
+
+    Ok(entry)
+}
+
+pub fn require_lead_time(writer: &Writer, slot_date: NaiveDate) -> AppResult<()> {
+    let today = calendar::today_in(&writer.timezone);
+    let min_date = today + chrono::Duration::days(writer.lead_time_days as i64);
+    if slot_date < min_date {
+        return Err(AppError::BadRequest(format!(
+            "Slot date must be at least {} days in the future",
+            writer.lead_time_days
+        )));
+    }
+    Ok(())
+}
+
+pub fn require_not_paused(writer: &Writer, slot_date: NaiveDate) -> AppResult<()> {
+    if !writer.is_paused_on(slot_date) {
+        return Ok(());
+    }
+    let message = match writer.pause_ends_on {
+        Some(end) => format!(
+            "{} is not taking sponsorships until after {}",
+            writer.newsletter_name, end
+        ),
+        None => format!("{} has paused sponsorships for now", writer.newsletter_name),
+    };
+    Err(AppError::BadRequest(message))
+}
+
+pub fn require_issue_date(writer: &Writer, slot_date: NaiveDate) -> AppResult<()> {
+    if !PublishingSchedule::for_writer(writer).is_issue_date(slot_date) {
+        return Err(AppError::BadRequest(format!(
+            "{} is not an issue date for {}",
+            slot_date, writer.newsletter_name
+        )));
+    }
+    Ok(())
+}
+
 pub fn require_writer_ownership(writer: &Writer, user_id: Uuid, is_admin: bool) -> AppResult<()> {
     if writer.user_id != user_id && !is_admin {
         return Err(AppError::Forbidden);
@@ -59,3 +160,24 @@ pub fn require_booking_ownership_by_writer(booking: &Booking, writer_id: Uuid) -
     }
     Ok(())
 }
+
+pub async fn require_booking_access(
+    pool: &PgPool,
+    booking: &Booking,
+    user: &AuthenticatedUser,
+) -> AppResult<()> {
+    let allowed = match user.role {
+        UserRole::Admin => true,
+        UserRole::Writer => db::writer::get_writer_by_user_id(pool, user.id)
+            .await?
+            .is_some_and(|w| w.id == booking.writer_id),
+        UserRole::Sponsor => db::sponsor::get_sponsor_by_user_id(pool, user.id)
+            .await?
+            .is_some_and(|s| s.id == booking.sponsor_id),
+    };
+
+    if !allowed {
+        return Err(AppError::Forbidden);
+    }
+    Ok(())
+}
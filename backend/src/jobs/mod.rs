@@ -0,0 +1,50 @@
+//! Background jobs. Every job must be safe to run on several instances at once.
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::error::AppResult;
+use crate::state::AppState;
+
+mod overdue_publications;
+mod pending_payments;
+mod waitlist_offers;
+
+pub fn spawn_all(state: &AppState) {
+    spawn_every(
+        "expire_pending_payments",
+        Duration::from_secs(state.config.bookings.reaper_interval_secs),
+        state.clone(),
+        pending_payments::expire_abandoned_bookings,
+    );
+    spawn_every(
+        "overdue_publications",
+        Duration::from_secs(state.config.bookings.overdue_check_interval_secs),
+        state.clone(),
+        overdue_publications::process_overdue_publications,
+    );
+    spawn_every(
+        "waitlist_offers",
+        Duration::from_secs(state.config.bookings.reaper_interval_secs),
+        state.clone(),
+        waitlist_offers::offer_freed_slots,
+    );
+}
+
+fn spawn_every<F, Fut>(name: &'static str, period: Duration, state: AppState, job: F)
+where
+    F: Fn(AppState) -> Fut + Send + 'static,
+    Fut: Future<Output = AppResult<()>> + Send,
+{
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(period);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if let Err(e) = job(state.clone()).await {
+                tracing::error!(job = name, "Background job failed: {}", e);
+            }
+        }
+    });
+}
@@ -0,0 +1,228 @@
+use chrono::Duration;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::calendar;
+use crate::db;
+use crate::db::sponsor::OverdueBooking;
+use crate::error::AppResult;
+use crate::lifecycle::{self, Actor};
+use crate::models::{BookingEventType, BookingStatus};
+use crate::services::PublicationOverdueData;
+use crate::state::AppState;
+
+const BATCH_SIZE: usize = 100;
+
+#[derive(Debug, Clone, Copy)]
+enum OverdueStep {
+    Remind,
+    Escalate,
+    Refund,
+}
+
+impl OverdueStep {
+    // Steps always run in order, one per pass, so a sponsor is never refunded
+    // before the writer was reminded and admins were alerted
+    fn next(booking: &OverdueBooking) -> Self {
+        if !booking.reminded {
+            OverdueStep::Remind
+        } else if !booking.escalated {
+            OverdueStep::Escalate
+        } else {
+            OverdueStep::Refund
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct OverdueSchedule {
+    escalate_after_days: i64,
+    refund_after_days: i64,
+}
+
+impl OverdueSchedule {
+    fn new(escalation_days: i64, refund_days: i64) -> Self {
+        Self {
+            escalate_after_days: escalation_days,
+            refund_after_days: refund_days.max(escalation_days) - escalation_days,
+        }
+    }
+
+    fn days_until_refund(&self, step: OverdueStep) -> i64 {
+        match step {
+            OverdueStep::Remind => self.escalate_after_days + self.refund_after_days,
+            OverdueStep::Escalate => self.refund_after_days,
+            OverdueStep::Refund => 0,
+        }
+    }
+}
+
+pub async fn process_overdue_publications(state: AppState) -> AppResult<()> {
+    let config = &state.config.bookings;
+    let schedule = OverdueSchedule::new(config.overdue_escalation_days, config.overdue_refund_days);
+
+    // Each booking takes at most one step per pass, and bookings that failed are
+    // retried on the next pass
+    let mut seen: Vec<Uuid> = Vec::new();
+
+    for _ in 0..BATCH_SIZE {
+        let mut tx = state.db.begin().await?;
+
+        let Some(booking) = db::sponsor::lock_next_overdue_booking(
+            &mut tx,
+            schedule.escalate_after_days as i32,
+            schedule.refund_after_days as i32,
+            &seen,
+        )
+        .await?
+        else {
+            return Ok(());
+        };
+
+        let step = OverdueStep::next(&booking);
+        seen.push(booking.id);
+
+        if let Err(e) = apply_step(&state, &mut tx, &booking, step).await {
+            tracing::error!(
+                booking_id = %booking.id,
+                step = ?step,
+                "Failed to process overdue booking: {}",
+                e
+            );
+            continue;
+        }
+
+        tx.commit().await?;
+
+        tracing::info!(
+            booking_id = %booking.id,
+            slot_date = %booking.slot_date,
+            step = ?step,
+            "Processed overdue publication"
+        );
+
+        notify(&state, &booking, step, schedule).await;
+    }
+
+    Ok(())
+}
+
+async fn apply_step(
+    state: &AppState,
+    tx: &mut Transaction<'_, Postgres>,
+    booking: &OverdueBooking,
+    step: OverdueStep,
+) -> AppResult<()> {
+    match step {
+        OverdueStep::Remind => {
+            lifecycle::record_event_tx(
+                tx,
+                booking.id,
+                BookingEventType::PublicationReminder,
+                Actor::system(),
+                Some("Slot date passed without publication"),
+            )
+            .await?;
+        }
+        OverdueStep::Escalate => {
+            lifecycle::record_event_tx(
+                tx,
+                booking.id,
+                BookingEventType::PublicationEscalated,
+                Actor::system(),
+                Some("Still not published after reminder"),
+            )
+            .await?;
+        }
+        OverdueStep::Refund => {
+            let refunded = lifecycle::transition_tx(
+                tx,
+                booking.id,
+                BookingStatus::Refunded,
+                Actor::system(),
+                Some("Not published after reminder and escalation"),
+            )
+            .await?;
+
+            db::sponsor::record_booking_refund(tx, booking.id, booking.amount_cents, 0, 0).await?;
+
+            // Refund while the booking row is still locked, so the refund webhook
+            // only ever sees the booking as refunded
+            lifecycle::refund_payment(state, &refunded, booking.amount_cents).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn notify(
+    state: &AppState,
+    booking: &OverdueBooking,
+    step: OverdueStep,
+    schedule: OverdueSchedule,
+) {
+    let Some(email_service) = &state.email else {
+        return;
+    };
+
+    let writer_timezone = booking.writer_timezone.as_str();
+    let email_data = |recipient_timezone: &str| PublicationOverdueData {
+        newsletter_name: booking.newsletter_name.clone(),
+        sponsor_name: booking.company_name.clone(),
+        slot_date: calendar::format_writer_date(
+            booking.slot_date,
+            writer_timezone,
+            recipient_timezone,
+        ),
+        ad_headline: booking.ad_headline.clone(),
+        amount_cents: booking.amount_cents,
+        currency: booking.currency.clone(),
+        refund_date: calendar::format_writer_date(
+            calendar::today_in(writer_timezone) + Duration::days(schedule.days_until_refund(step)),
+            writer_timezone,
+            recipient_timezone,
+        ),
+        dashboard_url: format!("{}/dashboard/bookings", state.config.server.frontend_url),
+    };
+
+    match step {
+        OverdueStep::Remind => {
+            if let Err(e) = email_service
+                .send_publication_reminder(&booking.writer_email, email_data(writer_timezone))
+                .await
+            {
+                tracing::warn!("Failed to send publication reminder email: {}", e);
+            }
+        }
+        OverdueStep::Escalate => {
+            let admin_emails = match db::user::get_admin_emails(&state.db).await {
+                Ok(emails) => emails,
+                Err(e) => {
+                    tracing::warn!("Failed to load admin emails: {}", e);
+                    return;
+                }
+            };
+            if admin_emails.is_empty() {
+                tracing::warn!(booking_id = %booking.id, "No admins to escalate overdue booking to");
+            }
+            for admin_email in &admin_emails {
+                if let Err(e) = email_service
+                    .send_publication_escalation(admin_email, email_data(writer_timezone))
+                    .await
+                {
+                    tracing::warn!("Failed to send publication escalation email: {}", e);
+                }
+            }
+        }
+        OverdueStep::Refund => {
+            if let Some(billing_email) = &booking.billing_email {
+                if let Err(e) = email_service
+                    .send_overdue_refund(billing_email, email_data(&booking.sponsor_timezone))
+                    .await
+                {
+                    tracing::warn!("Failed to send overdue refund email: {}", e);
+                }
+            }
+        }
+    }
+}
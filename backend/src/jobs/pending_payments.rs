@@ -0,0 +1,63 @@
+use chrono::Utc;
+
+use crate::db;
+use crate::error::AppResult;
+use crate::lifecycle::{self, Actor};
+use crate::models::BookingStatus;
+use crate::state::AppState;
+
+const BATCH_SIZE: i64 = 100;
+
+// Extra time after the checkout expires, so a payment that was submitted just
+// before the deadline can still be confirmed by its webhook
+const EXPIRY_GRACE_MINUTES: i64 = 5;
+
+/// Slots are released at once; bookings are cancelled after the grace period.
+pub async fn expire_abandoned_bookings(state: AppState) -> AppResult<()> {
+    loop {
+        let mut tx = state.db.begin().await?;
+        let released = db::slot_hold::release_expired_holds(&mut tx, BATCH_SIZE).await?;
+        tx.commit().await?;
+
+        if !released.is_empty() {
+            tracing::info!(count = released.len(), "Released expired slot holds");
+        }
+        if (released.len() as i64) < BATCH_SIZE {
+            break;
+        }
+    }
+
+    let expired_before = Utc::now() - chrono::Duration::minutes(EXPIRY_GRACE_MINUTES);
+
+    loop {
+        let mut tx = state.db.begin().await?;
+
+        let expired =
+            db::sponsor::lock_expired_pending_bookings(&mut tx, expired_before, BATCH_SIZE).await?;
+
+        for booking in &expired {
+            lifecycle::transition_tx(
+                &mut tx,
+                booking.id,
+                BookingStatus::Cancelled,
+                Actor::system(),
+                Some("Checkout expired without payment"),
+            )
+            .await?;
+
+            tracing::info!(
+                booking_id = %booking.id,
+                writer_id = %booking.writer_id,
+                slot_date = %booking.slot_date,
+                expired_at = ?booking.payment_expires_at,
+                "Released slot held by abandoned checkout"
+            );
+        }
+
+        tx.commit().await?;
+
+        if (expired.len() as i64) < BATCH_SIZE {
+            return Ok(());
+        }
+    }
+}
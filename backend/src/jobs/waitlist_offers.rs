@@ -0,0 +1,130 @@
+use chrono::{Duration, Utc};
+
+use crate::calendar;
+use crate::db;
+use crate::db::waitlist::WaitlistedSlot;
+use crate::error::AppResult;
+use crate::helpers::{get_placement_or_404, get_sponsor_or_404};
+use crate::models::{WaitlistEntry, Writer};
+use crate::services::WaitlistOfferData;
+use crate::state::AppState;
+use crate::waitlist;
+
+/// Compares waitlisted slots with what is open now instead of hooking every way a slot frees up.
+pub async fn offer_freed_slots(state: AppState) -> AppResult<()> {
+    let expired = db::waitlist::expire_offers(&state.db).await?;
+    if expired > 0 {
+        tracing::info!(count = expired, "Expired unclaimed waitlist offers");
+    }
+
+    for slot in db::waitlist::get_waitlisted_slots(&state.db).await? {
+        if let Err(e) = offer_slot(&state, &slot).await {
+            tracing::error!(
+                placement_id = %slot.placement_id,
+                slot_date = %slot.slot_date,
+                "Failed to process waitlist: {}",
+                e
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn offer_slot(state: &AppState, slot: &WaitlistedSlot) -> AppResult<()> {
+    let mut tx = state.db.begin().await?;
+
+    let (writer, remaining) = db::sponsor::lock_slots_remaining_tx(
+        &mut tx,
+        slot.writer_id,
+        slot.placement_id,
+        slot.slot_date,
+    )
+    .await?;
+
+    // Once the date is inside the writer's lead time nobody can book it
+    let min_date =
+        calendar::today_in(&writer.timezone) + Duration::days(writer.lead_time_days as i64);
+    if slot.slot_date < min_date {
+        db::waitlist::expire_waiting_tx(&mut tx, slot.placement_id, slot.slot_date).await?;
+        tx.commit().await?;
+        return Ok(());
+    }
+
+    let expires_at = Utc::now() + Duration::hours(state.config.bookings.waitlist_offer_hours);
+    let queue = db::waitlist::lock_waiting_tx(&mut tx, slot.placement_id, slot.slot_date).await?;
+    let count = waitlist::offers_to_make(remaining);
+    let mut offers = Vec::new();
+
+    for entry in waitlist::next_in_line(&queue, count) {
+        let token = state.auth.generate_random_token();
+        db::waitlist::make_offer_tx(&mut tx, entry, &state.auth.hash_token(&token), expires_at)
+            .await?;
+        offers.push((entry, token));
+    }
+
+    tx.commit().await?;
+
+    for (entry, token) in &offers {
+        tracing::info!(
+            waitlist_entry_id = %entry.id,
+            sponsor_id = %entry.sponsor_id,
+            slot_date = %entry.slot_date,
+            "Offered freed slot to waitlisted sponsor"
+        );
+
+        if let Err(e) = notify(state, &writer, entry, token, expires_at).await {
+            tracing::warn!("Failed to send waitlist offer email: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+async fn notify(
+    state: &AppState,
+    writer: &Writer,
+    entry: &WaitlistEntry,
+    token: &str,
+    expires_at: chrono::DateTime<Utc>,
+) -> AppResult<()> {
+    let Some(email_service) = &state.email else {
+        return Ok(());
+    };
+
+    let sponsor = get_sponsor_or_404(&state.db, entry.sponsor_id).await?;
+    let Some(billing_email) = &sponsor.billing_email else {
+        return Ok(());
+    };
+    let placement = get_placement_or_404(&state.db, entry.placement_id).await?;
+    let price_cents =
+        db::availability::get_slot_prices(&state.db, writer, &placement, &[entry.slot_date])
+            .await?[0];
+
+    let sponsor_tz = calendar::timezone(&sponsor.timezone);
+
+    email_service
+        .send_waitlist_offer(
+            billing_email,
+            WaitlistOfferData {
+                newsletter_name: writer.newsletter_name.clone(),
+                placement_name: placement.name,
+                slot_date: calendar::format_writer_date(
+                    entry.slot_date,
+                    &writer.timezone,
+                    &sponsor.timezone,
+                ),
+                price_cents,
+                currency: placement.currency,
+                expires_at: expires_at
+                    .with_timezone(&sponsor_tz)
+                    .format("%A, %-d %B %Y %H:%M %Z")
+                    .to_string(),
+                offer_url: format!(
+                    "{}/waitlist/offers/{}",
+                    state.config.server.frontend_url, token
+                ),
+            },
+        )
+        .await
+}
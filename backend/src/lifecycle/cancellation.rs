@@ -0,0 +1,214 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{Booking, BookingStatus, Writer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundKind {
+    None,
+    Full,
+    Partial,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CancellationQuote {
+    pub refund_kind: RefundKind,
+    pub refund_cents: i32,
+    pub retained_cents: i32,
+    pub days_before_slot: i64,
+    pub cancellation_window_days: i32,
+}
+
+/// No refund once the writer has approved the booking.
+pub fn quote_cancellation(
+    booking: &Booking,
+    writer: &Writer,
+    today: NaiveDate,
+) -> AppResult<CancellationQuote> {
+    let days_before_slot = (booking.slot_date - today).num_days();
+
+    let (refund_kind, refund_cents) = match booking.status {
+        // Nothing has been charged yet
+        BookingStatus::PendingPayment => (RefundKind::None, 0),
+        BookingStatus::Paid | BookingStatus::ChangesRequested => {
+            if days_before_slot > writer.cancellation_window_days as i64 {
+                (RefundKind::Full, booking.amount_cents)
+            } else {
+                let pct = writer.late_cancellation_refund_pct.clamp(0, 100);
+                let cents = (booking.amount_cents as i64 * pct as i64 / 100) as i32;
+                let kind = if cents > 0 {
+                    RefundKind::Partial
+                } else {
+                    RefundKind::None
+                };
+                (kind, cents)
+            }
+        }
+        BookingStatus::Approved => (RefundKind::None, 0),
+        BookingStatus::Published => {
+            return Err(AppError::BadRequest(
+                "Published bookings can no longer be cancelled".into(),
+            ));
+        }
+        _ => {
+            return Err(AppError::BadRequest(
+                "This booking is already closed".into(),
+            ));
+        }
+    };
+
+    let retained_cents = if booking.status == BookingStatus::PendingPayment {
+        0
+    } else {
+        booking.amount_cents - refund_cents
+    };
+
+    Ok(CancellationQuote {
+        refund_kind,
+        refund_cents,
+        retained_cents,
+        days_before_slot,
+        cancellation_window_days: writer.cancellation_window_days,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    fn writer() -> Writer {
+        Writer {
+            id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            slug: None,
+            newsletter_name: "Test".into(),
+            newsletter_url: None,
+            description: None,
+            subscriber_count: None,
+            price_per_slot: 10_000,
+            currency: "usd".into(),
+            lead_time_days: 7,
+            slots_per_week: 1,
+            issue_weekdays: vec![1],
+            issue_week_of_month: 0,
+            slots_per_issue: 1,
+            auto_approve: false,
+            platform_fee_pct: Decimal::from(10),
+            cancellation_window_days: 7,
+            late_cancellation_refund_pct: 50,
+            timezone: "UTC".into(),
+            paused: false,
+            pause_starts_on: None,
+            pause_ends_on: None,
+            categories: Vec::new(),
+            tags: Vec::new(),
+            audience_countries: Vec::new(),
+            audience_job_roles: Vec::new(),
+            open_rate_pct: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn booking(status: BookingStatus, slot_date: NaiveDate) -> Booking {
+        Booking {
+            id: Uuid::nil(),
+            writer_id: Uuid::nil(),
+            sponsor_id: Uuid::nil(),
+            slot_date,
+            ad_headline: "Headline".into(),
+            ad_body: "Body".into(),
+            ad_cta_text: None,
+            ad_cta_url: "https://example.com".into(),
+            ad_image_url: None,
+            status,
+            amount_cents: 10_000,
+            platform_fee_cents: 1_000,
+            writer_payout_cents: 9_000,
+            currency: "usd".into(),
+            lemon_order_id: None,
+            created_at: Utc::now(),
+            paid_at: None,
+            approved_at: None,
+            rejected_at: None,
+            published_at: None,
+            refunded_cents: 0,
+            cancelled_at: None,
+            payment_expires_at: None,
+            bundle_id: None,
+            published_url: None,
+            publication_proof_url: None,
+            placement_id: Uuid::nil(),
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_full_refund_outside_window() {
+        let quote = quote_cancellation(
+            &booking(BookingStatus::Paid, date("2025-03-20")),
+            &writer(),
+            date("2025-03-01"),
+        )
+        .unwrap();
+        assert_eq!(quote.refund_kind, RefundKind::Full);
+        assert_eq!(quote.refund_cents, 10_000);
+        assert_eq!(quote.retained_cents, 0);
+    }
+
+    #[test]
+    fn test_partial_refund_inside_window() {
+        let quote = quote_cancellation(
+            &booking(BookingStatus::Paid, date("2025-03-08")),
+            &writer(),
+            date("2025-03-01"),
+        )
+        .unwrap();
+        assert_eq!(quote.refund_kind, RefundKind::Partial);
+        assert_eq!(quote.refund_cents, 5_000);
+        assert_eq!(quote.retained_cents, 5_000);
+    }
+
+    #[test]
+    fn test_pending_payment_has_nothing_to_refund() {
+        let quote = quote_cancellation(
+            &booking(BookingStatus::PendingPayment, date("2025-03-08")),
+            &writer(),
+            date("2025-03-01"),
+        )
+        .unwrap();
+        assert_eq!(quote.refund_kind, RefundKind::None);
+        assert_eq!(quote.retained_cents, 0);
+    }
+
+    #[test]
+    fn test_approved_cancels_without_refund() {
+        let quote = quote_cancellation(
+            &booking(BookingStatus::Approved, date("2025-03-20")),
+            &writer(),
+            date("2025-03-01"),
+        )
+        .unwrap();
+        assert_eq!(quote.refund_kind, RefundKind::None);
+        assert_eq!(quote.refund_cents, 0);
+        assert_eq!(quote.retained_cents, 10_000);
+    }
+
+    #[test]
+    fn test_published_cannot_be_cancelled() {
+        assert!(quote_cancellation(
+            &booking(BookingStatus::Published, date("2025-03-20")),
+            &writer(),
+            date("2025-03-01"),
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,303 @@
+//! The only place booking status is allowed to change.
+
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::db;
+use crate::db::booking_event::NewBookingEvent;
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    Booking, BookingActor, BookingEvent, BookingEventType, BookingStatus, SlotHoldStatus, UserRole,
+};
+use crate::services::AuthenticatedUser;
+use crate::state::AppState;
+
+pub mod cancellation;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Actor {
+    pub kind: BookingActor,
+    pub user_id: Option<Uuid>,
+}
+
+impl Actor {
+    pub fn user(user: &AuthenticatedUser) -> Self {
+        let kind = match user.role {
+            UserRole::Writer => BookingActor::Writer,
+            UserRole::Sponsor => BookingActor::Sponsor,
+            UserRole::Admin => BookingActor::Admin,
+        };
+        Self {
+            kind,
+            user_id: Some(user.id),
+        }
+    }
+
+    pub fn system() -> Self {
+        Self {
+            kind: BookingActor::System,
+            user_id: None,
+        }
+    }
+
+    pub fn payment_provider() -> Self {
+        Self {
+            kind: BookingActor::PaymentProvider,
+            user_id: None,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransitionError {
+    #[error("Booking not found")]
+    NotFound,
+
+    #[error("Cannot move booking from {from:?} to {to:?}")]
+    Illegal {
+        from: BookingStatus,
+        to: BookingStatus,
+    },
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+impl From<TransitionError> for AppError {
+    fn from(err: TransitionError) -> Self {
+        match err {
+            TransitionError::NotFound => AppError::NotFound("Booking not found".into()),
+            TransitionError::Illegal { .. } => AppError::Conflict(err.to_string()),
+            TransitionError::Database(e) => AppError::Database(e),
+        }
+    }
+}
+
+pub fn can_transition(from: BookingStatus, to: BookingStatus) -> bool {
+    use BookingStatus::*;
+
+    matches!(
+        (from, to),
+        (PendingPayment, Paid)
+            | (PendingPayment, Cancelled)
+            | (Paid, Approved)
+            | (Paid, Rejected)
+            | (Paid, Cancelled)
+            | (Paid, Refunded)
+            | (Paid, ChangesRequested)
+            | (ChangesRequested, Paid)
+            | (ChangesRequested, Rejected)
+            | (ChangesRequested, Cancelled)
+            | (ChangesRequested, Refunded)
+            | (Approved, Published)
+            | (Approved, Rejected)
+            | (Approved, Cancelled)
+            | (Approved, Refunded)
+            | (Published, Refunded)
+    )
+}
+
+pub async fn transition(
+    pool: &PgPool,
+    booking_id: Uuid,
+    to: BookingStatus,
+    actor: Actor,
+    reason: Option<&str>,
+) -> Result<Booking, TransitionError> {
+    let mut tx = pool.begin().await?;
+    let booking = transition_tx(&mut tx, booking_id, to, actor, reason).await?;
+    tx.commit().await?;
+    Ok(booking)
+}
+
+pub async fn transition_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+    to: BookingStatus,
+    actor: Actor,
+    reason: Option<&str>,
+) -> Result<Booking, TransitionError> {
+    let from = db::sponsor::lock_booking_status(tx, booking_id)
+        .await?
+        .ok_or(TransitionError::NotFound)?;
+
+    if !can_transition(from, to) {
+        return Err(TransitionError::Illegal { from, to });
+    }
+
+    apply_transition_tx(tx, booking_id, from, to, actor, reason).await
+}
+
+/// Callers must hold the week lock from `lock_slots_remaining_tx` and have checked there is room.
+pub async fn reinstate_lapsed_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+    actor: Actor,
+    reason: Option<&str>,
+) -> Result<Booking, TransitionError> {
+    let from = db::sponsor::lock_booking_status(tx, booking_id)
+        .await?
+        .ok_or(TransitionError::NotFound)?;
+
+    let to = BookingStatus::Paid;
+    if from != BookingStatus::Cancelled
+        || !db::booking_event::was_cancelled_unpaid(tx, booking_id).await?
+    {
+        return Err(TransitionError::Illegal { from, to });
+    }
+
+    apply_transition_tx(tx, booking_id, from, to, actor, reason).await
+}
+
+async fn apply_transition_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+    from: BookingStatus,
+    to: BookingStatus,
+    actor: Actor,
+    reason: Option<&str>,
+) -> Result<Booking, TransitionError> {
+    let booking = db::sponsor::set_booking_status(tx, booking_id, to).await?;
+
+    // Once a booking leaves pending payment its slot hold has served its purpose
+    if from == BookingStatus::PendingPayment {
+        let hold_status = if to == BookingStatus::Paid {
+            SlotHoldStatus::Converted
+        } else {
+            SlotHoldStatus::Released
+        };
+        db::slot_hold::close_hold(tx, booking_id, hold_status).await?;
+    }
+
+    if from.takes_capacity() != to.takes_capacity() {
+        let delta = if to.takes_capacity() { 1 } else { -1 };
+        db::slot_inventory::adjust_for_booking_tx(tx, booking_id, delta).await?;
+    }
+
+    db::booking_event::insert_booking_event(
+        tx,
+        &NewBookingEvent {
+            booking_id,
+            event_type: BookingEventType::StatusChange,
+            from_status: Some(from),
+            to_status: to,
+            actor: actor.kind,
+            actor_user_id: actor.user_id,
+            reason,
+        },
+    )
+    .await?;
+
+    tracing::info!(
+        booking_id = %booking_id,
+        from = ?from,
+        to = ?to,
+        actor = ?actor.kind,
+        "Booking status changed"
+    );
+
+    Ok(booking)
+}
+
+pub async fn record_event_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    booking_id: Uuid,
+    event_type: BookingEventType,
+    actor: Actor,
+    reason: Option<&str>,
+) -> Result<BookingEvent, TransitionError> {
+    let status = db::sponsor::lock_booking_status(tx, booking_id)
+        .await?
+        .ok_or(TransitionError::NotFound)?;
+
+    let event = db::booking_event::insert_booking_event(
+        tx,
+        &NewBookingEvent {
+            booking_id,
+            event_type,
+            from_status: Some(status),
+            to_status: status,
+            actor: actor.kind,
+            actor_user_id: actor.user_id,
+            reason,
+        },
+    )
+    .await?;
+
+    Ok(event)
+}
+
+/// Bookings in a bundle share one order, so they are only ever refunded by amount.
+pub async fn refund_payment(
+    state: &AppState,
+    booking: &Booking,
+    refund_cents: i32,
+) -> AppResult<()> {
+    let Some(order_id) = &booking.lemon_order_id else {
+        return Ok(());
+    };
+    if refund_cents <= 0 {
+        return Ok(());
+    }
+
+    let payments = state.require_payments()?;
+    if booking.bundle_id.is_none() && refund_cents >= booking.amount_cents {
+        payments.refund_order(order_id).await
+    } else {
+        payments
+            .refund_order_amount(order_id, refund_cents as i64)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use BookingStatus::*;
+
+    #[test]
+    fn test_happy_path_is_allowed() {
+        assert!(can_transition(PendingPayment, Paid));
+        assert!(can_transition(Paid, Approved));
+        assert!(can_transition(Approved, Published));
+    }
+
+    #[test]
+    fn test_cannot_skip_payment() {
+        assert!(!can_transition(PendingPayment, Approved));
+        assert!(!can_transition(PendingPayment, Published));
+    }
+
+    #[test]
+    fn test_terminal_states_are_final() {
+        for from in [Rejected, Cancelled, Refunded] {
+            for to in [
+                PendingPayment,
+                Paid,
+                ChangesRequested,
+                Approved,
+                Rejected,
+                Published,
+                Cancelled,
+                Refunded,
+            ] {
+                assert!(!can_transition(from, to), "{:?} -> {:?}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn test_changes_requested_must_be_resubmitted_before_approval() {
+        assert!(can_transition(Paid, ChangesRequested));
+        assert!(can_transition(ChangesRequested, Paid));
+        assert!(!can_transition(ChangesRequested, Approved));
+        assert!(!can_transition(ChangesRequested, Published));
+    }
+
+    #[test]
+    fn test_published_can_only_be_refunded() {
+        assert!(can_transition(Published, Refunded));
+        assert!(!can_transition(Published, Cancelled));
+        assert!(!can_transition(Published, Rejected));
+    }
+}
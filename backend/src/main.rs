@@ -1,17 +1,24 @@
+mod calendar;
 mod config;
 mod db;
+mod directory;
 mod error;
 mod helpers;
+mod jobs;
+mod lifecycle;
 mod middlewares;
 mod models;
+mod pricing;
 mod responses;
 mod routes;
 mod services;
 mod state;
 mod validation;
+mod waitlist;
 
 use axum::{middleware, Router};
 use tokio::net::TcpListener;
+use tower::Layer;
 use tower_http::trace::TraceLayer;
 
 use crate::config::Config;
@@ -36,16 +43,26 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let state = AppState::new(pool, config.clone());
 
+    jobs::spawn_all(&state);
+
     let rate_limit_config = RateLimitConfig::from_env();
     let general_rate_limit = middlewares::general_rate_limit_layer(&rate_limit_config);
 
-    let app = Router::new()
+    let api = Router::new()
         .nest("/api", routes::api_router(&rate_limit_config))
+        .with_state(state.clone());
+
+    // Layers on a router run after routing, so slug resolution wraps the router
+    // itself for writer slugs to reach the id-based routes. The rate limit goes
+    // outside it, as resolving a slug costs a query.
+    let api = middleware::from_fn_with_state(state, middlewares::resolve_writer_slugs).layer(api);
+
+    let app = Router::new()
+        .fallback_service(api)
         .layer(general_rate_limit)
         .layer(middleware::from_fn(middlewares::request_logging))
         .layer(TraceLayer::new_for_http())
-        .layer(middlewares::cors_layer(&config.cors))
-        .with_state(state);
+        .layer(middlewares::cors_layer(&config.cors));
 
     let addr = config.server.addr();
     let listener = TcpListener::bind(&addr).await?;
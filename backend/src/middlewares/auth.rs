@@ -113,7 +113,6 @@ where
     }
 }
 
-#[allow(dead_code)]
 pub struct OptionalAuth(pub Option<AuthenticatedUser>);
 
 impl<S> FromRequestParts<S> for OptionalAuth
@@ -10,11 +10,13 @@ use crate::config::CorsConfig;
 
 pub mod auth;
 pub mod rate_limit;
+pub mod writer_slug;
 
-pub use auth::{Auth, SponsorAuth, WriterAuth};
+pub use auth::{Auth, OptionalAuth, SponsorAuth, WriterAuth};
 pub use rate_limit::{
     auth_rate_limit_layer, general_rate_limit_layer, payment_rate_limit_layer, RateLimitConfig,
 };
+pub use writer_slug::resolve_writer_slugs;
 
 pub fn cors_layer(config: &CorsConfig) -> CorsLayer {
     let origins: Vec<_> = config
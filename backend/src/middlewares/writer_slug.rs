@@ -0,0 +1,90 @@
+use axum::{
+    extract::{Request, State},
+    http::Uri,
+    middleware::Next,
+    response::{IntoResponse, Redirect, Response},
+};
+use uuid::Uuid;
+
+use crate::db;
+use crate::error::AppError;
+use crate::state::AppState;
+
+const WRITER_PATH_PREFIXES: &[&str] = &["/api/writers/", "/api/widget/writers/"];
+
+fn slug_segment(path: &str) -> Option<(&'static str, &str, &str)> {
+    let (prefix, rest) = WRITER_PATH_PREFIXES
+        .iter()
+        .find_map(|prefix| path.strip_prefix(prefix).map(|rest| (*prefix, rest)))?;
+
+    let (segment, tail) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    if segment.is_empty() || segment == "me" || Uuid::parse_str(segment).is_ok() {
+        return None;
+    }
+    Some((prefix, segment, tail))
+}
+
+/// Runs before routing so handlers only see ids; request bodies must still use ids.
+pub async fn resolve_writer_slugs(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some((prefix, segment, tail)) = slug_segment(request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    let slug_match = match db::writer_slug::resolve_slug(&state.db, &segment.to_lowercase()).await {
+        Ok(Some(slug_match)) => slug_match,
+        Ok(None) => return AppError::NotFound("Writer not found".into()).into_response(),
+        Err(e) => return AppError::from(e).into_response(),
+    };
+
+    let query = request
+        .uri()
+        .query()
+        .map(|q| format!("?{}", q))
+        .unwrap_or_default();
+
+    if slug_match.retired {
+        let current = slug_match
+            .current_slug
+            .unwrap_or_else(|| slug_match.writer_id.to_string());
+        return Redirect::permanent(&format!("{}{}{}{}", prefix, current, tail, query))
+            .into_response();
+    }
+
+    let rewritten = format!("{}{}{}{}", prefix, slug_match.writer_id, tail, query);
+    match rewritten.parse::<Uri>() {
+        Ok(uri) => *request.uri_mut() = uri,
+        Err(e) => return AppError::Internal(e.to_string()).into_response(),
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slug_segment() {
+        assert_eq!(
+            slug_segment("/api/writers/rust-weekly/availability"),
+            Some(("/api/writers/", "rust-weekly", "/availability"))
+        );
+        assert_eq!(
+            slug_segment("/api/widget/writers/rust-weekly"),
+            Some(("/api/widget/writers/", "rust-weekly", ""))
+        );
+
+        // Already an id, the caller's own profile, or not a writer path
+        assert_eq!(
+            slug_segment("/api/writers/00000000-0000-0000-0000-000000000001/placements"),
+            None
+        );
+        assert_eq!(slug_segment("/api/writers/me"), None);
+        assert_eq!(slug_segment("/api/writers/"), None);
+        assert_eq!(slug_segment("/api/bookings/rust-weekly"), None);
+    }
+}
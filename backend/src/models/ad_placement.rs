@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct AdPlacement {
+    pub id: Uuid,
+    pub writer_id: Uuid,
+
+    pub name: String,
+    pub description: Option<String>,
+
+    pub price_cents: i32,
+    pub currency: String,
+    pub slots_per_issue: i32,
+
+    pub max_headline_length: i32,
+    pub max_body_length: i32,
+    pub allows_image: bool,
+
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateAdPlacement {
+    pub name: String,
+    pub description: Option<String>,
+    pub price_cents: i32,
+    /// Must be the newsletter's currency; defaults to it
+    #[serde(default)]
+    pub currency: String,
+    #[serde(default = "default_slots_per_issue")]
+    pub slots_per_issue: i32,
+    #[serde(default = "default_max_headline_length")]
+    pub max_headline_length: i32,
+    #[serde(default = "default_max_body_length")]
+    pub max_body_length: i32,
+    #[serde(default = "default_allows_image")]
+    pub allows_image: bool,
+}
+
+fn default_slots_per_issue() -> i32 {
+    1
+}
+
+fn default_max_headline_length() -> i32 {
+    100
+}
+
+fn default_max_body_length() -> i32 {
+    500
+}
+
+fn default_allows_image() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateAdPlacement {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub price_cents: Option<i32>,
+    pub slots_per_issue: Option<i32>,
+    pub max_headline_length: Option<i32>,
+    pub max_body_length: Option<i32>,
+    pub allows_image: Option<bool>,
+    pub is_active: Option<bool>,
+}
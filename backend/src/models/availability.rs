@@ -6,12 +6,16 @@ use sqlx::FromRow;
 pub struct AvailableSlot {
     pub available_date: NaiveDate,
     pub slots_remaining: i32,
+    /// After pricing rules
+    pub price_cents: i32,
 }
 
 #[derive(Debug, Serialize)]
 pub struct WriterAvailability {
     pub writer_id: uuid::Uuid,
     pub newsletter_name: String,
+    pub placement_id: uuid::Uuid,
+    pub placement_name: String,
     pub price_per_slot: i32,
     pub currency: String,
     pub available_slots: Vec<AvailableSlot>,
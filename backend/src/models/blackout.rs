@@ -0,0 +1,55 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::ConflictingBooking;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "blackout_recurrence", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum BlackoutRecurrence {
+    #[default]
+    Once,
+    Weekly,
+    Yearly,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct Blackout {
+    pub id: Uuid,
+    pub writer_id: Uuid,
+
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub recurrence: BlackoutRecurrence,
+    pub interval_weeks: i32,
+    pub repeat_until: Option<NaiveDate>,
+
+    pub reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateBlackout {
+    #[serde(alias = "blocked_date")]
+    pub start_date: NaiveDate,
+    /// Defaults to `start_date`, blocking a single day
+    pub end_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub recurrence: BlackoutRecurrence,
+    #[serde(default = "default_interval_weeks")]
+    pub interval_weeks: i32,
+    pub repeat_until: Option<NaiveDate>,
+    pub reason: Option<String>,
+}
+
+fn default_interval_weeks() -> i32 {
+    1
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlackoutCreated {
+    pub blackout: Blackout,
+    pub conflicting_bookings: Vec<ConflictingBooking>,
+}
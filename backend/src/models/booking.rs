@@ -9,6 +9,7 @@ use uuid::Uuid;
 pub enum BookingStatus {
     PendingPayment,
     Paid,
+    ChangesRequested,
     Approved,
     Rejected,
     Published,
@@ -16,6 +17,16 @@ pub enum BookingStatus {
     Refunded,
 }
 
+impl BookingStatus {
+    /// Unpaid bookings only occupy a slot through their slot hold.
+    pub fn takes_capacity(self) -> bool {
+        !matches!(
+            self,
+            Self::PendingPayment | Self::Rejected | Self::Cancelled | Self::Refunded
+        )
+    }
+}
+
 #[derive(Debug, Clone, FromRow, Serialize)]
 pub struct Booking {
     pub id: Uuid,
@@ -45,12 +56,26 @@ pub struct Booking {
     pub approved_at: Option<DateTime<Utc>>,
     pub rejected_at: Option<DateTime<Utc>>,
     pub published_at: Option<DateTime<Utc>>,
+
+    pub refunded_cents: i32,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub payment_expires_at: Option<DateTime<Utc>>,
+    pub bundle_id: Option<Uuid>,
+    pub published_url: Option<String>,
+    pub publication_proof_url: Option<String>,
+    pub placement_id: Uuid,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct CreateBooking {
+    /// Slugs are only resolved in URL paths
     pub writer_id: Uuid,
-    pub slot_date: NaiveDate,
+    /// Defaults to the writer's first active placement
+    pub placement_id: Option<Uuid>,
+    pub slot_date: Option<NaiveDate>,
+    pub slot_dates: Option<Vec<NaiveDate>>,
+    pub issue_count: Option<u32>,
+    pub waitlist_offer: Option<String>,
     pub ad_headline: String,
     pub ad_body: String,
     pub ad_cta_text: Option<String>,
@@ -76,6 +101,12 @@ pub struct BookingWithDetails {
     pub paid_at: Option<DateTime<Utc>>,
     pub approved_at: Option<DateTime<Utc>>,
     pub published_at: Option<DateTime<Utc>>,
+    pub bundle_id: Option<Uuid>,
+    pub published_url: Option<String>,
+    pub publication_proof_url: Option<String>,
+
+    pub placement_id: Uuid,
+    pub placement_name: String,
 
     pub writer_id: Uuid,
     pub newsletter_name: String,
@@ -83,4 +114,16 @@ pub struct BookingWithDetails {
     pub sponsor_id: Uuid,
     pub company_name: String,
     pub sponsor_logo_url: Option<String>,
+
+    pub unread_messages: i64,
+}
+
+/// Paid booking on a date the writer no longer takes sponsors for.
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct ConflictingBooking {
+    pub booking_id: Uuid,
+    pub slot_date: NaiveDate,
+    pub status: BookingStatus,
+    pub company_name: String,
+    pub ad_headline: String,
 }
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct BookingChangeRequest {
+    pub id: Uuid,
+    pub booking_id: Uuid,
+    pub requested_by: Option<Uuid>,
+    pub feedback: String,
+    pub created_at: DateTime<Utc>,
+    pub resubmitted_at: Option<DateTime<Utc>>,
+}
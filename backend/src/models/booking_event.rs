@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::BookingStatus;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "booking_actor", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum BookingActor {
+    System,
+    Writer,
+    Sponsor,
+    Admin,
+    PaymentProvider,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "booking_event_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum BookingEventType {
+    StatusChange,
+    PublicationReminder,
+    PublicationEscalated,
+    RescheduleProposed,
+    RescheduleAccepted,
+    RescheduleDeclined,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct BookingEvent {
+    pub id: Uuid,
+    pub booking_id: Uuid,
+    pub event_type: BookingEventType,
+
+    pub from_status: Option<BookingStatus>,
+    pub to_status: BookingStatus,
+
+    pub actor: BookingActor,
+    pub actor_user_id: Option<Uuid>,
+    pub reason: Option<String>,
+
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::BookingActor;
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct BookingMessage {
+    pub id: Uuid,
+    pub booking_id: Uuid,
+    pub sender_user_id: Option<Uuid>,
+    pub sender_role: BookingActor,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateBookingMessage {
+    pub body: String,
+}
@@ -0,0 +1,40 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::BookingActor;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "reschedule_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RescheduleStatus {
+    Pending,
+    Accepted,
+    Declined,
+    Withdrawn,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct BookingReschedule {
+    pub id: Uuid,
+    pub booking_id: Uuid,
+
+    pub proposed_by: BookingActor,
+    pub proposed_by_user_id: Option<Uuid>,
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    pub reason: Option<String>,
+
+    pub status: RescheduleStatus,
+    pub responded_by_user_id: Option<Uuid>,
+    pub responded_at: Option<DateTime<Utc>>,
+
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProposeReschedule {
+    pub new_date: NaiveDate,
+    pub reason: Option<String>,
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Sold outside Adsloty: takes a slot but never goes through checkout, fees or payouts.
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct ManualBooking {
+    pub id: Uuid,
+    pub writer_id: Uuid,
+    pub placement_id: Uuid,
+    pub placement_name: String,
+    pub slot_date: NaiveDate,
+    pub sponsor_name: String,
+    pub amount_cents: Option<i32>,
+    pub currency: String,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateManualBooking {
+    /// Defaults to the writer's first active placement
+    pub placement_id: Option<Uuid>,
+    pub slot_date: NaiveDate,
+    pub sponsor_name: String,
+    pub amount_cents: Option<i32>,
+    pub notes: Option<String>,
+}
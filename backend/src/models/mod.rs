@@ -1,15 +1,37 @@
+pub mod ad_placement;
 pub mod availability;
-pub mod blackout_date;
+pub mod blackout;
 pub mod booking;
+pub mod booking_change_request;
+pub mod booking_event;
+pub mod booking_message;
+pub mod booking_reschedule;
+pub mod manual_booking;
 pub mod payout;
+pub mod pricing_rule;
+pub mod review;
+pub mod saved_list;
+pub mod slot_hold;
 pub mod sponsor;
 pub mod user;
+pub mod waitlist;
 pub mod writer;
 
+pub use ad_placement::*;
 pub use availability::*;
-pub use blackout_date::*;
+pub use blackout::*;
 pub use booking::*;
+pub use booking_change_request::*;
+pub use booking_event::*;
+pub use booking_message::*;
+pub use booking_reschedule::*;
+pub use manual_booking::*;
 pub use payout::*;
+pub use pricing_rule::*;
+pub use review::*;
+pub use saved_list::*;
+pub use slot_hold::*;
 pub use sponsor::*;
 pub use user::*;
+pub use waitlist::*;
 pub use writer::*;
@@ -0,0 +1,49 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "pricing_rule_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PricingRuleKind {
+    DateOverride,
+    LeadTime,
+    Weekday,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct PricingRule {
+    pub id: Uuid,
+    pub writer_id: Uuid,
+    /// `None` applies the rule to all of the writer's placements
+    pub placement_id: Option<Uuid>,
+    pub kind: PricingRuleKind,
+    pub label: Option<String>,
+
+    pub rule_date: Option<NaiveDate>,
+    pub price_cents: Option<i32>,
+
+    pub min_days_ahead: Option<i32>,
+    pub max_days_ahead: Option<i32>,
+    pub adjustment_pct: Option<i32>,
+
+    pub weekday: Option<i16>,
+    pub multiplier_pct: Option<i32>,
+
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreatePricingRule {
+    pub kind: PricingRuleKind,
+    pub placement_id: Option<Uuid>,
+    pub label: Option<String>,
+    pub rule_date: Option<NaiveDate>,
+    pub price_cents: Option<i32>,
+    pub min_days_ahead: Option<i32>,
+    pub max_days_ahead: Option<i32>,
+    pub adjustment_pct: Option<i32>,
+    pub weekday: Option<i16>,
+    pub multiplier_pct: Option<i32>,
+}
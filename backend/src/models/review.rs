@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct Review {
+    pub id: Uuid,
+    pub booking_id: Uuid,
+    pub writer_id: Uuid,
+    pub sponsor_id: Uuid,
+    pub company_name: String,
+    pub rating: i16,
+    pub body: String,
+    pub performance_note: Option<String>,
+    pub writer_reply: Option<String>,
+    pub replied_at: Option<DateTime<Utc>>,
+    /// Only shown to admins; hidden reviews never reach public listings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden_reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateReview {
+    pub rating: i16,
+    pub body: String,
+    pub performance_note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReplyToReview {
+    pub reply: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ModerateReview {
+    pub hidden: bool,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, FromRow, Serialize)]
+pub struct RatingSummary {
+    pub review_count: i64,
+    /// `None` until the first review
+    pub average_rating: Option<Decimal>,
+}
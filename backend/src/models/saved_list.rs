@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::{AvailableSlot, RatingSummary};
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct SavedList {
+    pub id: Uuid,
+    pub sponsor_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub member_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateSavedList {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateSavedList {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct SavedListMember {
+    pub list_id: Uuid,
+    pub writer_id: Uuid,
+    pub note: Option<String>,
+    pub added_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SaveListMember {
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SavedListPlan {
+    #[serde(flatten)]
+    pub list: SavedList,
+    pub weeks_ahead: i32,
+    pub members: Vec<PlannedNewsletter>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlannedNewsletter {
+    pub writer_id: Uuid,
+    pub slug: Option<String>,
+    pub newsletter_name: String,
+    pub newsletter_url: Option<String>,
+    pub subscriber_count: Option<i32>,
+    pub timezone: String,
+    pub paused: bool,
+    #[serde(flatten)]
+    pub rating: RatingSummary,
+    pub note: Option<String>,
+    pub added_at: DateTime<Utc>,
+    pub placements: Vec<PlannedPlacement>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlannedPlacement {
+    pub placement_id: Uuid,
+    pub name: String,
+    pub currency: String,
+    /// List price before date-specific pricing rules
+    pub price_cents: i32,
+    pub next_available: Vec<AvailableSlot>,
+}
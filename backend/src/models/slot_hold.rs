@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "slot_hold_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SlotHoldStatus {
+    Active,
+    Converted,
+    Released,
+}
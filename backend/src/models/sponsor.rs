@@ -13,6 +13,7 @@ pub struct Sponsor {
     pub logo_url: Option<String>,
 
     pub billing_email: Option<String>,
+    pub timezone: String,
 
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -24,6 +25,12 @@ pub struct CreateSponsor {
     pub website_url: Option<String>,
     pub logo_url: Option<String>,
     pub billing_email: Option<String>,
+    #[serde(default = "default_timezone")]
+    pub timezone: String,
+}
+
+fn default_timezone() -> String {
+    "UTC".to_string()
 }
 
 #[derive(Debug, Deserialize)]
@@ -32,4 +39,5 @@ pub struct UpdateSponsor {
     pub website_url: Option<String>,
     pub logo_url: Option<String>,
     pub billing_email: Option<String>,
+    pub timezone: Option<String>,
 }
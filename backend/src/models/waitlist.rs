@@ -0,0 +1,55 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "waitlist_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum WaitlistStatus {
+    Waiting,
+    /// A freed slot is reserved for the sponsor until `offer_expires_at`
+    Offered,
+    Claimed,
+    Expired,
+    Left,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct WaitlistEntry {
+    pub id: Uuid,
+    pub writer_id: Uuid,
+    pub placement_id: Uuid,
+    pub sponsor_id: Uuid,
+    pub slot_date: NaiveDate,
+    pub status: WaitlistStatus,
+    pub offered_at: Option<DateTime<Utc>>,
+    pub offer_expires_at: Option<DateTime<Utc>>,
+    pub claimed_booking_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct WaitlistEntryWithDetails {
+    pub id: Uuid,
+    pub writer_id: Uuid,
+    pub newsletter_name: String,
+    pub placement_id: Uuid,
+    pub placement_name: String,
+    pub slot_date: NaiveDate,
+    pub status: WaitlistStatus,
+    /// 1 is next; only while waiting
+    pub position: Option<i64>,
+    pub offer_expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JoinWaitlist {
+    /// The writer's id; slugs are only resolved in URL paths
+    pub writer_id: Uuid,
+    /// Defaults to the writer's first active placement
+    pub placement_id: Option<Uuid>,
+    pub slot_date: NaiveDate,
+}
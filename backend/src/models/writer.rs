@@ -1,12 +1,18 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use super::RatingSummary;
+
 #[derive(Debug, Clone, FromRow, Serialize)]
 pub struct Writer {
     pub id: Uuid,
     pub user_id: Uuid,
+    /// Vanity name usable in place of `id` in writer and widget URLs
+    pub slug: Option<String>,
 
     pub newsletter_name: String,
     pub newsletter_url: Option<String>,
@@ -19,13 +25,49 @@ pub struct Writer {
     pub lead_time_days: i32,
     pub slots_per_week: i32,
 
+    /// 1 = Monday
+    pub issue_weekdays: Vec<i16>,
+    /// 0 = every week, 1-4 = only the nth of those weekdays in the month, -1 = the last
+    pub issue_week_of_month: i16,
+    pub slots_per_issue: i32,
+
     pub auto_approve: bool,
-    pub platform_fee_pct: rust_decimal::Decimal,
+    pub platform_fee_pct: Decimal,
+
+    pub cancellation_window_days: i32,
+    pub late_cancellation_refund_pct: i32,
+
+    /// "Today" for lead times is local to this zone
+    pub timezone: String,
+
+    /// Either bound may be open-ended
+    pub paused: bool,
+    pub pause_starts_on: Option<NaiveDate>,
+    pub pause_ends_on: Option<NaiveDate>,
+
+    pub categories: Vec<String>,
+    pub tags: Vec<String>,
+    pub audience_countries: Vec<String>,
+    pub audience_job_roles: Vec<String>,
+    pub open_rate_pct: Option<Decimal>,
 
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl Writer {
+    pub fn platform_fee_cents(&self, amount_cents: i32) -> i32 {
+        (Decimal::from(amount_cents) * self.platform_fee_pct / Decimal::from(100))
+            .trunc()
+            .to_i32()
+            .unwrap_or(0)
+    }
+
+    pub fn is_paused_on(&self, date: NaiveDate) -> bool {
+        self.paused && crate::calendar::pause_covers(self.pause_starts_on, self.pause_ends_on, date)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateWriter {
     pub newsletter_name: String,
@@ -39,6 +81,14 @@ pub struct CreateWriter {
     pub lead_time_days: i32,
     #[serde(default = "default_slots_per_week")]
     pub slots_per_week: i32,
+    #[serde(default = "default_issue_weekdays")]
+    pub issue_weekdays: Vec<i16>,
+    #[serde(default)]
+    pub issue_week_of_month: i16,
+    #[serde(default = "default_slots_per_issue")]
+    pub slots_per_issue: i32,
+    #[serde(default = "default_timezone")]
+    pub timezone: String,
 }
 
 fn default_currency() -> String {
@@ -53,6 +103,18 @@ fn default_slots_per_week() -> i32 {
     1
 }
 
+fn default_issue_weekdays() -> Vec<i16> {
+    vec![1]
+}
+
+fn default_slots_per_issue() -> i32 {
+    1
+}
+
+fn default_timezone() -> String {
+    "UTC".to_string()
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UpdateWriter {
     pub newsletter_name: Option<String>,
@@ -62,7 +124,161 @@ pub struct UpdateWriter {
     pub price_per_slot: Option<i32>,
     pub lead_time_days: Option<i32>,
     pub slots_per_week: Option<i32>,
+    pub issue_weekdays: Option<Vec<i16>>,
+    pub issue_week_of_month: Option<i16>,
+    pub slots_per_issue: Option<i32>,
     pub auto_approve: Option<bool>,
+    pub cancellation_window_days: Option<i32>,
+    pub late_cancellation_refund_pct: Option<i32>,
+    pub timezone: Option<String>,
+    pub categories: Option<Vec<String>>,
+    pub tags: Option<Vec<String>>,
+    pub audience_countries: Option<Vec<String>>,
+    pub audience_job_roles: Option<Vec<String>>,
+    pub open_rate_pct: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PublishingFrequency {
+    Daily,
+    SeveralTimesAWeek,
+    Weekly,
+    Monthly,
+}
+
+impl PublishingFrequency {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::SeveralTimesAWeek => "several_times_a_week",
+            Self::Weekly => "weekly",
+            Self::Monthly => "monthly",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NewsletterProfile {
+    pub categories: Vec<String>,
+    pub tags: Vec<String>,
+    pub audience_countries: Vec<String>,
+    pub audience_job_roles: Vec<String>,
+    pub open_rate_pct: Option<Decimal>,
+    pub publishing_frequency: PublishingFrequency,
+}
+
+impl NewsletterProfile {
+    pub fn for_writer(writer: &Writer) -> Self {
+        Self {
+            categories: writer.categories.clone(),
+            tags: writer.tags.clone(),
+            audience_countries: writer.audience_countries.clone(),
+            audience_job_roles: writer.audience_job_roles.clone(),
+            open_rate_pct: writer.open_rate_pct,
+            publishing_frequency: crate::calendar::PublishingSchedule::for_writer(writer)
+                .frequency(),
+        }
+    }
+}
+
+/// Leaves out the owning user and the platform fee.
+#[derive(Debug, Serialize)]
+pub struct PublicWriter {
+    pub id: Uuid,
+    pub slug: Option<String>,
+    pub newsletter_name: String,
+    pub newsletter_url: Option<String>,
+    pub description: Option<String>,
+    pub subscriber_count: Option<i32>,
+    pub price_per_slot: i32,
+    pub currency: String,
+    pub lead_time_days: i32,
+    pub slots_per_week: i32,
+    pub issue_weekdays: Vec<i16>,
+    pub issue_week_of_month: i16,
+    pub slots_per_issue: i32,
+    pub cancellation_window_days: i32,
+    pub late_cancellation_refund_pct: i32,
+    pub timezone: String,
+    pub paused: bool,
+    pub pause_starts_on: Option<NaiveDate>,
+    pub pause_ends_on: Option<NaiveDate>,
+    #[serde(flatten)]
+    pub profile: NewsletterProfile,
+    #[serde(flatten)]
+    pub rating: RatingSummary,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PublicWriter {
+    pub fn new(writer: Writer, rating: RatingSummary) -> Self {
+        Self {
+            profile: NewsletterProfile::for_writer(&writer),
+            rating,
+            id: writer.id,
+            slug: writer.slug,
+            newsletter_name: writer.newsletter_name,
+            newsletter_url: writer.newsletter_url,
+            description: writer.description,
+            subscriber_count: writer.subscriber_count,
+            price_per_slot: writer.price_per_slot,
+            currency: writer.currency,
+            lead_time_days: writer.lead_time_days,
+            slots_per_week: writer.slots_per_week,
+            issue_weekdays: writer.issue_weekdays,
+            issue_week_of_month: writer.issue_week_of_month,
+            slots_per_issue: writer.slots_per_issue,
+            cancellation_window_days: writer.cancellation_window_days,
+            late_cancellation_refund_pct: writer.late_cancellation_refund_pct,
+            timezone: writer.timezone,
+            paused: writer.paused,
+            pause_starts_on: writer.pause_starts_on,
+            pause_ends_on: writer.pause_ends_on,
+            created_at: writer.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewsletterListing {
+    pub id: Uuid,
+    pub slug: Option<String>,
+    pub newsletter_name: String,
+    pub newsletter_url: Option<String>,
+    pub description: Option<String>,
+    pub subscriber_count: Option<i32>,
+    /// List price of the cheapest active placement
+    pub price_from_cents: i32,
+    pub currency: String,
+    pub issue_weekdays: Vec<i16>,
+    pub issue_week_of_month: i16,
+    pub timezone: String,
+    pub paused: bool,
+    #[serde(flatten)]
+    pub profile: NewsletterProfile,
+    #[serde(flatten)]
+    pub rating: RatingSummary,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetSlug {
+    pub slug: String,
+}
+
+/// Unset bounds mean from today and until resumed.
+#[derive(Debug, Deserialize)]
+pub struct SetPause {
+    pub starts_on: Option<NaiveDate>,
+    pub ends_on: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PauseStatus {
+    pub paused: bool,
+    pub pause_starts_on: Option<NaiveDate>,
+    pub pause_ends_on: Option<NaiveDate>,
+    pub conflicting_bookings: Vec<super::ConflictingBooking>,
 }
 
 #[derive(Debug, Serialize)]
@@ -71,4 +287,8 @@ pub struct WriterStats {
     pub pending_bookings: i64,
     pub total_revenue_cents: i64,
     pub pending_revenue_cents: i64,
+    /// Paid outside Adsloty, so not part of the revenue above
+    pub off_platform_bookings: i64,
+    /// Only deals in the newsletter's own currency
+    pub off_platform_revenue_cents: i64,
 }
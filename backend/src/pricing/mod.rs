@@ -0,0 +1,190 @@
+//! A fixed override wins; otherwise the weekday multiplier, then the lead time adjustment.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::models::{AdPlacement, PricingRule, PricingRuleKind};
+
+pub fn effective_price(
+    placement: &AdPlacement,
+    rules: &[PricingRule],
+    date: NaiveDate,
+    today: NaiveDate,
+) -> i32 {
+    let applicable: Vec<&PricingRule> = rules
+        .iter()
+        .filter(|r| r.placement_id.is_none_or(|id| id == placement.id))
+        .collect();
+
+    // Placement-specific rules sort ahead of writer-wide ones
+    let most_specific = |kind: PricingRuleKind, matches: &dyn Fn(&PricingRule) -> bool| {
+        applicable
+            .iter()
+            .filter(|r| r.kind == kind && matches(r))
+            .min_by_key(|r| r.placement_id.is_none())
+            .copied()
+    };
+
+    if let Some(price) = most_specific(PricingRuleKind::DateOverride, &|r| {
+        r.rule_date == Some(date)
+    })
+    .and_then(|r| r.price_cents)
+    {
+        return price;
+    }
+
+    let mut price = placement.price_cents as f64;
+
+    let weekday = date.weekday().number_from_monday() as i16;
+    if let Some(multiplier) =
+        most_specific(PricingRuleKind::Weekday, &|r| r.weekday == Some(weekday))
+            .and_then(|r| r.multiplier_pct)
+    {
+        price = price * multiplier as f64 / 100.0;
+    }
+
+    let days_ahead = (date - today).num_days() as i32;
+    let lead_time = applicable
+        .iter()
+        .filter(|r| r.kind == PricingRuleKind::LeadTime && covers_lead_time(r, days_ahead))
+        // Most specific placement first, then the narrowest (latest starting) window
+        .min_by_key(|r| (r.placement_id.is_none(), -r.min_days_ahead.unwrap_or(0)));
+    if let Some(adjustment) = lead_time.and_then(|r| r.adjustment_pct) {
+        price = price * (100 + adjustment) as f64 / 100.0;
+    }
+
+    (price.round() as i32).max(1)
+}
+
+fn covers_lead_time(rule: &PricingRule, days_ahead: i32) -> bool {
+    rule.min_days_ahead.is_some_and(|min| days_ahead >= min)
+        && rule.max_days_ahead.is_none_or(|max| days_ahead <= max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn placement(price_cents: i32) -> AdPlacement {
+        AdPlacement {
+            id: Uuid::now_v7(),
+            writer_id: Uuid::nil(),
+            name: "Sponsored slot".into(),
+            description: None,
+            price_cents,
+            currency: "usd".into(),
+            slots_per_issue: 1,
+            max_headline_length: 80,
+            max_body_length: 500,
+            allows_image: true,
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn rule(kind: PricingRuleKind, placement_id: Option<Uuid>) -> PricingRule {
+        PricingRule {
+            id: Uuid::now_v7(),
+            writer_id: Uuid::nil(),
+            placement_id,
+            kind,
+            label: None,
+            rule_date: None,
+            price_cents: None,
+            min_days_ahead: None,
+            max_days_ahead: None,
+            adjustment_pct: None,
+            weekday: None,
+            multiplier_pct: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_list_price_without_rules() {
+        let p = placement(10_000);
+        assert_eq!(
+            effective_price(&p, &[], date("2025-03-04"), date("2025-03-01")),
+            10_000
+        );
+    }
+
+    #[test]
+    fn test_date_override_wins() {
+        let p = placement(10_000);
+        let mut all = rule(PricingRuleKind::DateOverride, None);
+        all.rule_date = Some(date("2025-12-01"));
+        all.price_cents = Some(25_000);
+        let mut own = rule(PricingRuleKind::DateOverride, Some(p.id));
+        own.rule_date = Some(date("2025-12-01"));
+        own.price_cents = Some(30_000);
+        let mut monday = rule(PricingRuleKind::Weekday, None);
+        monday.weekday = Some(1);
+        monday.multiplier_pct = Some(200);
+
+        let rules = [all, monday, own];
+        assert_eq!(
+            effective_price(&p, &rules, date("2025-12-01"), date("2025-11-01")),
+            30_000
+        );
+        assert_eq!(
+            effective_price(&p, &rules[..2], date("2025-12-01"), date("2025-11-01")),
+            25_000
+        );
+        assert_eq!(
+            effective_price(&p, &rules, date("2025-12-08"), date("2025-11-01")),
+            20_000
+        );
+    }
+
+    #[test]
+    fn test_weekday_and_lead_time_combine() {
+        let p = placement(10_000);
+        let mut friday = rule(PricingRuleKind::Weekday, None);
+        friday.weekday = Some(5);
+        friday.multiplier_pct = Some(150);
+        let mut early = rule(PricingRuleKind::LeadTime, None);
+        early.min_days_ahead = Some(30);
+        early.adjustment_pct = Some(-10);
+        let mut last_minute = rule(PricingRuleKind::LeadTime, None);
+        last_minute.min_days_ahead = Some(0);
+        last_minute.max_days_ahead = Some(3);
+        last_minute.adjustment_pct = Some(-25);
+
+        let rules = [friday, early, last_minute];
+        let today = date("2025-03-01");
+        // Friday, 6 days ahead: weekday only
+        assert_eq!(
+            effective_price(&p, &rules, date("2025-03-07"), today),
+            15_000
+        );
+        // Friday, 34 days ahead: 1.5x then 10% off
+        assert_eq!(
+            effective_price(&p, &rules, date("2025-04-04"), today),
+            13_500
+        );
+        // Tuesday, 3 days ahead: last minute discount
+        assert_eq!(
+            effective_price(&p, &rules, date("2025-03-04"), today),
+            7_500
+        );
+    }
+
+    #[test]
+    fn test_rules_for_other_placements_are_ignored() {
+        let p = placement(10_000);
+        let mut other = rule(PricingRuleKind::Weekday, Some(Uuid::now_v7()));
+        other.weekday = Some(2);
+        other.multiplier_pct = Some(300);
+        assert_eq!(
+            effective_price(&p, &[other], date("2025-03-04"), date("2025-03-01")),
+            10_000
+        );
+    }
+}
@@ -66,6 +66,26 @@ impl<T: Serialize> PaginatedResponse<T> {
     }
 }
 
+/// `next_cursor` is `None` on the last page.
+#[derive(Debug, Serialize)]
+pub struct CursorPaginatedResponse<T: Serialize> {
+    pub success: bool,
+    pub data: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+impl<T: Serialize> CursorPaginatedResponse<T> {
+    pub fn new(data: Vec<T>, next_cursor: Option<String>) -> Self {
+        Self {
+            success: true,
+            data,
+            has_more: next_cursor.is_some(),
+            next_cursor,
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct PaginationParams {
     #[serde(default = "default_limit")]
@@ -1,5 +1,5 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     routing::{delete, get, patch, post},
     Json, Router,
 };
@@ -9,8 +9,10 @@ use uuid::Uuid;
 use crate::db;
 use crate::error::{AppError, AppResult};
 use crate::middlewares::auth::AdminAuth;
-use crate::models::PayoutStatus;
+use crate::models::{ModerateReview, PayoutStatus, Review};
+use crate::responses::PaginationParams;
 use crate::state::AppState;
+use crate::validation;
 
 pub fn router() -> Router<AppState> {
     Router::new()
@@ -23,6 +25,8 @@ pub fn router() -> Router<AppState> {
         .route("/orders/{id}", get(get_order_details))
         .route("/checkouts/{id}", get(get_checkout_details))
         .route("/email/test", post(send_test_email))
+        .route("/reviews", get(list_reviews))
+        .route("/reviews/{id}/visibility", patch(moderate_review))
 }
 
 #[derive(Debug, Serialize)]
@@ -90,6 +94,63 @@ async fn update_payout_status(
     }))
 }
 
+#[derive(Debug, Deserialize)]
+struct ReviewsQuery {
+    #[serde(default)]
+    hidden_only: bool,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+async fn list_reviews(
+    State(state): State<AppState>,
+    AdminAuth(_user): AdminAuth,
+    Query(query): Query<ReviewsQuery>,
+) -> AppResult<Json<Vec<Review>>> {
+    let defaults = PaginationParams::default();
+    let pagination = PaginationParams {
+        limit: query.limit.unwrap_or(defaults.limit),
+        offset: query.offset.unwrap_or(defaults.offset),
+    }
+    .validated();
+    let reviews = db::review::get_reviews_for_moderation(
+        &state.db,
+        query.hidden_only,
+        pagination.limit,
+        pagination.offset,
+    )
+    .await?;
+    Ok(Json(reviews))
+}
+
+async fn moderate_review(
+    State(state): State<AppState>,
+    AdminAuth(_user): AdminAuth,
+    Path(review_id): Path<Uuid>,
+    Json(input): Json<ModerateReview>,
+) -> AppResult<Json<Review>> {
+    let reason = input
+        .reason
+        .as_deref()
+        .map(|r| validation::sanitize_text(r.trim()))
+        .filter(|r| !r.is_empty());
+    if reason
+        .as_ref()
+        .is_some_and(|r| r.len() > validation::MAX_FEEDBACK_LENGTH)
+    {
+        return Err(AppError::Validation(format!(
+            "Reason exceeds maximum length of {} characters",
+            validation::MAX_FEEDBACK_LENGTH
+        )));
+    }
+
+    let review = db::review::set_hidden(&state.db, review_id, input.hidden, reason.as_deref())
+        .await?
+        .ok_or_else(|| AppError::NotFound("Review not found".into()))?;
+
+    Ok(Json(review))
+}
+
 async fn get_order_details(
     State(state): State<AppState>,
     AdminAuth(_user): AdminAuth,
@@ -209,7 +209,7 @@ async fn request_reset_password(
     let user = db::user::get_user_by_email(&state.db, &input.email).await?;
 
     if let Some(_user) = user {
-        let reset_token = state.auth.generate_reset_token();
+        let reset_token = state.auth.generate_random_token();
 
         db::user::set_reset_token(&state.db, &input.email, &reset_token).await?;
 
@@ -7,18 +7,29 @@ use chrono::NaiveDate;
 use serde::Deserialize;
 use uuid::Uuid;
 
+use crate::calendar::{self, PublishingSchedule};
 use crate::db;
-use crate::db::sponsor::{BookingFilters, BookingSortBy, CreateBookingError};
+use crate::db::sponsor::{BookingFilters, BookingSortBy, CreateBookingError, NewBooking};
 use crate::error::{AppError, AppResult};
 use crate::helpers::{
-    get_booking_or_404, get_sponsor_for_user_or_404, get_writer_for_user_or_404, get_writer_or_404,
-    require_booking_ownership_by_writer,
+    get_booking_or_404, get_offered_placement, get_placement_or_404, get_sponsor_for_user_or_404,
+    get_sponsor_or_404, get_waitlist_offer_or_404, get_writer_for_user_or_404, get_writer_or_404,
+    require_booking_access, require_booking_ownership_by_writer, require_issue_date,
+    require_lead_time, require_not_paused,
+};
+use crate::lifecycle::cancellation::{self, CancellationQuote};
+use crate::lifecycle::{self, Actor};
+use crate::middlewares::{Auth, SponsorAuth, WriterAuth};
+use crate::models::{
+    Booking, BookingActor, BookingChangeRequest, BookingEvent, BookingEventType, BookingMessage,
+    BookingReschedule, BookingStatus, BookingWithDetails, CreateBooking, CreateBookingMessage,
+    CreateReview, ProposeReschedule, RescheduleStatus, Review, Writer,
 };
-use crate::middlewares::{SponsorAuth, WriterAuth};
-use crate::models::{BookingStatus, BookingWithDetails, CreateBooking};
 use crate::responses::{DataResponse, PaginatedResponse, PaginationParams, SuccessResponse};
 use crate::services::{
-    BookingPublishedData, BookingRejectedData, BookingStatusData, CreateCheckoutParams,
+    AdResubmittedData, BookingCancelledData, BookingMessageData, BookingPublishedData,
+    BookingRejectedData, BookingStatusData, ChangesRequestedData, CreateCheckoutParams,
+    RescheduleProposedData, RescheduleResponseData,
 };
 use crate::state::AppState;
 use crate::validation;
@@ -31,13 +42,31 @@ pub fn router() -> Router<AppState> {
         .route("/{id}", get(get_booking).patch(update_booking_ad_content))
         .route("/{id}/approve", patch(approve_booking))
         .route("/{id}/reject", patch(reject_booking))
+        .route("/{id}/request-changes", patch(request_changes))
+        .route("/{id}/resubmit", patch(resubmit_booking))
+        .route("/{id}/change-requests", get(get_change_requests))
+        .route(
+            "/{id}/reschedules",
+            get(get_reschedules).post(propose_reschedule),
+        )
+        .route("/{id}/reschedules/accept", patch(accept_reschedule))
+        .route("/{id}/reschedules/decline", patch(decline_reschedule))
+        .route("/{id}/messages", get(get_messages).post(post_message))
         .route("/{id}/mark-published", patch(mark_published))
+        .route("/{id}/events", get(get_booking_events))
+        .route("/{id}/cancellation-quote", get(get_cancellation_quote))
+        .route("/{id}/cancel", patch(cancel_booking))
+        .route("/{id}/review", post(create_review))
 }
 
 #[derive(Debug, serde::Serialize)]
 struct CreateBookingData {
     booking_id: Uuid,
+    bundle_id: Option<Uuid>,
+    booking_ids: Vec<Uuid>,
     checkout_url: String,
+    /// The slots are held for the sponsor until then
+    hold_expires_at: chrono::DateTime<chrono::Utc>,
 }
 
 async fn create_booking(
@@ -55,16 +84,37 @@ async fn create_booking(
 
     let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
     let writer = get_writer_or_404(&state.db, input.writer_id).await?;
+    let placement = get_offered_placement(&state.db, &writer, input.placement_id).await?;
+
+    validation::validate_placement_copy(&sanitized, &placement)?;
+
+    let slot_dates = validation::validate_slot_dates(
+        input.slot_date,
+        input.slot_dates.as_deref(),
+        input.issue_count,
+        &PublishingSchedule::for_writer(&writer),
+    )?;
 
-    let today = chrono::Utc::now().date_naive();
-    let min_date = today + chrono::Duration::days(writer.lead_time_days as i64);
-    if input.slot_date < min_date {
-        return Err(AppError::BadRequest(format!(
-            "Slot date must be at least {} days in the future",
-            writer.lead_time_days
-        )));
+    require_lead_time(&writer, slot_dates[0])?;
+    for &date in &slot_dates {
+        require_issue_date(&writer, date)?;
+        require_not_paused(&writer, date)?;
     }
 
+    let waitlist_entry = match &input.waitlist_offer {
+        Some(token) => {
+            let token_hash = state.auth.hash_token(token);
+            let entry = get_waitlist_offer_or_404(&state.db, &token_hash, sponsor.id).await?;
+            if entry.placement_id != placement.id || slot_dates != [entry.slot_date] {
+                return Err(AppError::BadRequest(
+                    "A waitlist offer can only be used to book the slot it was made for".into(),
+                ));
+            }
+            Some(entry)
+        }
+        None => None,
+    };
+
     let payments = state.require_payments()?;
 
     let sponsor_email = sponsor.billing_email.clone().ok_or_else(|| {
@@ -74,42 +124,71 @@ async fn create_booking(
         )
     })?;
 
+    let prices =
+        db::availability::get_slot_prices(&state.db, &writer, &placement, &slot_dates).await?;
+    let slots: Vec<(Uuid, NaiveDate, i32)> = slot_dates
+        .iter()
+        .zip(prices)
+        .map(|(&date, price_cents)| (Uuid::now_v7(), date, price_cents))
+        .collect();
+    let bundle_id = (slots.len() > 1).then(Uuid::now_v7);
+    let payment_expires_at = chrono::Utc::now()
+        + chrono::Duration::minutes(state.config.bookings.checkout_expiry_minutes);
+
     let checkout_params = CreateCheckoutParams {
-        booking_id: Uuid::now_v7().to_string(),
+        booking_id: slots[0].0.to_string(),
+        bundle_id: bundle_id.map(|id| id.to_string()),
         writer_id: writer.id.to_string(),
         sponsor_id: sponsor.id.to_string(),
         sponsor_email,
         newsletter_name: writer.newsletter_name.clone(),
-        slot_date: input.slot_date.to_string(),
-        amount_cents: writer.price_per_slot as i64,
+        slot_date: slot_dates
+            .iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+        amount_cents: slots.iter().map(|&(_, _, price)| price as i64).sum(),
         success_url: format!(
             "{}/bookings/success?session_id={{CHECKOUT_SESSION_ID}}",
             state.config.server.frontend_url
         ),
-        expires_at: None,
+        expires_at: Some(payment_expires_at.to_rfc3339()),
     };
 
     let checkout = payments.create_checkout(checkout_params).await?;
 
-    let booking = db::sponsor::create_booking_with_availability_check(
+    let bookings = db::sponsor::create_bookings_with_availability_check(
         &state.db,
-        sponsor.id,
-        &writer,
-        input.slot_date,
-        &sanitized,
-        &checkout.checkout_id,
+        &NewBooking {
+            sponsor_id: sponsor.id,
+            writer: &writer,
+            placement: &placement,
+            slots: &slots,
+            bundle_id,
+            waitlist_entry_id: waitlist_entry.map(|e| e.id),
+            ad_content: &sanitized,
+            lemon_order_id: &checkout.checkout_id,
+            payment_expires_at,
+            created_by: user.id,
+        },
     )
     .await
     .map_err(|e| match e {
-        CreateBookingError::SlotNotAvailable => {
-            AppError::Conflict("This slot is no longer available".into())
+        CreateBookingError::SlotNotAvailable(date) => {
+            AppError::Conflict(format!("The slot on {} is no longer available", date))
+        }
+        CreateBookingError::OfferExpired => {
+            AppError::Conflict("This waitlist offer has expired".into())
         }
         CreateBookingError::Database(err) => AppError::from(err),
     })?;
 
     Ok(Json(DataResponse::new(CreateBookingData {
-        booking_id: booking.id,
+        booking_id: bookings[0].id,
+        bundle_id,
+        booking_ids: bookings.iter().map(|b| b.id).collect(),
         checkout_url: checkout.checkout_url,
+        hold_expires_at: payment_expires_at,
     })))
 }
 
@@ -120,7 +199,7 @@ async fn get_booking(
 ) -> AppResult<Json<DataResponse<BookingWithDetails>>> {
     let writer = get_writer_for_user_or_404(&state.db, user.id).await?;
 
-    let bookings = db::sponsor::get_writer_bookings(&state.db, writer.id).await?;
+    let bookings = db::sponsor::get_writer_bookings(&state.db, writer.id, user.id).await?;
 
     let booking = bookings
         .into_iter()
@@ -184,6 +263,7 @@ fn parse_booking_status(s: &str) -> Option<BookingStatus> {
     match s.to_lowercase().as_str() {
         "pending_payment" => Some(BookingStatus::PendingPayment),
         "paid" => Some(BookingStatus::Paid),
+        "changes_requested" => Some(BookingStatus::ChangesRequested),
         "approved" => Some(BookingStatus::Approved),
         "rejected" => Some(BookingStatus::Rejected),
         "published" => Some(BookingStatus::Published),
@@ -210,6 +290,7 @@ async fn get_sponsor_bookings(
         pagination.offset,
         &filters,
         sort_by,
+        user.id,
     )
     .await?;
 
@@ -238,6 +319,7 @@ async fn get_writer_bookings(
         pagination.offset,
         &filters,
         sort_by,
+        user.id,
     )
     .await?;
 
@@ -271,7 +353,10 @@ async fn update_booking_ad_content(
         return Err(AppError::Forbidden);
     }
 
-    if booking.status != BookingStatus::PendingPayment && booking.status != BookingStatus::Paid {
+    if !matches!(
+        booking.status,
+        BookingStatus::PendingPayment | BookingStatus::Paid | BookingStatus::ChangesRequested
+    ) {
         return Err(AppError::BadRequest(
             "Can only edit ad content before the booking is approved".into(),
         ));
@@ -285,9 +370,12 @@ async fn update_booking_ad_content(
         input.ad_image_url.as_deref(),
     )?;
 
+    let placement = get_placement_or_404(&state.db, booking.placement_id).await?;
+    validation::validate_placement_copy(&sanitized, &placement)?;
+
     db::sponsor::update_booking_ad_content(&state.db, id, &sanitized).await?;
 
-    let bookings = db::sponsor::get_sponsor_bookings(&state.db, sponsor.id).await?;
+    let bookings = db::sponsor::get_sponsor_bookings(&state.db, sponsor.id, user.id).await?;
     let updated_booking = bookings
         .into_iter()
         .find(|b| b.id == id)
@@ -316,7 +404,14 @@ async fn approve_booking(
         ));
     }
 
-    db::sponsor::update_booking_status(&state.db, id, BookingStatus::Approved).await?;
+    lifecycle::transition(
+        &state.db,
+        id,
+        BookingStatus::Approved,
+        Actor::user(&user),
+        None,
+    )
+    .await?;
 
     // Send email notification to sponsor
     if let Some(email_service) = &state.email {
@@ -325,7 +420,11 @@ async fn approve_booking(
             if let Some(billing_email) = &sponsor.billing_email {
                 let email_data = BookingStatusData {
                     newsletter_name: writer.newsletter_name.clone(),
-                    slot_date: booking.slot_date.to_string(),
+                    slot_date: calendar::format_writer_date(
+                        booking.slot_date,
+                        &writer.timezone,
+                        &sponsor.timezone,
+                    ),
                     dashboard_url: format!(
                         "{}/dashboard/bookings",
                         state.config.server.frontend_url
@@ -354,18 +453,27 @@ async fn reject_booking(
     let booking = get_booking_or_404(&state.db, id).await?;
     require_booking_ownership_by_writer(&booking, writer.id)?;
 
-    if booking.status != BookingStatus::Paid && booking.status != BookingStatus::Approved {
+    if !matches!(
+        booking.status,
+        BookingStatus::Paid | BookingStatus::ChangesRequested | BookingStatus::Approved
+    ) {
         return Err(AppError::BadRequest(
             "Can only reject bookings that are paid or approved".into(),
         ));
     }
 
-    if let Some(order_id) = &booking.lemon_order_id {
-        let payments = state.require_payments()?;
-        payments.refund_order(order_id).await?;
-    }
+    let reason = validation::validate_reason(input.reason.as_deref())?;
+
+    lifecycle::refund_payment(&state, &booking, booking.amount_cents).await?;
 
-    db::sponsor::update_booking_status(&state.db, id, BookingStatus::Rejected).await?;
+    lifecycle::transition(
+        &state.db,
+        id,
+        BookingStatus::Rejected,
+        Actor::user(&user),
+        reason.as_deref(),
+    )
+    .await?;
 
     // Send email notification to sponsor with rejection reason
     if let Some(email_service) = &state.email {
@@ -374,10 +482,14 @@ async fn reject_booking(
             if let Some(billing_email) = &sponsor.billing_email {
                 let email_data = BookingRejectedData {
                     newsletter_name: writer.newsletter_name.clone(),
-                    slot_date: booking.slot_date.to_string(),
+                    slot_date: calendar::format_writer_date(
+                        booking.slot_date,
+                        &writer.timezone,
+                        &sponsor.timezone,
+                    ),
                     amount_cents: booking.amount_cents,
                     currency: booking.currency.clone(),
-                    reason: input.reason.clone(),
+                    reason: reason.clone(),
                 };
                 if let Err(e) = email_service
                     .send_booking_rejected(billing_email, email_data)
@@ -391,8 +503,7 @@ async fn reject_booking(
 
     let message = format!(
         "Booking rejected{}",
-        input
-            .reason
+        reason
             .map(|r| format!(" - Reason: {}", r))
             .unwrap_or_default()
     );
@@ -400,10 +511,17 @@ async fn reject_booking(
     Ok(Json(SuccessResponse::new(message)))
 }
 
+#[derive(Debug, Default, Deserialize)]
+struct MarkPublishedInput {
+    published_url: Option<String>,
+    publication_proof_url: Option<String>,
+}
+
 async fn mark_published(
     State(state): State<AppState>,
     WriterAuth(user): WriterAuth,
     Path(id): Path<Uuid>,
+    input: Option<Json<MarkPublishedInput>>,
 ) -> AppResult<Json<SuccessResponse>> {
     let writer = get_writer_for_user_or_404(&state.db, user.id).await?;
     let booking = get_booking_or_404(&state.db, id).await?;
@@ -415,7 +533,40 @@ async fn mark_published(
         ));
     }
 
-    db::sponsor::update_booking_status(&state.db, id, BookingStatus::Published).await?;
+    let input = input.map(|Json(input)| input).unwrap_or_default();
+    let published_url = input
+        .published_url
+        .as_deref()
+        .filter(|url| !url.trim().is_empty())
+        .map(validation::validate_url)
+        .transpose()?;
+    let publication_proof_url = input
+        .publication_proof_url
+        .as_deref()
+        .filter(|url| !url.trim().is_empty())
+        .map(validation::validate_url)
+        .transpose()?;
+
+    let mut tx = state.db.begin().await?;
+
+    lifecycle::transition_tx(
+        &mut tx,
+        id,
+        BookingStatus::Published,
+        Actor::user(&user),
+        None,
+    )
+    .await?;
+
+    db::sponsor::set_publication_proof(
+        &mut tx,
+        id,
+        published_url.as_deref(),
+        publication_proof_url.as_deref(),
+    )
+    .await?;
+
+    tx.commit().await?;
 
     // Send email notification to sponsor that their ad was published
     if let Some(email_service) = &state.email {
@@ -424,12 +575,18 @@ async fn mark_published(
             if let Some(billing_email) = &sponsor.billing_email {
                 let email_data = BookingPublishedData {
                     newsletter_name: writer.newsletter_name.clone(),
-                    slot_date: booking.slot_date.to_string(),
+                    slot_date: calendar::format_writer_date(
+                        booking.slot_date,
+                        &writer.timezone,
+                        &sponsor.timezone,
+                    ),
                     subscriber_count: writer.subscriber_count,
                     ad_headline: booking.ad_headline.clone(),
                     ad_body: booking.ad_body.clone(),
                     ad_cta_text: booking.ad_cta_text.clone(),
                     ad_cta_url: booking.ad_cta_url.clone(),
+                    published_url,
+                    publication_proof_url,
                     dashboard_url: format!(
                         "{}/dashboard/bookings",
                         state.config.server.frontend_url
@@ -447,3 +604,702 @@ async fn mark_published(
 
     Ok(Json(SuccessResponse::new("Booking marked as published")))
 }
+
+#[derive(Debug, Deserialize)]
+struct RequestChangesInput {
+    feedback: String,
+}
+
+async fn request_changes(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+    Json(input): Json<RequestChangesInput>,
+) -> AppResult<Json<DataResponse<BookingChangeRequest>>> {
+    let writer = get_writer_for_user_or_404(&state.db, user.id).await?;
+    let booking = get_booking_or_404(&state.db, id).await?;
+    require_booking_ownership_by_writer(&booking, writer.id)?;
+
+    if booking.status != BookingStatus::Paid {
+        return Err(AppError::BadRequest(
+            "Can only request changes on bookings awaiting review".into(),
+        ));
+    }
+
+    let feedback = validation::validate_change_feedback(&input.feedback)?;
+
+    let mut tx = state.db.begin().await?;
+
+    lifecycle::transition_tx(
+        &mut tx,
+        id,
+        BookingStatus::ChangesRequested,
+        Actor::user(&user),
+        Some(&feedback),
+    )
+    .await?;
+
+    let change_request =
+        db::booking_change_request::insert_change_request(&mut tx, id, user.id, &feedback).await?;
+
+    tx.commit().await?;
+
+    if let Some(email_service) = &state.email {
+        if let Some(sponsor) = db::sponsor::get_sponsor_by_id(&state.db, booking.sponsor_id).await?
+        {
+            if let Some(billing_email) = &sponsor.billing_email {
+                let email_data = ChangesRequestedData {
+                    newsletter_name: writer.newsletter_name.clone(),
+                    slot_date: calendar::format_writer_date(
+                        booking.slot_date,
+                        &writer.timezone,
+                        &sponsor.timezone,
+                    ),
+                    ad_headline: booking.ad_headline.clone(),
+                    feedback: feedback.clone(),
+                    dashboard_url: format!(
+                        "{}/dashboard/bookings",
+                        state.config.server.frontend_url
+                    ),
+                };
+                if let Err(e) = email_service
+                    .send_changes_requested(billing_email, email_data)
+                    .await
+                {
+                    tracing::warn!("Failed to send changes requested email: {}", e);
+                }
+            }
+        }
+    }
+
+    Ok(Json(DataResponse::new(change_request)))
+}
+
+async fn resubmit_booking(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<SuccessResponse>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    let booking = get_booking_or_404(&state.db, id).await?;
+
+    if booking.sponsor_id != sponsor.id {
+        return Err(AppError::Forbidden);
+    }
+
+    if booking.status != BookingStatus::ChangesRequested {
+        return Err(AppError::BadRequest(
+            "Only bookings with requested changes can be resubmitted".into(),
+        ));
+    }
+
+    let mut tx = state.db.begin().await?;
+
+    // Back to `paid` puts the booking in the writer's review queue again
+    let updated = lifecycle::transition_tx(
+        &mut tx,
+        id,
+        BookingStatus::Paid,
+        Actor::user(&user),
+        Some("Ad resubmitted"),
+    )
+    .await?;
+
+    db::booking_change_request::mark_change_requests_resubmitted(&mut tx, id).await?;
+
+    tx.commit().await?;
+
+    if let Some(email_service) = &state.email {
+        let writer = get_writer_or_404(&state.db, booking.writer_id).await?;
+        if let Some(writer_user) = db::user::get_user_by_id(&state.db, writer.user_id).await? {
+            let email_data = AdResubmittedData {
+                sponsor_name: sponsor.company_name.clone(),
+                slot_date: calendar::format_writer_date(
+                    updated.slot_date,
+                    &writer.timezone,
+                    &writer.timezone,
+                ),
+                ad_headline: updated.ad_headline.clone(),
+                dashboard_url: format!("{}/dashboard/bookings", state.config.server.frontend_url),
+            };
+            if let Err(e) = email_service
+                .send_ad_resubmitted(&writer_user.email, email_data)
+                .await
+            {
+                tracing::warn!("Failed to send ad resubmitted email: {}", e);
+            }
+        }
+    }
+
+    Ok(Json(SuccessResponse::new("Ad resubmitted for review")))
+}
+
+async fn get_change_requests(
+    State(state): State<AppState>,
+    Auth(user): Auth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<DataResponse<Vec<BookingChangeRequest>>>> {
+    let booking = get_booking_or_404(&state.db, id).await?;
+    require_booking_access(&state.db, &booking, &user).await?;
+
+    let change_requests = db::booking_change_request::get_change_requests(&state.db, id).await?;
+
+    Ok(Json(DataResponse::new(change_requests)))
+}
+
+fn is_reschedulable(status: BookingStatus) -> bool {
+    matches!(
+        status,
+        BookingStatus::Paid | BookingStatus::ChangesRequested | BookingStatus::Approved
+    )
+}
+
+async fn party_contact(
+    state: &AppState,
+    booking: &Booking,
+    party: BookingActor,
+) -> AppResult<Option<(String, String)>> {
+    match party {
+        BookingActor::Writer => {
+            let writer = get_writer_or_404(&state.db, booking.writer_id).await?;
+            Ok(db::user::get_user_by_id(&state.db, writer.user_id)
+                .await?
+                .map(|u| (u.email, writer.timezone)))
+        }
+        BookingActor::Sponsor => Ok(
+            db::sponsor::get_sponsor_by_id(&state.db, booking.sponsor_id)
+                .await?
+                .and_then(|s| s.billing_email.map(|email| (email, s.timezone))),
+        ),
+        _ => Ok(None),
+    }
+}
+
+async fn propose_reschedule(
+    State(state): State<AppState>,
+    Auth(user): Auth,
+    Path(id): Path<Uuid>,
+    Json(input): Json<ProposeReschedule>,
+) -> AppResult<Json<DataResponse<BookingReschedule>>> {
+    let booking = get_booking_or_404(&state.db, id).await?;
+    require_booking_access(&state.db, &booking, &user).await?;
+
+    let actor = Actor::user(&user);
+    let counterparty = match actor.kind {
+        BookingActor::Writer => BookingActor::Sponsor,
+        BookingActor::Sponsor => BookingActor::Writer,
+        _ => return Err(AppError::Forbidden),
+    };
+
+    if !is_reschedulable(booking.status) {
+        return Err(AppError::BadRequest(
+            "Only paid or approved bookings can be rescheduled".into(),
+        ));
+    }
+
+    if input.new_date == booking.slot_date {
+        return Err(AppError::BadRequest(
+            "The booking is already on that date".into(),
+        ));
+    }
+
+    let writer = get_writer_or_404(&state.db, booking.writer_id).await?;
+    require_lead_time(&writer, input.new_date)?;
+    require_issue_date(&writer, input.new_date)?;
+    require_not_paused(&writer, input.new_date)?;
+
+    let placement = get_placement_or_404(&state.db, booking.placement_id).await?;
+    if !db::availability::is_slot_available(&state.db, &writer, &placement, input.new_date).await? {
+        return Err(AppError::Conflict(
+            "The proposed date is not available".into(),
+        ));
+    }
+
+    let reason = validation::validate_reason(input.reason.as_deref())?;
+
+    let mut tx = state.db.begin().await?;
+
+    let status = db::sponsor::lock_booking_status(&mut tx, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Booking not found".into()))?;
+    if !is_reschedulable(status) {
+        return Err(AppError::Conflict(
+            "Booking was updated while proposing, please try again".into(),
+        ));
+    }
+
+    if db::booking_reschedule::lock_pending_reschedule(&mut tx, id)
+        .await?
+        .is_some()
+    {
+        return Err(AppError::Conflict(
+            "This booking already has a pending reschedule proposal".into(),
+        ));
+    }
+
+    let reschedule = db::booking_reschedule::create_reschedule(
+        &mut tx,
+        id,
+        actor.kind,
+        user.id,
+        booking.slot_date,
+        input.new_date,
+        reason.as_deref(),
+    )
+    .await?;
+
+    lifecycle::record_event_tx(
+        &mut tx,
+        id,
+        BookingEventType::RescheduleProposed,
+        actor,
+        Some(&format!(
+            "Proposed moving from {} to {}",
+            booking.slot_date, input.new_date
+        )),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    if let Some(email_service) = &state.email {
+        if let Some((email, timezone)) = party_contact(&state, &booking, counterparty).await? {
+            let proposed_by_name = match actor.kind {
+                BookingActor::Writer => writer.newsletter_name.clone(),
+                _ => {
+                    get_sponsor_or_404(&state.db, booking.sponsor_id)
+                        .await?
+                        .company_name
+                }
+            };
+            let email_data = RescheduleProposedData {
+                newsletter_name: writer.newsletter_name.clone(),
+                proposed_by_name,
+                current_date: calendar::format_writer_date(
+                    booking.slot_date,
+                    &writer.timezone,
+                    &timezone,
+                ),
+                proposed_date: calendar::format_writer_date(
+                    input.new_date,
+                    &writer.timezone,
+                    &timezone,
+                ),
+                reason,
+                dashboard_url: format!("{}/dashboard/bookings", state.config.server.frontend_url),
+            };
+            if let Err(e) = email_service
+                .send_reschedule_proposed(&email, email_data)
+                .await
+            {
+                tracing::warn!("Failed to send reschedule proposal email: {}", e);
+            }
+        }
+    }
+
+    Ok(Json(DataResponse::new(reschedule)))
+}
+
+async fn accept_reschedule(
+    State(state): State<AppState>,
+    Auth(user): Auth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<DataResponse<BookingReschedule>>> {
+    let booking = get_booking_or_404(&state.db, id).await?;
+    require_booking_access(&state.db, &booking, &user).await?;
+    let writer = get_writer_or_404(&state.db, booking.writer_id).await?;
+
+    let actor = Actor::user(&user);
+
+    let mut tx = state.db.begin().await?;
+
+    let status = db::sponsor::lock_booking_status(&mut tx, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Booking not found".into()))?;
+    if !is_reschedulable(status) {
+        return Err(AppError::BadRequest(
+            "Only paid or approved bookings can be rescheduled".into(),
+        ));
+    }
+
+    let proposal = db::booking_reschedule::lock_pending_reschedule(&mut tx, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("No pending reschedule proposal".into()))?;
+
+    if proposal.proposed_by == actor.kind {
+        return Err(AppError::BadRequest(
+            "A reschedule must be accepted by the other party".into(),
+        ));
+    }
+
+    require_lead_time(&writer, proposal.to_date)?;
+    require_issue_date(&writer, proposal.to_date)?;
+    require_not_paused(&writer, proposal.to_date)?;
+
+    // Same week lock as booking creation, so the new date cannot be oversold
+    if !db::sponsor::check_slot_available_tx(
+        &mut tx,
+        writer.id,
+        booking.placement_id,
+        proposal.to_date,
+    )
+    .await?
+    {
+        return Err(AppError::Conflict(
+            "The proposed date is no longer available".into(),
+        ));
+    }
+
+    db::sponsor::move_booking_slot(&mut tx, id, proposal.to_date).await?;
+
+    let accepted = db::booking_reschedule::resolve_reschedule(
+        &mut tx,
+        proposal.id,
+        RescheduleStatus::Accepted,
+        user.id,
+    )
+    .await?;
+
+    lifecycle::record_event_tx(
+        &mut tx,
+        id,
+        BookingEventType::RescheduleAccepted,
+        actor,
+        Some(&format!(
+            "Moved from {} to {}",
+            proposal.from_date, proposal.to_date
+        )),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    notify_reschedule_response(&state, &booking, &writer, &accepted).await?;
+
+    Ok(Json(DataResponse::new(accepted)))
+}
+
+async fn decline_reschedule(
+    State(state): State<AppState>,
+    Auth(user): Auth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<DataResponse<BookingReschedule>>> {
+    let booking = get_booking_or_404(&state.db, id).await?;
+    require_booking_access(&state.db, &booking, &user).await?;
+    let writer = get_writer_or_404(&state.db, booking.writer_id).await?;
+
+    let actor = Actor::user(&user);
+
+    let mut tx = state.db.begin().await?;
+
+    let proposal = db::booking_reschedule::lock_pending_reschedule(&mut tx, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("No pending reschedule proposal".into()))?;
+
+    // The proposer declining their own proposal withdraws it
+    let (status, reason) = if proposal.proposed_by == actor.kind {
+        (RescheduleStatus::Withdrawn, "Proposal withdrawn")
+    } else {
+        (RescheduleStatus::Declined, "Proposal declined")
+    };
+
+    let resolved =
+        db::booking_reschedule::resolve_reschedule(&mut tx, proposal.id, status, user.id).await?;
+
+    lifecycle::record_event_tx(
+        &mut tx,
+        id,
+        BookingEventType::RescheduleDeclined,
+        actor,
+        Some(reason),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    if status == RescheduleStatus::Declined {
+        notify_reschedule_response(&state, &booking, &writer, &resolved).await?;
+    }
+
+    Ok(Json(DataResponse::new(resolved)))
+}
+
+async fn notify_reschedule_response(
+    state: &AppState,
+    booking: &Booking,
+    writer: &Writer,
+    reschedule: &BookingReschedule,
+) -> AppResult<()> {
+    if let Some(email_service) = &state.email {
+        if let Some((email, timezone)) =
+            party_contact(state, booking, reschedule.proposed_by).await?
+        {
+            let email_data = RescheduleResponseData {
+                newsletter_name: writer.newsletter_name.clone(),
+                current_date: calendar::format_writer_date(
+                    reschedule.from_date,
+                    &writer.timezone,
+                    &timezone,
+                ),
+                proposed_date: calendar::format_writer_date(
+                    reschedule.to_date,
+                    &writer.timezone,
+                    &timezone,
+                ),
+                accepted: reschedule.status == RescheduleStatus::Accepted,
+                dashboard_url: format!("{}/dashboard/bookings", state.config.server.frontend_url),
+            };
+            if let Err(e) = email_service
+                .send_reschedule_response(&email, email_data)
+                .await
+            {
+                tracing::warn!("Failed to send reschedule response email: {}", e);
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn get_reschedules(
+    State(state): State<AppState>,
+    Auth(user): Auth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<DataResponse<Vec<BookingReschedule>>>> {
+    let booking = get_booking_or_404(&state.db, id).await?;
+    require_booking_access(&state.db, &booking, &user).await?;
+
+    let reschedules = db::booking_reschedule::get_reschedules(&state.db, id).await?;
+
+    Ok(Json(DataResponse::new(reschedules)))
+}
+
+async fn get_messages(
+    State(state): State<AppState>,
+    Auth(user): Auth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<DataResponse<Vec<BookingMessage>>>> {
+    let booking = get_booking_or_404(&state.db, id).await?;
+    require_booking_access(&state.db, &booking, &user).await?;
+
+    let messages = db::booking_message::get_messages(&state.db, id).await?;
+    if let Some(newest) = messages.last() {
+        db::booking_message::mark_read(&state.db, id, user.id, newest.created_at).await?;
+    }
+
+    Ok(Json(DataResponse::new(messages)))
+}
+
+async fn post_message(
+    State(state): State<AppState>,
+    Auth(user): Auth,
+    Path(id): Path<Uuid>,
+    Json(input): Json<CreateBookingMessage>,
+) -> AppResult<Json<DataResponse<BookingMessage>>> {
+    let booking = get_booking_or_404(&state.db, id).await?;
+    require_booking_access(&state.db, &booking, &user).await?;
+
+    let body = validation::validate_message_body(&input.body)?;
+    let actor = Actor::user(&user);
+
+    let message =
+        db::booking_message::insert_message(&state.db, id, user.id, actor.kind, &body).await?;
+    db::booking_message::mark_read(&state.db, id, user.id, message.created_at).await?;
+
+    if let Some(email_service) = &state.email {
+        let writer = get_writer_or_404(&state.db, booking.writer_id).await?;
+        let (sender_name, recipients) = match actor.kind {
+            BookingActor::Writer => (writer.newsletter_name.clone(), vec![BookingActor::Sponsor]),
+            BookingActor::Sponsor => (
+                get_sponsor_or_404(&state.db, booking.sponsor_id)
+                    .await?
+                    .company_name,
+                vec![BookingActor::Writer],
+            ),
+            _ => (
+                "The Adsloty team".to_string(),
+                vec![BookingActor::Writer, BookingActor::Sponsor],
+            ),
+        };
+
+        for recipient in recipients {
+            let Some((email, timezone)) = party_contact(&state, &booking, recipient).await? else {
+                continue;
+            };
+            let email_data = BookingMessageData {
+                newsletter_name: writer.newsletter_name.clone(),
+                sender_name: sender_name.clone(),
+                slot_date: calendar::format_writer_date(
+                    booking.slot_date,
+                    &writer.timezone,
+                    &timezone,
+                ),
+                message: body.clone(),
+                dashboard_url: format!("{}/dashboard/bookings", state.config.server.frontend_url),
+            };
+            if let Err(e) = email_service.send_booking_message(&email, email_data).await {
+                tracing::warn!("Failed to send booking message email: {}", e);
+            }
+        }
+    }
+
+    Ok(Json(DataResponse::new(message)))
+}
+
+async fn get_booking_events(
+    State(state): State<AppState>,
+    Auth(user): Auth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<DataResponse<Vec<BookingEvent>>>> {
+    let booking = get_booking_or_404(&state.db, id).await?;
+    require_booking_access(&state.db, &booking, &user).await?;
+
+    let events = db::booking_event::get_booking_events(&state.db, id).await?;
+
+    Ok(Json(DataResponse::new(events)))
+}
+
+async fn get_cancellation_quote(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<DataResponse<CancellationQuote>>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    let booking = get_booking_or_404(&state.db, id).await?;
+
+    if booking.sponsor_id != sponsor.id {
+        return Err(AppError::Forbidden);
+    }
+
+    let writer = get_writer_or_404(&state.db, booking.writer_id).await?;
+    let quote =
+        cancellation::quote_cancellation(&booking, &writer, calendar::today_in(&writer.timezone))?;
+
+    Ok(Json(DataResponse::new(quote)))
+}
+
+#[derive(Debug, Deserialize)]
+struct CancelBookingInput {
+    reason: Option<String>,
+}
+
+async fn cancel_booking(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Path(id): Path<Uuid>,
+    Json(input): Json<CancelBookingInput>,
+) -> AppResult<Json<DataResponse<CancellationQuote>>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    let booking = get_booking_or_404(&state.db, id).await?;
+
+    if booking.sponsor_id != sponsor.id {
+        return Err(AppError::Forbidden);
+    }
+
+    let reason = validation::validate_reason(input.reason.as_deref())?;
+
+    let writer = get_writer_or_404(&state.db, booking.writer_id).await?;
+    let quote =
+        cancellation::quote_cancellation(&booking, &writer, calendar::today_in(&writer.timezone))?;
+
+    let platform_fee_cents = writer.platform_fee_cents(quote.retained_cents);
+    let writer_payout_cents = quote.retained_cents - platform_fee_cents;
+
+    let mut tx = state.db.begin().await?;
+
+    let current_status = db::sponsor::lock_booking_status(&mut tx, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Booking not found".into()))?;
+    if current_status != booking.status {
+        return Err(AppError::Conflict(
+            "Booking was updated while cancelling, please try again".into(),
+        ));
+    }
+
+    lifecycle::transition_tx(
+        &mut tx,
+        id,
+        BookingStatus::Cancelled,
+        Actor::user(&user),
+        reason.as_deref(),
+    )
+    .await?;
+
+    db::sponsor::record_booking_refund(
+        &mut tx,
+        id,
+        quote.refund_cents,
+        platform_fee_cents,
+        writer_payout_cents,
+    )
+    .await?;
+
+    // Refund while the booking row is still locked, so a racing refund webhook
+    // only ever sees the booking as cancelled
+    lifecycle::refund_payment(&state, &booking, quote.refund_cents).await?;
+
+    tx.commit().await?;
+
+    if let Some(email_service) = &state.email {
+        let email_data = |recipient_timezone: &str| BookingCancelledData {
+            newsletter_name: writer.newsletter_name.clone(),
+            sponsor_name: sponsor.company_name.clone(),
+            slot_date: calendar::format_writer_date(
+                booking.slot_date,
+                &writer.timezone,
+                recipient_timezone,
+            ),
+            refund_cents: quote.refund_cents,
+            currency: booking.currency.clone(),
+            reason: reason.clone(),
+            dashboard_url: format!("{}/dashboard/bookings", state.config.server.frontend_url),
+        };
+
+        if let Some(billing_email) = &sponsor.billing_email {
+            if let Err(e) = email_service
+                .send_booking_cancelled(billing_email, email_data(&sponsor.timezone))
+                .await
+            {
+                tracing::warn!("Failed to send cancellation email to sponsor: {}", e);
+            }
+        }
+
+        if let Some(writer_user) = db::user::get_user_by_id(&state.db, writer.user_id).await? {
+            if let Err(e) = email_service
+                .send_booking_cancelled_writer(&writer_user.email, email_data(&writer.timezone))
+                .await
+            {
+                tracing::warn!("Failed to send cancellation email to writer: {}", e);
+            }
+        }
+    }
+
+    Ok(Json(DataResponse::new(quote)))
+}
+
+async fn create_review(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Path(id): Path<Uuid>,
+    Json(input): Json<CreateReview>,
+) -> AppResult<Json<DataResponse<Review>>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    let booking = get_booking_or_404(&state.db, id).await?;
+
+    if booking.sponsor_id != sponsor.id {
+        return Err(AppError::Forbidden);
+    }
+
+    if booking.status != BookingStatus::Published {
+        return Err(AppError::BadRequest(
+            "Only published bookings can be reviewed".into(),
+        ));
+    }
+
+    let input = validation::validate_review(input)?;
+
+    let review = db::review::create_review(&state.db, &booking, &input)
+        .await?
+        .ok_or_else(|| AppError::Conflict("This booking has already been reviewed".into()))?;
+
+    Ok(Json(DataResponse::new(review)))
+}
@@ -0,0 +1,107 @@
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+    routing::get,
+    Router,
+};
+
+use crate::calendar::{
+    self,
+    ics::{self, IcsEvent},
+};
+use crate::db;
+use crate::error::{AppError, AppResult};
+use crate::helpers::get_writer_or_404;
+use crate::models::BlackoutRecurrence;
+use crate::state::AppState;
+use crate::validation::unescape_text;
+
+const FEED_WEEKS: i32 = 26;
+
+pub fn router() -> Router<AppState> {
+    Router::new().route("/{token}", get(calendar_feed))
+}
+
+/// The token in the URL is the only credential, so calendar apps can poll without signing in.
+async fn calendar_feed(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> AppResult<impl IntoResponse> {
+    let token = token.strip_suffix(".ics").unwrap_or(&token);
+    let token_hash = state.auth.hash_token(token);
+
+    let writer_id = db::calendar_feed::get_writer_id_by_token_hash(&state.db, &token_hash)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Calendar feed not found".into()))?;
+    let writer = get_writer_or_404(&state.db, writer_id).await?;
+
+    let bookings =
+        db::sponsor::get_writer_upcoming_bookings(&state.db, writer.id, FEED_WEEKS, writer.user_id)
+            .await?;
+    let manual_bookings = db::manual_booking::get_writer_manual_bookings(
+        &state.db,
+        writer.id,
+        calendar::today_in(&writer.timezone),
+    )
+    .await?;
+    let blackouts = db::blackout::get_writer_blackouts(&state.db, writer.id).await?;
+
+    let booking_events = bookings.into_iter().map(|b| IcsEvent {
+        uid: format!("booking-{}@adsloty", b.id),
+        start_date: b.slot_date,
+        end_date: b.slot_date,
+        summary: unescape_text(&format!("{}: {}", b.placement_name, b.company_name)),
+        description: Some(format!(
+            "{}\n{}\nStatus: {:?}",
+            unescape_text(&b.ad_headline),
+            b.ad_cta_url,
+            b.status
+        )),
+        recurrence: BlackoutRecurrence::Once,
+        interval_weeks: 1,
+        repeat_until: None,
+    });
+
+    let manual_events = manual_bookings.into_iter().map(|b| IcsEvent {
+        uid: format!("manual-booking-{}@adsloty", b.id),
+        start_date: b.slot_date,
+        end_date: b.slot_date,
+        summary: unescape_text(&format!(
+            "{}: {} (off-platform)",
+            b.placement_name, b.sponsor_name
+        )),
+        description: b.notes.as_deref().map(unescape_text),
+        recurrence: BlackoutRecurrence::Once,
+        interval_weeks: 1,
+        repeat_until: None,
+    });
+
+    let blackout_events = blackouts.into_iter().map(|b| IcsEvent {
+        uid: format!("blackout-{}@adsloty", b.id),
+        start_date: b.start_date,
+        end_date: b.end_date,
+        summary: match b.reason {
+            Some(reason) => format!("No sponsors: {}", reason),
+            None => "No sponsors".to_string(),
+        },
+        description: None,
+        recurrence: b.recurrence,
+        interval_weeks: b.interval_weeks,
+        repeat_until: b.repeat_until,
+    });
+
+    let events: Vec<IcsEvent> = booking_events
+        .chain(manual_events)
+        .chain(blackout_events)
+        .collect();
+    let body = ics::write_calendar(&writer.newsletter_name, &events, chrono::Utc::now());
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+            (header::CACHE_CONTROL, "private, max-age=300"),
+        ],
+        body,
+    ))
+}
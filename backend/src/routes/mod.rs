@@ -6,9 +6,13 @@ use crate::state::AppState;
 pub mod admin;
 pub mod auth;
 pub mod bookings;
+pub mod calendar;
+pub mod newsletters;
 pub mod payouts;
+pub mod saved_lists;
 pub mod sponsors;
 pub mod uploads;
+pub mod waitlist;
 pub mod webhooks;
 pub mod widget;
 pub mod writers;
@@ -25,6 +29,7 @@ pub fn api_router(rate_limit_config: &RateLimitConfig) -> Router<AppState> {
         .route("/health", get(health_check))
         .nest("/auth", auth::router().layer(auth_rate_limit))
         .nest("/writers", writers::router())
+        .nest("/newsletters", newsletters::router())
         .nest("/sponsors", sponsors::router())
         .nest(
             "/bookings",
@@ -32,7 +37,10 @@ pub fn api_router(rate_limit_config: &RateLimitConfig) -> Router<AppState> {
         )
         .nest("/payouts", payouts::router())
         .nest("/uploads", uploads::router())
+        .nest("/waitlist", waitlist::router())
+        .nest("/saved-lists", saved_lists::router())
         .nest("/widget", widget::router())
+        .nest("/calendar", calendar::router())
         .nest("/webhooks", webhooks::router())
         .nest("/admin", admin::router())
 }
@@ -0,0 +1,235 @@
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Json, Router,
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::calendar;
+use crate::db;
+use crate::db::writer::{NewsletterFilters, NewsletterRow};
+use crate::directory::{
+    self, Category, Cursor, NewsletterSort, CATEGORIES, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE,
+};
+use crate::error::{AppError, AppResult};
+use crate::models::{NewsletterListing, NewsletterProfile, PublishingFrequency, Writer};
+use crate::responses::CursorPaginatedResponse;
+use crate::state::AppState;
+use crate::validation;
+
+/// Pages can come back short once this is hit; the cursor resumes the scan.
+const MAX_AVAILABILITY_SCAN: usize = 200;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_newsletters))
+        .route("/categories", get(list_categories))
+}
+
+async fn list_categories() -> Json<&'static [Category]> {
+    Json(CATEGORIES)
+}
+
+#[derive(Debug, Deserialize)]
+struct DirectoryQuery {
+    q: Option<String>,
+    currency: Option<String>,
+    min_price_cents: Option<i32>,
+    max_price_cents: Option<i32>,
+    min_subscribers: Option<i32>,
+    max_subscribers: Option<i32>,
+    category: Option<String>,
+    tag: Option<String>,
+    country: Option<String>,
+    job_role: Option<String>,
+    min_open_rate_pct: Option<Decimal>,
+    frequency: Option<PublishingFrequency>,
+    available_within_weeks: Option<i32>,
+    /// Defaults to relevance when searching and audience size otherwise
+    sort: Option<NewsletterSort>,
+    cursor: Option<String>,
+    limit: Option<i64>,
+}
+
+impl DirectoryQuery {
+    fn to_filters(&self) -> AppResult<NewsletterFilters> {
+        let query = directory::search_query(self.q.as_deref())?;
+
+        if let (Some(min), Some(max)) = (self.min_price_cents, self.max_price_cents) {
+            if max < min {
+                return Err(AppError::Validation(
+                    "max_price_cents must not be below min_price_cents".into(),
+                ));
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_subscribers, self.max_subscribers) {
+            if max < min {
+                return Err(AppError::Validation(
+                    "max_subscribers must not be below min_subscribers".into(),
+                ));
+            }
+        }
+
+        let category = match self.category.as_deref().map(str::trim) {
+            Some(slug) if !slug.is_empty() => {
+                Some(validation::validate_categories(&[slug.to_string()])?.remove(0))
+            }
+            _ => None,
+        };
+        let country = match self.country.as_deref().map(str::trim) {
+            Some(code) if !code.is_empty() => {
+                Some(validation::validate_audience_countries(&[code.to_string()])?.remove(0))
+            }
+            _ => None,
+        };
+        let tag = validation::validate_tags(self.tag.as_slice())?.pop();
+        let job_role = validation::validate_job_roles(self.job_role.as_slice())?.pop();
+
+        Ok(NewsletterFilters {
+            query,
+            currency: self
+                .currency
+                .as_deref()
+                .map(str::trim)
+                .filter(|c| !c.is_empty())
+                .map(str::to_string),
+            min_price_cents: self.min_price_cents,
+            max_price_cents: self.max_price_cents,
+            min_subscribers: self.min_subscribers,
+            max_subscribers: self.max_subscribers,
+            category,
+            tag,
+            country,
+            job_role,
+            min_open_rate_pct: self.min_open_rate_pct,
+            frequency: self.frequency,
+        })
+    }
+}
+
+async fn list_newsletters(
+    State(state): State<AppState>,
+    Query(query): Query<DirectoryQuery>,
+) -> AppResult<Json<CursorPaginatedResponse<NewsletterListing>>> {
+    let filters = query.to_filters()?;
+
+    let sort = match (query.sort, &filters.query) {
+        (Some(NewsletterSort::Relevance), None) => {
+            return Err(AppError::Validation(
+                "Sorting by relevance needs a search query".into(),
+            ))
+        }
+        (Some(sort), _) => sort,
+        (None, Some(_)) => NewsletterSort::Relevance,
+        (None, None) => NewsletterSort::Audience,
+    };
+
+    if query
+        .available_within_weeks
+        .is_some_and(|weeks| !(1..=52).contains(&weeks))
+    {
+        return Err(AppError::Validation(
+            "available_within_weeks must be between 1 and 52".into(),
+        ));
+    }
+
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let mut after = query
+        .cursor
+        .as_deref()
+        .map(|c| Cursor::decode(c, sort))
+        .transpose()?;
+
+    let Some(weeks) = query.available_within_weeks else {
+        // One extra row tells whether there is another page
+        let mut rows =
+            db::writer::search_newsletters(&state.db, &filters, sort, after, limit + 1).await?;
+        let next_cursor = if rows.len() as i64 > limit {
+            rows.truncate(limit as usize);
+            rows.last().map(|row| cursor_for(row).encode(sort))
+        } else {
+            None
+        };
+        let listings = rows.into_iter().map(listing).collect();
+        return Ok(Json(CursorPaginatedResponse::new(listings, next_cursor)));
+    };
+
+    let mut listings = Vec::new();
+    let mut scanned = 0;
+
+    loop {
+        let rows = db::writer::search_newsletters(&state.db, &filters, sort, after, limit).await?;
+        let exhausted = (rows.len() as i64) < limit;
+
+        let writers: Vec<Writer> = rows.iter().map(|row| row.writer.clone()).collect();
+        let availability =
+            db::availability::get_availability_for_writers(&state.db, &writers, weeks).await?;
+
+        for row in rows {
+            let has_open_slot = availability
+                .get(&row.writer.id)
+                .is_some_and(|placements| placements.iter().any(|p| !p.available_slots.is_empty()));
+            if !has_open_slot {
+                after = Some(cursor_for(&row));
+                scanned += 1;
+                continue;
+            }
+            if listings.len() as i64 == limit {
+                // Another match exists, so the next page starts with it
+                return Ok(Json(CursorPaginatedResponse::new(
+                    listings,
+                    after.map(|c| c.encode(sort)),
+                )));
+            }
+            after = Some(cursor_for(&row));
+            scanned += 1;
+            listings.push(listing(row));
+        }
+
+        if exhausted {
+            return Ok(Json(CursorPaginatedResponse::new(listings, None)));
+        }
+        if scanned >= MAX_AVAILABILITY_SCAN {
+            return Ok(Json(CursorPaginatedResponse::new(
+                listings,
+                after.map(|c| c.encode(sort)),
+            )));
+        }
+    }
+}
+
+fn cursor_for(row: &NewsletterRow) -> Cursor {
+    Cursor {
+        sort_key: row.sort_key,
+        id: row.writer.id,
+    }
+}
+
+fn listing(row: NewsletterRow) -> NewsletterListing {
+    let paused = row
+        .writer
+        .is_paused_on(calendar::today_in(&row.writer.timezone));
+    let profile = NewsletterProfile::for_writer(&row.writer);
+    let writer = row.writer;
+
+    NewsletterListing {
+        id: writer.id,
+        slug: writer.slug,
+        newsletter_name: writer.newsletter_name,
+        newsletter_url: writer.newsletter_url,
+        description: writer.description,
+        subscriber_count: writer.subscriber_count,
+        price_from_cents: row.price_from_cents,
+        currency: row.price_currency,
+        issue_weekdays: writer.issue_weekdays,
+        issue_week_of_month: writer.issue_week_of_month,
+        timezone: writer.timezone,
+        paused,
+        profile,
+        rating: row.rating,
+    }
+}
@@ -0,0 +1,233 @@
+use axum::{
+    extract::{Path, Query, State},
+    routing::{delete, get, patch, post, put},
+    Json, Router,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::calendar;
+use crate::db;
+use crate::error::{AppError, AppResult};
+use crate::helpers::{get_saved_list_or_404, get_sponsor_for_user_or_404, get_writer_or_404};
+use crate::middlewares::SponsorAuth;
+use crate::models::{
+    CreateSavedList, PlannedNewsletter, PlannedPlacement, SaveListMember, SavedList,
+    SavedListMember, SavedListPlan, UpdateSavedList,
+};
+use crate::responses::SuccessResponse;
+use crate::state::AppState;
+use crate::validation;
+
+const NEXT_AVAILABLE_PER_PLACEMENT: usize = 3;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_saved_lists))
+        .route("/", post(create_saved_list))
+        .route("/{id}", get(get_saved_list_plan))
+        .route("/{id}", patch(update_saved_list))
+        .route("/{id}", delete(delete_saved_list))
+        .route("/{id}/members/{writer_id}", put(save_member))
+        .route("/{id}/members/{writer_id}", delete(remove_member))
+}
+
+fn name_taken() -> AppError {
+    AppError::Conflict("You already have a list with this name".into())
+}
+
+async fn list_saved_lists(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+) -> AppResult<Json<Vec<SavedList>>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    let lists = db::saved_list::get_sponsor_lists(&state.db, sponsor.id).await?;
+    Ok(Json(lists))
+}
+
+async fn create_saved_list(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Json(input): Json<CreateSavedList>,
+) -> AppResult<Json<SavedList>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+
+    let input = CreateSavedList {
+        name: validation::validate_saved_list_name(&input.name)?,
+        description: input
+            .description
+            .as_deref()
+            .map(validation::validate_saved_list_note)
+            .transpose()?
+            .filter(|d| !d.is_empty()),
+    };
+
+    match db::saved_list::create_saved_list(&state.db, sponsor.id, &input).await {
+        Ok(list) => Ok(Json(list)),
+        Err(sqlx::Error::Database(e)) if e.is_unique_violation() => Err(name_taken()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn update_saved_list(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Path(id): Path<Uuid>,
+    Json(input): Json<UpdateSavedList>,
+) -> AppResult<Json<SavedList>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    get_saved_list_or_404(&state.db, id, sponsor.id).await?;
+
+    let input = UpdateSavedList {
+        name: input
+            .name
+            .as_deref()
+            .map(validation::validate_saved_list_name)
+            .transpose()?,
+        description: input
+            .description
+            .as_deref()
+            .map(validation::validate_saved_list_note)
+            .transpose()?,
+    };
+
+    match db::saved_list::update_saved_list(&state.db, id, &input).await {
+        Ok(list) => Ok(Json(list)),
+        Err(sqlx::Error::Database(e)) if e.is_unique_violation() => Err(name_taken()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn delete_saved_list(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<SuccessResponse>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    get_saved_list_or_404(&state.db, id, sponsor.id).await?;
+
+    db::saved_list::delete_saved_list(&state.db, id).await?;
+    Ok(Json(SuccessResponse::new("Saved list deleted")))
+}
+
+async fn save_member(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Path((id, writer_id)): Path<(Uuid, Uuid)>,
+    Json(input): Json<SaveListMember>,
+) -> AppResult<Json<SavedListMember>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    let list = get_saved_list_or_404(&state.db, id, sponsor.id).await?;
+    let writer = get_writer_or_404(&state.db, writer_id).await?;
+
+    let note = input
+        .note
+        .as_deref()
+        .map(validation::validate_saved_list_note)
+        .transpose()?
+        .filter(|n| !n.is_empty());
+
+    // Only new members count against the limit; re-saving one updates its note
+    if list.member_count >= validation::MAX_LIST_MEMBERS
+        && !db::saved_list::is_member(&state.db, list.id, writer.id).await?
+    {
+        return Err(AppError::Validation(format!(
+            "A list can hold at most {} newsletters",
+            validation::MAX_LIST_MEMBERS
+        )));
+    }
+
+    let member =
+        db::saved_list::save_member(&state.db, list.id, writer.id, note.as_deref()).await?;
+    Ok(Json(member))
+}
+
+async fn remove_member(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Path((id, writer_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<Json<SuccessResponse>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    let list = get_saved_list_or_404(&state.db, id, sponsor.id).await?;
+
+    if db::saved_list::remove_member(&state.db, list.id, writer_id).await? {
+        Ok(Json(SuccessResponse::new("Newsletter removed from list")))
+    } else {
+        Err(AppError::NotFound("Newsletter is not on this list".into()))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PlanQuery {
+    #[serde(default = "default_plan_weeks")]
+    weeks: i32,
+}
+
+/// A quarter ahead
+fn default_plan_weeks() -> i32 {
+    13
+}
+
+async fn get_saved_list_plan(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Path(id): Path<Uuid>,
+    Query(query): Query<PlanQuery>,
+) -> AppResult<Json<SavedListPlan>> {
+    if !(1..=52).contains(&query.weeks) {
+        return Err(AppError::Validation(
+            "weeks must be between 1 and 52".into(),
+        ));
+    }
+
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    let list = get_saved_list_or_404(&state.db, id, sponsor.id).await?;
+
+    let rows = db::saved_list::get_list_members(&state.db, list.id).await?;
+    let writers: Vec<_> = rows.iter().map(|row| row.writer.clone()).collect();
+    let mut availability =
+        db::availability::get_availability_for_writers(&state.db, &writers, query.weeks).await?;
+
+    let members = rows
+        .into_iter()
+        .map(|row| {
+            let writer = row.writer;
+            let placements = availability
+                .remove(&writer.id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|a| PlannedPlacement {
+                    placement_id: a.placement.id,
+                    name: a.placement.name,
+                    currency: a.placement.currency,
+                    price_cents: a.placement.price_cents,
+                    next_available: a
+                        .available_slots
+                        .into_iter()
+                        .take(NEXT_AVAILABLE_PER_PLACEMENT)
+                        .collect(),
+                })
+                .collect();
+
+            PlannedNewsletter {
+                paused: writer.is_paused_on(calendar::today_in(&writer.timezone)),
+                writer_id: writer.id,
+                slug: writer.slug,
+                newsletter_name: writer.newsletter_name,
+                newsletter_url: writer.newsletter_url,
+                subscriber_count: writer.subscriber_count,
+                timezone: writer.timezone,
+                rating: row.rating,
+                note: row.note,
+                added_at: row.added_at,
+                placements,
+            }
+        })
+        .collect();
+
+    Ok(Json(SavedListPlan {
+        list,
+        weeks_ahead: query.weeks,
+        members,
+    }))
+}
@@ -11,6 +11,7 @@ use crate::helpers::{get_sponsor_for_user_or_404, get_sponsor_or_404, require_sp
 use crate::middlewares::{Auth, SponsorAuth};
 use crate::models::{BookingWithDetails, CreateSponsor, Sponsor, UpdateSponsor, UserRole};
 use crate::state::AppState;
+use crate::validation;
 
 pub fn router() -> Router<AppState> {
     Router::new()
@@ -24,7 +25,7 @@ pub fn router() -> Router<AppState> {
 async fn create_sponsor(
     State(state): State<AppState>,
     Auth(user): Auth,
-    Json(input): Json<CreateSponsor>,
+    Json(mut input): Json<CreateSponsor>,
 ) -> AppResult<Json<Sponsor>> {
     if user.role != UserRole::Sponsor && user.role != UserRole::Admin {
         return Err(AppError::Forbidden);
@@ -38,6 +39,8 @@ async fn create_sponsor(
         return Err(AppError::Validation("Company name is required".into()));
     }
 
+    input.timezone = validation::validate_timezone(&input.timezone)?;
+
     let sponsor = db::sponsor::create_sponsor(&state.db, user.id, &input).await?;
 
     Ok(Json(sponsor))
@@ -63,7 +66,7 @@ async fn update_sponsor(
     State(state): State<AppState>,
     SponsorAuth(user): SponsorAuth,
     Path(id): Path<Uuid>,
-    Json(input): Json<UpdateSponsor>,
+    Json(mut input): Json<UpdateSponsor>,
 ) -> AppResult<Json<Sponsor>> {
     let sponsor = get_sponsor_or_404(&state.db, id).await?;
     require_sponsor_ownership(&sponsor, user.id, user.is_admin())?;
@@ -74,6 +77,10 @@ async fn update_sponsor(
         }
     }
 
+    if let Some(timezone) = &input.timezone {
+        input.timezone = Some(validation::validate_timezone(timezone)?);
+    }
+
     let updated = db::sponsor::update_sponsor(&state.db, id, &input).await?;
 
     Ok(Json(updated))
@@ -87,7 +94,7 @@ async fn list_bookings(
     let sponsor = get_sponsor_or_404(&state.db, id).await?;
     require_sponsor_ownership(&sponsor, user.id, user.is_admin())?;
 
-    let bookings = db::sponsor::get_sponsor_bookings(&state.db, id).await?;
+    let bookings = db::sponsor::get_sponsor_bookings(&state.db, id, user.id).await?;
 
     Ok(Json(bookings))
 }
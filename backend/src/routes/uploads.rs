@@ -6,14 +6,16 @@ use axum::{
 use serde::{Deserialize, Serialize};
 
 use crate::error::{AppError, AppResult};
-use crate::middlewares::Auth;
-use crate::services::{storage::SignedUploadParams, ImageTransformations};
+use crate::middlewares::{Auth, WriterAuth};
+use crate::services::storage::{SignedUploadParams, UploadResult};
+use crate::services::ImageTransformations;
 use crate::state::AppState;
 
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/ad-image", post(upload_ad_image))
         .route("/sponsor-logo", post(upload_sponsor_logo))
+        .route("/publication-proof", post(upload_publication_proof))
         .route("/signed-params", get(get_signed_upload_params))
         .route("/image/{public_id}", delete(delete_image))
         .route("/transform", get(get_transformed_url))
@@ -29,48 +31,71 @@ struct UploadResponse {
     bytes: u64,
 }
 
-async fn upload_ad_image(
-    State(state): State<AppState>,
-    Auth(_user): Auth,
-    mut multipart: Multipart,
-) -> AppResult<Json<UploadResponse>> {
-    let storage = state.require_storage()?;
-
-    while let Some(field) = multipart
-        .next_field()
-        .await
-        .map_err(|e| AppError::BadRequest(format!("Failed to read multipart: {}", e)))?
-    {
-        let filename = field
-            .file_name()
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| "image".to_string());
-
-        let content_type = field
-            .content_type()
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| "image/jpeg".to_string());
-
-        let data = field
-            .bytes()
-            .await
-            .map_err(|e| AppError::BadRequest(format!("Failed to read file: {}", e)))?;
-
-        let result = storage
-            .upload_ad_image(&filename, &content_type, data.to_vec())
-            .await?;
-
-        return Ok(Json(UploadResponse {
+impl From<UploadResult> for UploadResponse {
+    fn from(result: UploadResult) -> Self {
+        Self {
             url: result.url,
             public_id: result.public_id,
             width: result.width,
             height: result.height,
             format: result.format,
             bytes: result.bytes,
-        }));
+        }
     }
+}
 
-    Err(AppError::BadRequest("No file provided".into()))
+struct UploadedFile {
+    filename: String,
+    content_type: String,
+    data: Vec<u8>,
+}
+
+async fn read_upload(
+    multipart: &mut Multipart,
+    default_filename: &str,
+    default_content_type: &str,
+) -> AppResult<UploadedFile> {
+    let field = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::BadRequest(format!("Failed to read multipart: {}", e)))?
+        .ok_or_else(|| AppError::BadRequest("No file provided".into()))?;
+
+    let filename = field
+        .file_name()
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| default_filename.to_string());
+
+    let content_type = field
+        .content_type()
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| default_content_type.to_string());
+
+    let data = field
+        .bytes()
+        .await
+        .map_err(|e| AppError::BadRequest(format!("Failed to read file: {}", e)))?;
+
+    Ok(UploadedFile {
+        filename,
+        content_type,
+        data: data.to_vec(),
+    })
+}
+
+async fn upload_ad_image(
+    State(state): State<AppState>,
+    Auth(_user): Auth,
+    mut multipart: Multipart,
+) -> AppResult<Json<UploadResponse>> {
+    let storage = state.require_storage()?;
+    let file = read_upload(&mut multipart, "image", "image/jpeg").await?;
+
+    let result = storage
+        .upload_ad_image(&file.filename, &file.content_type, file.data)
+        .await?;
+
+    Ok(Json(result.into()))
 }
 
 async fn upload_sponsor_logo(
@@ -79,42 +104,28 @@ async fn upload_sponsor_logo(
     mut multipart: Multipart,
 ) -> AppResult<Json<UploadResponse>> {
     let storage = state.require_storage()?;
+    let file = read_upload(&mut multipart, "logo", "image/png").await?;
 
-    while let Some(field) = multipart
-        .next_field()
-        .await
-        .map_err(|e| AppError::BadRequest(format!("Failed to read multipart: {}", e)))?
-    {
-        let filename = field
-            .file_name()
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| "logo".to_string());
-
-        let content_type = field
-            .content_type()
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| "image/png".to_string());
-
-        let data = field
-            .bytes()
-            .await
-            .map_err(|e| AppError::BadRequest(format!("Failed to read file: {}", e)))?;
-
-        let result = storage
-            .upload_sponsor_logo(&filename, &content_type, data.to_vec())
-            .await?;
-
-        return Ok(Json(UploadResponse {
-            url: result.url,
-            public_id: result.public_id,
-            width: result.width,
-            height: result.height,
-            format: result.format,
-            bytes: result.bytes,
-        }));
-    }
+    let result = storage
+        .upload_sponsor_logo(&file.filename, &file.content_type, file.data)
+        .await?;
+
+    Ok(Json(result.into()))
+}
+
+async fn upload_publication_proof(
+    State(state): State<AppState>,
+    WriterAuth(_user): WriterAuth,
+    mut multipart: Multipart,
+) -> AppResult<Json<UploadResponse>> {
+    let storage = state.require_storage()?;
+    let file = read_upload(&mut multipart, "proof", "image/png").await?;
+
+    let result = storage
+        .upload_publication_proof(&file.filename, &file.content_type, file.data)
+        .await?;
 
-    Err(AppError::BadRequest("No file provided".into()))
+    Ok(Json(result.into()))
 }
 
 async fn get_signed_upload_params(
@@ -0,0 +1,144 @@
+use axum::{
+    extract::{Path, State},
+    routing::{delete, get, post},
+    Json, Router,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::db;
+use crate::error::{AppError, AppResult};
+use crate::helpers::{
+    get_offered_placement, get_placement_or_404, get_sponsor_for_user_or_404,
+    get_waitlist_offer_or_404, get_writer_or_404, require_issue_date, require_lead_time,
+    require_not_paused,
+};
+use crate::middlewares::SponsorAuth;
+use crate::models::{JoinWaitlist, WaitlistEntryWithDetails};
+use crate::responses::SuccessResponse;
+use crate::state::AppState;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_waitlist))
+        .route("/", post(join_waitlist))
+        .route("/{id}", delete(leave_waitlist))
+        .route("/offers/{token}", get(get_offer))
+}
+
+async fn list_waitlist(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+) -> AppResult<Json<Vec<WaitlistEntryWithDetails>>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    let entries = db::waitlist::get_sponsor_entries(&state.db, sponsor.id).await?;
+    Ok(Json(entries))
+}
+
+/// Dates that still have room have to be booked directly.
+async fn join_waitlist(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Json(input): Json<JoinWaitlist>,
+) -> AppResult<Json<WaitlistEntryWithDetails>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    if sponsor.billing_email.is_none() {
+        return Err(AppError::BadRequest(
+            "Billing email is required to join a waitlist. Please update your sponsor profile."
+                .into(),
+        ));
+    }
+
+    let writer = get_writer_or_404(&state.db, input.writer_id).await?;
+    let placement = get_offered_placement(&state.db, &writer, input.placement_id).await?;
+
+    require_lead_time(&writer, input.slot_date)?;
+    require_issue_date(&writer, input.slot_date)?;
+    require_not_paused(&writer, input.slot_date)?;
+
+    if db::availability::is_slot_available(&state.db, &writer, &placement, input.slot_date).await? {
+        return Err(AppError::BadRequest(
+            "This slot is still available and can be booked directly".into(),
+        ));
+    }
+
+    if db::waitlist::is_on_waitlist(&state.db, sponsor.id, placement.id, input.slot_date).await? {
+        return Err(AppError::Conflict(
+            "You are already on the waitlist for this slot".into(),
+        ));
+    }
+
+    let id = db::waitlist::join_waitlist(
+        &state.db,
+        sponsor.id,
+        writer.id,
+        placement.id,
+        input.slot_date,
+    )
+    .await?;
+
+    let entry = db::waitlist::get_sponsor_entries(&state.db, sponsor.id)
+        .await?
+        .into_iter()
+        .find(|e| e.id == id)
+        .ok_or_else(|| AppError::Internal("Waitlist entry missing after insert".into()))?;
+
+    Ok(Json(entry))
+}
+
+async fn leave_waitlist(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<SuccessResponse>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+
+    if !db::waitlist::leave_waitlist(&state.db, sponsor.id, id).await? {
+        return Err(AppError::NotFound("Waitlist entry not found".into()));
+    }
+
+    Ok(Json(SuccessResponse::new("Removed from waitlist")))
+}
+
+#[derive(Debug, Serialize)]
+struct WaitlistOffer {
+    waitlist_entry_id: Uuid,
+    writer_id: Uuid,
+    newsletter_name: String,
+    placement_id: Uuid,
+    placement_name: String,
+    slot_date: NaiveDate,
+    price_cents: i32,
+    currency: String,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+/// The sponsor books it by passing the same token as `waitlist_offer`.
+async fn get_offer(
+    State(state): State<AppState>,
+    SponsorAuth(user): SponsorAuth,
+    Path(token): Path<String>,
+) -> AppResult<Json<WaitlistOffer>> {
+    let sponsor = get_sponsor_for_user_or_404(&state.db, user.id).await?;
+    let token_hash = state.auth.hash_token(&token);
+    let entry = get_waitlist_offer_or_404(&state.db, &token_hash, sponsor.id).await?;
+
+    let writer = get_writer_or_404(&state.db, entry.writer_id).await?;
+    let placement = get_placement_or_404(&state.db, entry.placement_id).await?;
+    let price_cents =
+        db::availability::get_slot_prices(&state.db, &writer, &placement, &[entry.slot_date])
+            .await?[0];
+
+    Ok(Json(WaitlistOffer {
+        waitlist_entry_id: entry.id,
+        writer_id: writer.id,
+        newsletter_name: writer.newsletter_name,
+        placement_id: placement.id,
+        placement_name: placement.name,
+        slot_date: entry.slot_date,
+        price_cents,
+        currency: placement.currency,
+        expires_at: entry.offer_expires_at,
+    }))
+}
@@ -7,9 +7,11 @@ use axum::{
 };
 use uuid::Uuid;
 
+use crate::calendar;
 use crate::db;
-use crate::models::BookingStatus;
-use crate::services::{BookingConfirmationData, NewBookingNotificationData};
+use crate::lifecycle::{self, Actor};
+use crate::models::{Booking, BookingStatus};
+use crate::services::{BookingCancelledData, BookingConfirmationData, NewBookingNotificationData};
 use crate::state::AppState;
 
 pub fn router() -> Router<AppState> {
@@ -87,26 +89,53 @@ async fn handle_order_created(
     state: &AppState,
     event: &crate::services::WebhookEvent,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let booking_id_str = event
-        .get_custom_data("booking_id")
-        .ok_or("Missing booking_id in custom data")?;
+    let bookings = match event.get_custom_data("bundle_id") {
+        Some(bundle_id) => {
+            db::sponsor::get_bundle_bookings(&state.db, Uuid::parse_str(bundle_id)?).await?
+        }
+        None => {
+            let booking_id_str = event
+                .get_custom_data("booking_id")
+                .ok_or("Missing booking_id in custom data")?;
+            let booking_id = Uuid::parse_str(booking_id_str)?;
+
+            db::sponsor::get_booking_by_id(&state.db, booking_id)
+                .await?
+                .into_iter()
+                .collect()
+        }
+    };
 
-    let booking_id = Uuid::parse_str(booking_id_str)?;
+    if bookings.is_empty() {
+        return Err("Booking not found".into());
+    }
 
-    let booking = db::sponsor::get_booking_by_id(&state.db, booking_id)
-        .await?
-        .ok_or("Booking not found")?;
+    let order_id = &event.data.id;
 
-    if booking.status != BookingStatus::PendingPayment {
-        tracing::info!(
-            "Booking {} already processed, status: {:?}",
-            booking_id,
-            booking.status
-        );
-        return Ok(());
+    let mut lapsed = Vec::new();
+    for booking in &bookings {
+        if booking.status == BookingStatus::Cancelled
+            && booking.refunded_cents == 0
+            && is_lapsed_checkout(state, booking.id).await?
+        {
+            lapsed.push(booking.id);
+        }
     }
 
-    let order_id = &event.data.id;
+    if lapsed.is_empty()
+        && bookings
+            .iter()
+            .all(|b| b.status == BookingStatus::Cancelled)
+    {
+        // Cancelled by the sponsor before the payment arrived
+        tracing::warn!(
+            "Payment received for cancelled booking {}, refunding order {}",
+            bookings[0].id,
+            order_id
+        );
+        let ids: Vec<Uuid> = bookings.iter().map(|b| b.id).collect();
+        return refund_cancelled_before_payment(state, &ids, order_id).await;
+    }
 
     // Extract order attributes for logging and verification
     if let Some(order_attrs) = event.get_order_attributes() {
@@ -120,24 +149,149 @@ async fn handle_order_created(
         );
 
         // Verify the order amount matches the booking
-        if order_attrs.total != booking.amount_cents as i64 {
+        let expected: i64 = bookings.iter().map(|b| b.amount_cents as i64).sum();
+        if order_attrs.total != expected {
             tracing::warn!(
                 "Order amount mismatch: expected {} but got {}",
-                booking.amount_cents,
+                expected,
                 order_attrs.total
             );
         }
     }
 
+    for booking in &bookings {
+        match booking.status {
+            BookingStatus::PendingPayment => mark_booking_paid(state, booking, order_id).await?,
+            // Expired by the reaper before the payment arrived; it still counts if
+            // the slot is free
+            BookingStatus::Cancelled if lapsed.contains(&booking.id) => {
+                mark_booking_paid(state, booking, order_id).await?
+            }
+            BookingStatus::Cancelled if booking.refunded_cents == 0 => {
+                // One date of a bundle was cancelled before the bundle was paid for
+                tracing::warn!(
+                    "Payment received for cancelled booking {}, refunding its share of order {}",
+                    booking.id,
+                    order_id
+                );
+                refund_cancelled_before_payment(state, &[booking.id], order_id).await?;
+            }
+            _ => {
+                tracing::info!(
+                    "Booking {} already processed, status: {:?}",
+                    booking.id,
+                    booking.status
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Records the refund before issuing it, so a redelivered webhook leaves the bookings alone.
+async fn refund_cancelled_before_payment(
+    state: &AppState,
+    booking_ids: &[Uuid],
+    order_id: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let mut tx = state.db.begin().await?;
+    let locked = db::sponsor::lock_bookings_tx(&mut tx, booking_ids).await?;
+
+    let unrefunded: Vec<&Booking> = locked
+        .iter()
+        .filter(|b| b.status == BookingStatus::Cancelled && b.refunded_cents == 0)
+        .collect();
+    if unrefunded.is_empty() {
+        tracing::info!("Cancelled bookings of order {} already refunded", order_id);
+        return Ok(());
+    }
+
+    for booking in &unrefunded {
+        sqlx::query!(
+            "UPDATE bookings SET lemon_order_id = $1 WHERE id = $2",
+            order_id,
+            booking.id
+        )
+        .execute(&mut *tx)
+        .await?;
+        db::sponsor::record_booking_refund(&mut tx, booking.id, booking.amount_cents, 0, 0).await?;
+    }
+
+    let payments = state.require_payments()?;
+    if unrefunded.len() == locked.len() && locked.len() == booking_ids.len() {
+        payments.refund_order(order_id).await?;
+    } else {
+        let amount: i64 = unrefunded.iter().map(|b| b.amount_cents as i64).sum();
+        payments.refund_order_amount(order_id, amount).await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+async fn is_lapsed_checkout(state: &AppState, booking_id: Uuid) -> Result<bool, sqlx::Error> {
+    let mut tx = state.db.begin().await?;
+    let lapsed = db::booking_event::was_cancelled_unpaid(&mut tx, booking_id).await?;
+    tx.commit().await?;
+    Ok(lapsed)
+}
+
+async fn mark_booking_paid(
+    state: &AppState,
+    booking: &Booking,
+    order_id: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let booking_id = booking.id;
+
+    let mut tx = state.db.begin().await?;
+
     sqlx::query!(
         "UPDATE bookings SET lemon_order_id = $1 WHERE id = $2",
         order_id,
         booking_id
     )
-    .execute(&state.db)
+    .execute(&mut *tx)
     .await?;
 
-    db::sponsor::update_booking_status(&state.db, booking_id, BookingStatus::Paid).await?;
+    // A payment can arrive after the hold was released, or even after the reaper
+    // cancelled the booking; it still counts if nobody has taken the slot since
+    let has_hold = booking.status == BookingStatus::PendingPayment
+        && db::slot_hold::has_active_hold(&mut tx, booking_id).await?;
+    if !has_hold {
+        let (_, remaining) = db::sponsor::lock_slots_remaining_tx(
+            &mut tx,
+            booking.writer_id,
+            booking.placement_id,
+            booking.slot_date,
+        )
+        .await?;
+        if remaining <= 0 {
+            release_lapsed_booking(state, tx, booking, order_id).await?;
+            return Ok(());
+        }
+    }
+
+    if booking.status == BookingStatus::Cancelled {
+        lifecycle::reinstate_lapsed_tx(
+            &mut tx,
+            booking_id,
+            Actor::payment_provider(),
+            Some("Payment arrived after the checkout expired and the slot was still free"),
+        )
+        .await?;
+    } else {
+        lifecycle::transition_tx(
+            &mut tx,
+            booking_id,
+            BookingStatus::Paid,
+            Actor::payment_provider(),
+            Some("order_created"),
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
 
     tracing::info!(
         "Booking {} marked as paid (order: {})",
@@ -150,8 +304,14 @@ async fn handle_order_created(
 
     let auto_approved = if let Some(ref writer) = writer {
         if writer.auto_approve {
-            db::sponsor::update_booking_status(&state.db, booking_id, BookingStatus::Approved)
-                .await?;
+            lifecycle::transition(
+                &state.db,
+                booking_id,
+                BookingStatus::Approved,
+                Actor::system(),
+                Some("Auto-approved by writer settings"),
+            )
+            .await?;
             tracing::info!("Booking {} auto-approved", booking_id);
             true
         } else {
@@ -169,7 +329,11 @@ async fn handle_order_created(
                 if let Some(ref writer) = writer {
                     let email_data = BookingConfirmationData {
                         newsletter_name: writer.newsletter_name.clone(),
-                        slot_date: booking.slot_date.to_string(),
+                        slot_date: calendar::format_writer_date(
+                            booking.slot_date,
+                            &writer.timezone,
+                            &sponsor.timezone,
+                        ),
                         amount_cents: booking.amount_cents,
                         currency: booking.currency.clone(),
                         ad_headline: booking.ad_headline.clone(),
@@ -195,7 +359,11 @@ async fn handle_order_created(
                         let email_data = NewBookingNotificationData {
                             sponsor_name: sponsor.company_name.clone(),
                             company_website: sponsor.website_url.clone(),
-                            slot_date: booking.slot_date.to_string(),
+                            slot_date: calendar::format_writer_date(
+                                booking.slot_date,
+                                &writer.timezone,
+                                &writer.timezone,
+                            ),
                             writer_payout_cents: booking.writer_payout_cents,
                             ad_headline: booking.ad_headline.clone(),
                             ad_body: booking.ad_body.clone(),
@@ -218,6 +386,76 @@ async fn handle_order_created(
     Ok(())
 }
 
+async fn release_lapsed_booking(
+    state: &AppState,
+    mut tx: sqlx::Transaction<'_, sqlx::Postgres>,
+    booking: &Booking,
+    order_id: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let reason = "Slot hold expired and the slot was taken before payment arrived";
+
+    // Already cancelled if the reaper got to it first
+    let cancelled = if booking.status == BookingStatus::Cancelled {
+        Booking {
+            lemon_order_id: Some(order_id.to_string()),
+            ..booking.clone()
+        }
+    } else {
+        lifecycle::transition_tx(
+            &mut tx,
+            booking.id,
+            BookingStatus::Cancelled,
+            Actor::payment_provider(),
+            Some(reason),
+        )
+        .await?
+    };
+
+    db::sponsor::record_booking_refund(&mut tx, booking.id, booking.amount_cents, 0, 0).await?;
+    lifecycle::refund_payment(state, &cancelled, booking.amount_cents).await?;
+
+    tx.commit().await?;
+
+    tracing::warn!(
+        "Booking {} paid after its hold expired and the slot was taken, refunded",
+        booking.id
+    );
+
+    if let Some(email_service) = &state.email {
+        let writer = db::writer::get_writer_by_id(&state.db, booking.writer_id).await?;
+        let sponsor = db::sponsor::get_sponsor_by_id(&state.db, booking.sponsor_id).await?;
+
+        if let (Some(writer), Some(sponsor)) = (writer, sponsor) {
+            if let Some(ref billing_email) = sponsor.billing_email {
+                let email_data = BookingCancelledData {
+                    newsletter_name: writer.newsletter_name.clone(),
+                    sponsor_name: sponsor.company_name.clone(),
+                    slot_date: calendar::format_writer_date(
+                        booking.slot_date,
+                        &writer.timezone,
+                        &sponsor.timezone,
+                    ),
+                    refund_cents: booking.amount_cents,
+                    currency: booking.currency.clone(),
+                    reason: Some(reason.to_string()),
+                    dashboard_url: format!(
+                        "{}/dashboard/bookings",
+                        state.config.server.frontend_url
+                    ),
+                };
+                if let Err(e) = email_service
+                    .send_booking_cancelled(billing_email, email_data)
+                    .await
+                {
+                    tracing::warn!("Failed to send cancellation email: {}", e);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 async fn handle_order_refunded(
     state: &AppState,
     event: &crate::services::WebhookEvent,
@@ -235,61 +473,164 @@ async fn handle_order_refunded(
         );
     }
 
-    let booking = db::sponsor::get_booking_by_lemon_order(&state.db, order_id)
-        .await?
-        .ok_or_else(|| format!("Booking not found for order {}", order_id))?;
+    let bookings = db::sponsor::get_bookings_by_lemon_order(&state.db, order_id).await?;
+    if bookings.is_empty() {
+        return Err(format!("Booking not found for order {}", order_id).into());
+    }
+
+    // Refunds of single dates in a bundle are recorded when they are issued; only
+    // a full refund of the order closes every booking paid for by it
+    let fully_refunded = event
+        .get_order_attributes()
+        .map(|attrs| attrs.refunded)
+        .unwrap_or(true);
+    if bookings.len() > 1 && !fully_refunded {
+        let refunded_amount = event
+            .get_order_attributes()
+            .map(|attrs| attrs.refunded_amount)
+            .unwrap_or(0);
+        return apply_partial_bundle_refund(state, &bookings, order_id, refunded_amount).await;
+    }
+
+    for booking in &bookings {
+        mark_booking_refunded(state, booking).await?;
+    }
+
+    Ok(())
+}
+
+/// For refunds issued outside the platform; the latest dates are refunded first.
+async fn apply_partial_bundle_refund(
+    state: &AppState,
+    bookings: &[Booking],
+    order_id: &str,
+    refunded_amount: i64,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let mut tx = state.db.begin().await?;
+    let ids: Vec<Uuid> = bookings.iter().map(|b| b.id).collect();
+    let mut locked = db::sponsor::lock_bookings_tx(&mut tx, &ids).await?;
+
+    let recorded: i64 = locked.iter().map(|b| b.refunded_cents as i64).sum();
+    let mut unrecorded = refunded_amount - recorded;
+    if unrecorded <= 0 {
+        tracing::info!("Order {} partially refunded, already recorded", order_id);
+        return Ok(());
+    }
+
+    locked.sort_by_key(|b| std::cmp::Reverse(b.slot_date));
 
+    let mut refunded = Vec::new();
+    for booking in &locked {
+        let live = matches!(
+            booking.status,
+            BookingStatus::Paid | BookingStatus::ChangesRequested | BookingStatus::Approved
+        );
+        if !live || booking.amount_cents as i64 > unrecorded {
+            continue;
+        }
+
+        let booking = lifecycle::transition_tx(
+            &mut tx,
+            booking.id,
+            BookingStatus::Refunded,
+            Actor::payment_provider(),
+            Some("order_partially_refunded"),
+        )
+        .await?;
+        db::sponsor::record_booking_refund(&mut tx, booking.id, booking.amount_cents, 0, 0).await?;
+        unrecorded -= booking.amount_cents as i64;
+        refunded.push(booking);
+    }
+
+    tx.commit().await?;
+
+    if unrecorded > 0 {
+        tracing::warn!(
+            "{} of the partial refund on order {} does not match a booking, review it manually",
+            unrecorded,
+            order_id
+        );
+    }
+
+    for booking in &refunded {
+        tracing::info!("Booking {} marked as refunded", booking.id);
+        notify_booking_refunded(state, booking).await?;
+    }
+
+    Ok(())
+}
+
+async fn mark_booking_refunded(
+    state: &AppState,
+    booking: &Booking,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     match booking.status {
-        BookingStatus::Paid | BookingStatus::Approved => {
-            db::sponsor::update_booking_status(&state.db, booking.id, BookingStatus::Refunded)
-                .await?;
+        BookingStatus::Paid | BookingStatus::ChangesRequested | BookingStatus::Approved => {
+            lifecycle::transition(
+                &state.db,
+                booking.id,
+                BookingStatus::Refunded,
+                Actor::payment_provider(),
+                Some("order_refunded"),
+            )
+            .await?;
             tracing::info!("Booking {} marked as refunded", booking.id);
+            notify_booking_refunded(state, booking).await?;
+        }
+        _ => {
+            tracing::info!(
+                "Booking {} not in refundable state: {:?}",
+                booking.id,
+                booking.status
+            );
+        }
+    }
 
-            if let Some(email_service) = &state.email {
-                let writer = db::writer::get_writer_by_id(&state.db, booking.writer_id).await?;
-                let sponsor = db::sponsor::get_sponsor_by_id(&state.db, booking.sponsor_id).await?;
-
-                if let Some(ref sponsor) = sponsor {
-                    if let Some(ref billing_email) = sponsor.billing_email {
-                        if let Some(ref writer) = writer {
-                            let email_data = crate::services::BookingRejectedData {
-                                newsletter_name: writer.newsletter_name.clone(),
-                                slot_date: booking.slot_date.to_string(),
-                                amount_cents: booking.amount_cents,
-                                currency: booking.currency.clone(),
-                                reason: Some("Order was refunded".to_string()),
-                            };
-                            if let Err(e) = email_service
-                                .send_booking_rejected(billing_email, email_data)
-                                .await
-                            {
-                                tracing::warn!("Failed to send refund notification email: {}", e);
-                            }
-                        }
-                    }
-                }
+    Ok(())
+}
 
+async fn notify_booking_refunded(
+    state: &AppState,
+    booking: &Booking,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if let Some(email_service) = &state.email {
+        let writer = db::writer::get_writer_by_id(&state.db, booking.writer_id).await?;
+        let sponsor = db::sponsor::get_sponsor_by_id(&state.db, booking.sponsor_id).await?;
+
+        if let Some(ref sponsor) = sponsor {
+            if let Some(ref billing_email) = sponsor.billing_email {
                 if let Some(ref writer) = writer {
-                    if let Ok(Some(user)) =
-                        db::user::get_user_by_id(&state.db, writer.user_id).await
+                    let email_data = crate::services::BookingRejectedData {
+                        newsletter_name: writer.newsletter_name.clone(),
+                        slot_date: calendar::format_writer_date(
+                            booking.slot_date,
+                            &writer.timezone,
+                            &sponsor.timezone,
+                        ),
+                        amount_cents: booking.amount_cents,
+                        currency: booking.currency.clone(),
+                        reason: Some("Order was refunded".to_string()),
+                    };
+                    if let Err(e) = email_service
+                        .send_booking_rejected(billing_email, email_data)
+                        .await
                     {
-                        if let Some(ref _sponsor) = sponsor {
-                            tracing::info!(
-                                "Booking {} cancelled/refunded - writer {} notified",
-                                booking.id,
-                                user.email
-                            );
-                        }
+                        tracing::warn!("Failed to send refund notification email: {}", e);
                     }
                 }
             }
         }
-        _ => {
-            tracing::info!(
-                "Booking {} not in refundable state: {:?}",
-                booking.id,
-                booking.status
-            );
+
+        if let Some(ref writer) = writer {
+            if let Ok(Some(user)) = db::user::get_user_by_id(&state.db, writer.user_id).await {
+                if let Some(ref _sponsor) = sponsor {
+                    tracing::info!(
+                        "Booking {} cancelled/refunded - writer {} notified",
+                        booking.id,
+                        user.email
+                    );
+                }
+            }
         }
     }
 
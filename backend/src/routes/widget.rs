@@ -7,10 +7,11 @@ use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::calendar;
 use crate::db;
-use crate::error::AppResult;
-use crate::helpers::get_writer_or_404;
-use crate::models::AvailableSlot;
+use crate::error::{AppError, AppResult};
+use crate::helpers::{get_offered_placement, get_writer_or_404};
+use crate::models::{AdPlacement, AvailableSlot, NewsletterProfile, RatingSummary};
 use crate::state::AppState;
 
 pub fn router() -> Router<AppState> {
@@ -23,6 +24,7 @@ pub fn router() -> Router<AppState> {
 #[derive(Debug, Serialize)]
 struct WidgetWriterInfo {
     id: Uuid,
+    slug: Option<String>,
     newsletter_name: String,
     newsletter_url: Option<String>,
     description: Option<String>,
@@ -31,6 +33,17 @@ struct WidgetWriterInfo {
     currency: String,
     lead_time_days: i32,
     slots_per_week: i32,
+    issue_weekdays: Vec<i16>,
+    issue_week_of_month: i16,
+    slots_per_issue: i32,
+    timezone: String,
+    paused: bool,
+    paused_until: Option<NaiveDate>,
+    #[serde(flatten)]
+    profile: NewsletterProfile,
+    #[serde(flatten)]
+    rating: RatingSummary,
+    placements: Vec<AdPlacement>,
 }
 
 async fn get_writer_info(
@@ -38,9 +51,14 @@ async fn get_writer_info(
     Path(id): Path<Uuid>,
 ) -> AppResult<Json<WidgetWriterInfo>> {
     let writer = get_writer_or_404(&state.db, id).await?;
+    let placements = db::ad_placement::get_writer_placements(&state.db, writer.id, false).await?;
+    let paused = writer.is_paused_on(calendar::today_in(&writer.timezone));
+    let profile = NewsletterProfile::for_writer(&writer);
+    let rating = db::review::get_rating_summary(&state.db, writer.id).await?;
 
     Ok(Json(WidgetWriterInfo {
         id: writer.id,
+        slug: writer.slug,
         newsletter_name: writer.newsletter_name,
         newsletter_url: writer.newsletter_url,
         description: writer.description,
@@ -49,6 +67,15 @@ async fn get_writer_info(
         currency: writer.currency,
         lead_time_days: writer.lead_time_days,
         slots_per_week: writer.slots_per_week,
+        issue_weekdays: writer.issue_weekdays,
+        issue_week_of_month: writer.issue_week_of_month,
+        slots_per_issue: writer.slots_per_issue,
+        timezone: writer.timezone,
+        paused,
+        paused_until: writer.pause_ends_on.filter(|_| paused),
+        profile,
+        rating,
+        placements,
     }))
 }
 
@@ -56,6 +83,7 @@ async fn get_writer_info(
 struct AvailabilityQuery {
     #[serde(default = "default_weeks")]
     weeks: i32,
+    placement_id: Option<Uuid>,
 }
 
 fn default_weeks() -> i32 {
@@ -66,6 +94,8 @@ fn default_weeks() -> i32 {
 struct WidgetAvailability {
     writer_id: Uuid,
     newsletter_name: String,
+    placement_id: Uuid,
+    placement_name: String,
     price_per_slot: i32,
     currency: String,
     lead_time_days: i32,
@@ -77,25 +107,41 @@ async fn writer_availability(
     Path(id): Path<Uuid>,
     Query(query): Query<AvailabilityQuery>,
 ) -> AppResult<Json<WidgetAvailability>> {
+    if !(1..=52).contains(&query.weeks) {
+        return Err(AppError::Validation(
+            "weeks must be between 1 and 52".into(),
+        ));
+    }
+
     let writer = get_writer_or_404(&state.db, id).await?;
+    let placement = get_offered_placement(&state.db, &writer, query.placement_id).await?;
 
     let available_slots =
-        db::availability::get_writer_availability(&state.db, id, query.weeks).await?;
+        db::availability::get_writer_availability(&state.db, &writer, &placement, query.weeks)
+            .await?;
 
     Ok(Json(WidgetAvailability {
         writer_id: writer.id,
         newsletter_name: writer.newsletter_name,
-        price_per_slot: writer.price_per_slot,
-        currency: writer.currency,
+        placement_id: placement.id,
+        placement_name: placement.name,
+        price_per_slot: placement.price_cents,
+        currency: placement.currency,
         lead_time_days: writer.lead_time_days,
         available_slots,
     }))
 }
 
+#[derive(Debug, Deserialize)]
+struct SlotCheckQuery {
+    placement_id: Option<Uuid>,
+}
+
 #[derive(Debug, Serialize)]
 struct SlotCheckResponse {
     available: bool,
     slot_date: NaiveDate,
+    placement_id: Uuid,
     slots_remaining: i32,
     price_cents: i32,
     currency: String,
@@ -104,35 +150,36 @@ struct SlotCheckResponse {
 async fn check_slot(
     State(state): State<AppState>,
     Path((id, date)): Path<(Uuid, NaiveDate)>,
+    Query(query): Query<SlotCheckQuery>,
 ) -> AppResult<Json<SlotCheckResponse>> {
     let writer = get_writer_or_404(&state.db, id).await?;
+    let placement = get_offered_placement(&state.db, &writer, query.placement_id).await?;
+
+    let price_cents =
+        db::availability::get_slot_prices(&state.db, &writer, &placement, &[date]).await?[0];
 
-    let today = chrono::Utc::now().date_naive();
+    let today = calendar::today_in(&writer.timezone);
     let min_date = today + chrono::Duration::days(writer.lead_time_days as i64);
     if date < min_date {
         return Ok(Json(SlotCheckResponse {
             available: false,
             slot_date: date,
+            placement_id: placement.id,
             slots_remaining: 0,
-            price_cents: writer.price_per_slot,
-            currency: writer.currency,
+            price_cents,
+            currency: placement.currency,
         }));
     }
 
-    let is_available = db::availability::is_slot_available(&state.db, id, date).await?;
-
-    let availability = db::availability::get_writer_availability(&state.db, id, 12).await?;
-    let slots_remaining = availability
-        .iter()
-        .find(|s| s.available_date == date)
-        .map(|s| s.slots_remaining)
-        .unwrap_or(0);
+    let slots_remaining =
+        db::availability::get_slots_remaining(&state.db, &writer, &placement, date).await?;
 
     Ok(Json(SlotCheckResponse {
-        available: is_available,
+        available: slots_remaining > 0,
         slot_date: date,
+        placement_id: placement.id,
         slots_remaining,
-        price_cents: writer.price_per_slot,
-        currency: writer.currency,
+        price_cents,
+        currency: placement.currency,
     }))
 }
@@ -1,21 +1,33 @@
 use axum::{
-    extract::{Path, Query, State},
-    routing::{delete, get, patch, post},
+    extract::{Multipart, Path, Query, State},
+    routing::{delete, get, patch, post, put},
     Json, Router,
 };
 use chrono::NaiveDate;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::calendar::{
+    self,
+    ics::{self, SkippedEvent},
+};
 use crate::db;
 use crate::error::{AppError, AppResult};
-use crate::helpers::{get_writer_for_user_or_404, get_writer_or_404, require_writer_ownership};
-use crate::middlewares::{Auth, WriterAuth};
+use crate::helpers::{
+    get_offered_placement, get_placement_or_404, get_writer_for_user_or_404, get_writer_or_404,
+    require_issue_date, require_writer_ownership,
+};
+use crate::middlewares::{Auth, OptionalAuth, WriterAuth};
 use crate::models::{
-    BlackoutDate, BookingWithDetails, CreateBlackoutDate, CreateWriter, Payout, PayoutSummary,
-    UpdateWriter, UserRole, Writer, WriterAvailability, WriterStats,
+    AdPlacement, Blackout, BlackoutCreated, BlackoutRecurrence, BookingWithDetails,
+    ConflictingBooking, CreateAdPlacement, CreateBlackout, CreateManualBooking, CreatePricingRule,
+    CreateWriter, ManualBooking, PauseStatus, Payout, PayoutSummary, PricingRule, PublicWriter,
+    ReplyToReview, Review, SetPause, SetSlug, UpdateAdPlacement, UpdateWriter, UserRole, Writer,
+    WriterAvailability, WriterStats,
 };
+use crate::responses::{PaginatedResponse, PaginationParams};
 use crate::state::AppState;
+use crate::validation;
 
 pub fn router() -> Router<AppState> {
     Router::new()
@@ -26,9 +38,36 @@ pub fn router() -> Router<AppState> {
         .route("/{id}/bookings", get(list_bookings))
         .route("/{id}/bookings/upcoming", get(upcoming_bookings))
         .route("/{id}/availability", get(get_availability))
+        .route("/{id}/placements", get(list_placements))
+        .route("/{id}/placements", post(create_placement))
+        .route("/{id}/placements/{placement_id}", patch(update_placement))
+        .route("/{id}/placements/{placement_id}", delete(delete_placement))
         .route("/{id}/blackout-dates", get(list_blackout_dates))
         .route("/{id}/blackout-dates", post(create_blackout))
+        .route("/{id}/blackout-dates", delete(clear_blackouts))
         .route("/{id}/blackout-dates/{date}", delete(delete_blackout))
+        .route("/{id}/blackout-dates/import", post(import_blackouts))
+        .route(
+            "/{id}/blackout-dates/rules/{blackout_id}",
+            delete(delete_blackout_rule),
+        )
+        .route("/{id}/calendar-feed", post(rotate_calendar_feed))
+        .route("/{id}/calendar-feed", delete(delete_calendar_feed))
+        .route("/{id}/pricing-rules", get(list_pricing_rules))
+        .route("/{id}/pricing-rules", post(create_pricing_rule))
+        .route("/{id}/pricing-rules/{rule_id}", delete(delete_pricing_rule))
+        .route("/{id}/manual-bookings", get(list_manual_bookings))
+        .route("/{id}/manual-bookings", post(create_manual_booking))
+        .route(
+            "/{id}/manual-bookings/{booking_id}",
+            delete(delete_manual_booking),
+        )
+        .route("/{id}/slug", put(set_slug))
+        .route("/{id}/reviews", get(list_reviews))
+        .route("/{id}/reviews/{review_id}/reply", put(reply_to_review))
+        .route("/{id}/pause", get(get_pause))
+        .route("/{id}/pause", put(set_pause))
+        .route("/{id}/pause", delete(clear_pause))
         .route("/{id}/stats", get(get_stats))
         .route("/{id}/payouts", get(list_payouts))
         .route("/{id}/payouts/summary", get(get_payout_summary))
@@ -38,7 +77,7 @@ pub fn router() -> Router<AppState> {
 async fn create_writer(
     State(state): State<AppState>,
     Auth(user): Auth,
-    Json(input): Json<CreateWriter>,
+    Json(mut input): Json<CreateWriter>,
 ) -> AppResult<Json<Writer>> {
     if user.role != UserRole::Writer && user.role != UserRole::Admin {
         return Err(AppError::Forbidden);
@@ -59,7 +98,37 @@ async fn create_writer(
         ));
     }
 
-    let writer = db::writer::create_writer(&state.db, user.id, &input).await?;
+    input.issue_weekdays = validation::validate_issue_weekdays(&input.issue_weekdays)?;
+    validation::validate_issue_week_of_month(input.issue_week_of_month)?;
+    input.timezone = validation::validate_timezone(&input.timezone)?;
+    input.currency = validation::validate_currency(&input.currency)?;
+
+    if input.slots_per_issue <= 0 || input.slots_per_week <= 0 {
+        return Err(AppError::Validation(
+            "Slots per issue and per week must be greater than 0".into(),
+        ));
+    }
+
+    let mut tx = state.db.begin().await?;
+    let writer = db::writer::create_writer(&mut tx, user.id, &input).await?;
+
+    // Start every newsletter with one placement matching its headline price
+    db::ad_placement::create_placement(
+        &mut tx,
+        writer.id,
+        &CreateAdPlacement {
+            name: "Sponsored slot".into(),
+            description: None,
+            price_cents: writer.price_per_slot,
+            currency: writer.currency.clone(),
+            slots_per_issue: writer.slots_per_issue,
+            max_headline_length: validation::MAX_HEADLINE_LENGTH as i32,
+            max_body_length: validation::MAX_BODY_LENGTH as i32,
+            allows_image: true,
+        },
+    )
+    .await?;
+    tx.commit().await?;
 
     Ok(Json(writer))
 }
@@ -75,16 +144,17 @@ async fn get_my_writer_profile(
 async fn get_writer(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
-) -> AppResult<Json<Writer>> {
+) -> AppResult<Json<PublicWriter>> {
     let writer = get_writer_or_404(&state.db, id).await?;
-    Ok(Json(writer))
+    let rating = db::review::get_rating_summary(&state.db, writer.id).await?;
+    Ok(Json(PublicWriter::new(writer, rating)))
 }
 
 async fn update_writer(
     State(state): State<AppState>,
     WriterAuth(user): WriterAuth,
     Path(id): Path<Uuid>,
-    Json(input): Json<UpdateWriter>,
+    Json(mut input): Json<UpdateWriter>,
 ) -> AppResult<Json<Writer>> {
     let writer = get_writer_or_404(&state.db, id).await?;
     require_writer_ownership(&writer, user.id, user.is_admin())?;
@@ -105,6 +175,61 @@ async fn update_writer(
         }
     }
 
+    if let Some(days) = input.cancellation_window_days {
+        if days < 0 {
+            return Err(AppError::Validation(
+                "Cancellation window cannot be negative".into(),
+            ));
+        }
+    }
+
+    if let Some(pct) = input.late_cancellation_refund_pct {
+        if !(0..=100).contains(&pct) {
+            return Err(AppError::Validation(
+                "Late cancellation refund must be between 0 and 100 percent".into(),
+            ));
+        }
+    }
+
+    if let Some(weekdays) = &input.issue_weekdays {
+        input.issue_weekdays = Some(validation::validate_issue_weekdays(weekdays)?);
+    }
+
+    if let Some(week_of_month) = input.issue_week_of_month {
+        validation::validate_issue_week_of_month(week_of_month)?;
+    }
+
+    if let Some(timezone) = &input.timezone {
+        input.timezone = Some(validation::validate_timezone(timezone)?);
+    }
+
+    if let Some(categories) = &input.categories {
+        input.categories = Some(validation::validate_categories(categories)?);
+    }
+
+    if let Some(tags) = &input.tags {
+        input.tags = Some(validation::validate_tags(tags)?);
+    }
+
+    if let Some(countries) = &input.audience_countries {
+        input.audience_countries = Some(validation::validate_audience_countries(countries)?);
+    }
+
+    if let Some(job_roles) = &input.audience_job_roles {
+        input.audience_job_roles = Some(validation::validate_job_roles(job_roles)?);
+    }
+
+    if let Some(open_rate) = input.open_rate_pct {
+        validation::validate_open_rate(open_rate)?;
+    }
+
+    if input.slots_per_issue.is_some_and(|n| n <= 0) || input.slots_per_week.is_some_and(|n| n <= 0)
+    {
+        return Err(AppError::Validation(
+            "Slots per issue and per week must be greater than 0".into(),
+        ));
+    }
+
     let updated = db::writer::update_writer(&state.db, id, &input).await?;
 
     Ok(Json(updated))
@@ -118,7 +243,7 @@ async fn list_bookings(
     let writer = get_writer_or_404(&state.db, id).await?;
     require_writer_ownership(&writer, user.id, user.is_admin())?;
 
-    let bookings = db::sponsor::get_writer_bookings(&state.db, id).await?;
+    let bookings = db::sponsor::get_writer_bookings(&state.db, id, user.id).await?;
 
     Ok(Json(bookings))
 }
@@ -142,7 +267,8 @@ async fn upcoming_bookings(
     let writer = get_writer_or_404(&state.db, id).await?;
     require_writer_ownership(&writer, user.id, user.is_admin())?;
 
-    let bookings = db::sponsor::get_writer_upcoming_bookings(&state.db, id, query.weeks).await?;
+    let bookings =
+        db::sponsor::get_writer_upcoming_bookings(&state.db, id, query.weeks, user.id).await?;
 
     Ok(Json(bookings))
 }
@@ -151,6 +277,7 @@ async fn upcoming_bookings(
 struct AvailabilityQuery {
     #[serde(default = "default_availability_weeks")]
     weeks: i32,
+    placement_id: Option<Uuid>,
 }
 
 fn default_availability_weeks() -> i32 {
@@ -162,52 +289,297 @@ async fn get_availability(
     Path(id): Path<Uuid>,
     Query(query): Query<AvailabilityQuery>,
 ) -> AppResult<Json<WriterAvailability>> {
+    if !(1..=52).contains(&query.weeks) {
+        return Err(AppError::Validation(
+            "weeks must be between 1 and 52".into(),
+        ));
+    }
+
     let writer = get_writer_or_404(&state.db, id).await?;
+    let placement = get_offered_placement(&state.db, &writer, query.placement_id).await?;
 
     let available_slots =
-        db::availability::get_writer_availability(&state.db, id, query.weeks).await?;
+        db::availability::get_writer_availability(&state.db, &writer, &placement, query.weeks)
+            .await?;
 
     Ok(Json(WriterAvailability {
         writer_id: writer.id,
         newsletter_name: writer.newsletter_name,
-        price_per_slot: writer.price_per_slot,
-        currency: writer.currency,
+        placement_id: placement.id,
+        placement_name: placement.name,
+        price_per_slot: placement.price_cents,
+        currency: placement.currency,
         available_slots,
     }))
 }
 
+async fn list_placements(
+    State(state): State<AppState>,
+    OptionalAuth(user): OptionalAuth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<Vec<AdPlacement>>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+
+    // Retired placements are only shown to the writer managing them
+    let include_inactive =
+        user.is_some_and(|u| require_writer_ownership(&writer, u.id, u.is_admin()).is_ok());
+
+    let placements =
+        db::ad_placement::get_writer_placements(&state.db, id, include_inactive).await?;
+
+    Ok(Json(placements))
+}
+
+async fn create_placement(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+    Json(mut input): Json<CreateAdPlacement>,
+) -> AppResult<Json<AdPlacement>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    input.name = validation::validate_placement_name(&input.name)?;
+    input.description = input
+        .description
+        .as_deref()
+        .map(validation::validate_placement_description)
+        .transpose()?
+        .filter(|d| !d.is_empty());
+
+    // Payouts and stats add amounts up per newsletter, so every placement is
+    // priced in the newsletter's currency
+    let writer_currency = writer.currency.trim().to_lowercase();
+    if input.currency.trim().is_empty() {
+        input.currency = writer_currency.clone();
+    }
+    input.currency = validation::validate_currency(&input.currency)?;
+    if input.currency != writer_currency {
+        return Err(AppError::Validation(format!(
+            "Placements must be priced in the newsletter's currency ({})",
+            writer_currency.to_uppercase()
+        )));
+    }
+
+    if input.price_cents <= 0 {
+        return Err(AppError::Validation(
+            "Placement price must be greater than 0".into(),
+        ));
+    }
+
+    if input.slots_per_issue <= 0 {
+        return Err(AppError::Validation(
+            "Slots per issue must be greater than 0".into(),
+        ));
+    }
+
+    validation::validate_placement_limits(input.max_headline_length, input.max_body_length)?;
+
+    let mut conn = state.db.acquire().await?;
+    match db::ad_placement::create_placement(&mut conn, id, &input).await {
+        Ok(placement) => Ok(Json(placement)),
+        Err(sqlx::Error::Database(e)) if e.is_unique_violation() => Err(placement_name_taken()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn placement_name_taken() -> AppError {
+    AppError::Conflict("A placement with this name already exists".into())
+}
+
+async fn get_writer_placement_or_404(
+    state: &AppState,
+    writer: &Writer,
+    placement_id: Uuid,
+) -> AppResult<AdPlacement> {
+    let placement = get_placement_or_404(&state.db, placement_id).await?;
+    if placement.writer_id != writer.id {
+        return Err(AppError::NotFound("Ad placement not found".into()));
+    }
+    Ok(placement)
+}
+
+async fn update_placement(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path((id, placement_id)): Path<(Uuid, Uuid)>,
+    Json(mut input): Json<UpdateAdPlacement>,
+) -> AppResult<Json<AdPlacement>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+    let placement = get_writer_placement_or_404(&state, &writer, placement_id).await?;
+
+    if let Some(ref name) = input.name {
+        input.name = Some(validation::validate_placement_name(name)?);
+    }
+
+    if let Some(ref description) = input.description {
+        input.description = Some(validation::validate_placement_description(description)?);
+    }
+
+    if input.price_cents.is_some_and(|p| p <= 0) {
+        return Err(AppError::Validation(
+            "Placement price must be greater than 0".into(),
+        ));
+    }
+
+    if input.slots_per_issue.is_some_and(|n| n <= 0) {
+        return Err(AppError::Validation(
+            "Slots per issue must be greater than 0".into(),
+        ));
+    }
+
+    validation::validate_placement_limits(
+        input
+            .max_headline_length
+            .unwrap_or(placement.max_headline_length),
+        input.max_body_length.unwrap_or(placement.max_body_length),
+    )?;
+
+    if input.is_active == Some(false) && placement.is_active {
+        require_another_active_placement(&state, &writer, &placement).await?;
+    }
+
+    let mut tx = state.db.begin().await?;
+    let updated = match db::ad_placement::update_placement(&mut tx, placement.id, &input).await {
+        Ok(updated) => updated,
+        Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+            return Err(placement_name_taken())
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    if updated.slots_per_issue != placement.slots_per_issue {
+        db::slot_inventory::set_capacity(&mut tx, updated.id, updated.slots_per_issue).await?;
+    }
+    tx.commit().await?;
+
+    Ok(Json(updated))
+}
+
+/// Placements are retired rather than deleted, since existing bookings point at them.
+async fn delete_placement(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path((id, placement_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<Json<MessageResponse>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+    let placement = get_writer_placement_or_404(&state, &writer, placement_id).await?;
+
+    if placement.is_active {
+        require_another_active_placement(&state, &writer, &placement).await?;
+        db::ad_placement::deactivate_placement(&state.db, placement.id).await?;
+    }
+
+    Ok(Json(MessageResponse {
+        message: "Ad placement removed".into(),
+    }))
+}
+
+async fn require_another_active_placement(
+    state: &AppState,
+    writer: &Writer,
+    placement: &AdPlacement,
+) -> AppResult<()> {
+    let active = db::ad_placement::get_writer_placements(&state.db, writer.id, false).await?;
+    if active.iter().all(|p| p.id == placement.id) {
+        return Err(AppError::BadRequest(
+            "A newsletter must keep at least one active ad placement".into(),
+        ));
+    }
+    Ok(())
+}
+
 async fn list_blackout_dates(
     State(state): State<AppState>,
     WriterAuth(user): WriterAuth,
     Path(id): Path<Uuid>,
-) -> AppResult<Json<Vec<BlackoutDate>>> {
+) -> AppResult<Json<Vec<Blackout>>> {
     let writer = get_writer_or_404(&state.db, id).await?;
     require_writer_ownership(&writer, user.id, user.is_admin())?;
 
-    let blackout_dates = db::blackout::get_writer_blackout_dates(&state.db, id).await?;
+    let blackouts = db::blackout::get_writer_blackouts(&state.db, id).await?;
 
-    Ok(Json(blackout_dates))
+    Ok(Json(blackouts))
 }
 
 async fn create_blackout(
     State(state): State<AppState>,
     WriterAuth(user): WriterAuth,
     Path(id): Path<Uuid>,
-    Json(input): Json<CreateBlackoutDate>,
-) -> AppResult<Json<BlackoutDate>> {
+    Json(mut input): Json<CreateBlackout>,
+) -> AppResult<Json<BlackoutCreated>> {
     let writer = get_writer_or_404(&state.db, id).await?;
     require_writer_ownership(&writer, user.id, user.is_admin())?;
 
-    let today = chrono::Utc::now().date_naive();
-    if input.blocked_date <= today {
+    let today = calendar::today_in(&writer.timezone);
+    if input.start_date <= today {
         return Err(AppError::Validation(
             "Blackout date must be in the future".into(),
         ));
     }
 
-    let blackout = db::blackout::create_blackout_date(&state.db, id, &input).await?;
+    validation::validate_blackout(&input)?;
+    // Kept as plain text; it is shown in calendar apps, not as HTML
+    input.reason = input
+        .reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(String::from);
+
+    let mut tx = state.db.begin().await?;
+    let blackout = db::blackout::create_blackout(&mut tx, id, &input).await?;
+    let conflicting_bookings = db::blackout::get_conflicting_bookings(&mut tx, &blackout).await?;
+    tx.commit().await?;
+
+    if !conflicting_bookings.is_empty() {
+        tracing::info!(
+            writer_id = %id,
+            blackout_id = %blackout.id,
+            bookings = conflicting_bookings.len(),
+            "blackout overlaps paid bookings"
+        );
+    }
+
+    Ok(Json(BlackoutCreated {
+        blackout,
+        conflicting_bookings,
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+struct ClearBlackoutsQuery {
+    from: NaiveDate,
+    to: NaiveDate,
+}
+
+async fn clear_blackouts(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ClearBlackoutsQuery>,
+) -> AppResult<Json<MessageResponse>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    if query.to < query.from {
+        return Err(AppError::Validation(
+            "End of the range must not be before its start".into(),
+        ));
+    }
+
+    let mut tx = state.db.begin().await?;
+    let changed = db::blackout::clear_blackout_range(&mut tx, id, query.from, query.to).await?;
+    tx.commit().await?;
 
-    Ok(Json(blackout))
+    Ok(Json(MessageResponse {
+        message: format!(
+            "Cleared {} to {} ({} blackouts changed)",
+            query.from, query.to, changed
+        ),
+    }))
 }
 
 async fn delete_blackout(
@@ -218,9 +590,24 @@ async fn delete_blackout(
     let writer = get_writer_or_404(&state.db, id).await?;
     require_writer_ownership(&writer, user.id, user.is_admin())?;
 
-    let deleted = db::blackout::delete_blackout_date(&state.db, id, date).await?;
+    let mut tx = state.db.begin().await?;
+
+    // Recurring rules are only removed as a whole, through their own endpoint
+    let blackouts = db::blackout::get_blackouts_between(&mut tx, id, date, date).await?;
+    if let Some(rule) = blackouts
+        .iter()
+        .find(|b| b.recurrence != BlackoutRecurrence::Once && calendar::blackout_covers(b, date))
+    {
+        return Err(AppError::Conflict(format!(
+            "{} falls in recurring blackout {}; remove the rule instead",
+            date, rule.id
+        )));
+    }
+
+    let changed = db::blackout::clear_blackout_range(&mut tx, id, date, date).await?;
+    tx.commit().await?;
 
-    if deleted {
+    if changed > 0 {
         Ok(Json(MessageResponse {
             message: "Blackout date deleted".into(),
         }))
@@ -229,6 +616,488 @@ async fn delete_blackout(
     }
 }
 
+async fn delete_blackout_rule(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path((id, blackout_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<Json<MessageResponse>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    if db::blackout::delete_blackout(&state.db, id, blackout_id).await? {
+        Ok(Json(MessageResponse {
+            message: "Blackout deleted".into(),
+        }))
+    } else {
+        Err(AppError::NotFound("Blackout not found".into()))
+    }
+}
+
+const MAX_ICS_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug, Deserialize)]
+struct ImportBlackoutsQuery {
+    #[serde(default)]
+    dry_run: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ImportBlackoutsResult {
+    dry_run: bool,
+    /// With `dry_run` these are what would be created; their ids are not kept
+    blackouts: Vec<Blackout>,
+    skipped: Vec<SkippedEvent>,
+    conflicting_bookings: Vec<ConflictingBooking>,
+}
+
+async fn import_blackouts(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ImportBlackoutsQuery>,
+    mut multipart: Multipart,
+) -> AppResult<Json<ImportBlackoutsResult>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    let field = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::BadRequest(format!("Failed to read multipart: {}", e)))?
+        .ok_or_else(|| AppError::BadRequest("No file provided".into()))?;
+    let data = field
+        .bytes()
+        .await
+        .map_err(|e| AppError::BadRequest(format!("Failed to read file: {}", e)))?;
+
+    if data.len() > MAX_ICS_BYTES {
+        return Err(AppError::BadRequest(
+            "Calendar file must be smaller than 1 MB".into(),
+        ));
+    }
+    let content = std::str::from_utf8(&data)
+        .map_err(|_| AppError::BadRequest("Calendar file must be UTF-8 text".into()))?;
+
+    let parsed = ics::parse_all_day_events(content);
+    let mut skipped = parsed.skipped;
+
+    let today = calendar::today_in(&writer.timezone);
+    let existing = db::blackout::get_writer_blackouts(&state.db, id).await?;
+
+    let mut tx = state.db.begin().await?;
+    let mut blackouts = Vec::new();
+    let mut conflicting_bookings = Vec::new();
+
+    for event in parsed.events {
+        let skip = |reason: &str| SkippedEvent {
+            summary: Some(event.summary.clone()),
+            reason: reason.to_string(),
+        };
+
+        let mut input = CreateBlackout {
+            start_date: event.start_date,
+            end_date: Some(event.end_date),
+            recurrence: event.recurrence,
+            interval_weeks: event.interval_weeks,
+            repeat_until: event.repeat_until,
+            reason: Some(event.summary.trim())
+                .filter(|r| !r.is_empty())
+                .map(|r| {
+                    r.chars()
+                        .take(validation::MAX_BLACKOUT_REASON_LENGTH)
+                        .collect()
+                }),
+        };
+
+        // Only the part of a one-off event that is still ahead is blocked
+        if input.recurrence == BlackoutRecurrence::Once {
+            if event.end_date <= today {
+                skipped.push(skip("Event is in the past"));
+                continue;
+            }
+            input.start_date = input.start_date.max(today + chrono::Duration::days(1));
+        } else if input.repeat_until.is_some_and(|until| until <= today) {
+            skipped.push(skip("Event no longer repeats"));
+            continue;
+        }
+
+        if let Err(AppError::Validation(reason)) = validation::validate_blackout(&input) {
+            skipped.push(skip(&reason));
+            continue;
+        }
+
+        let duplicate = existing.iter().chain(&blackouts).any(|b: &Blackout| {
+            b.start_date == input.start_date
+                && Some(b.end_date) == input.end_date
+                && b.recurrence == input.recurrence
+                && b.interval_weeks == input.interval_weeks
+                && b.repeat_until == input.repeat_until
+        });
+        if duplicate {
+            skipped.push(skip("Already blacked out"));
+            continue;
+        }
+
+        let blackout = db::blackout::create_blackout(&mut tx, id, &input).await?;
+        for conflict in db::blackout::get_conflicting_bookings(&mut tx, &blackout).await? {
+            if !conflicting_bookings
+                .iter()
+                .any(|c: &ConflictingBooking| c.booking_id == conflict.booking_id)
+            {
+                conflicting_bookings.push(conflict);
+            }
+        }
+        blackouts.push(blackout);
+    }
+
+    if query.dry_run {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
+    }
+
+    Ok(Json(ImportBlackoutsResult {
+        dry_run: query.dry_run,
+        blackouts,
+        skipped,
+        conflicting_bookings,
+    }))
+}
+
+#[derive(Debug, Serialize)]
+struct CalendarFeedResponse {
+    url: String,
+}
+
+async fn rotate_calendar_feed(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<CalendarFeedResponse>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    let token = state.auth.generate_random_token();
+    db::calendar_feed::set_feed_token(&state.db, id, &state.auth.hash_token(&token)).await?;
+
+    Ok(Json(CalendarFeedResponse {
+        url: format!(
+            "{}/api/calendar/{}.ics",
+            state.config.server.public_url, token
+        ),
+    }))
+}
+
+async fn delete_calendar_feed(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<MessageResponse>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    if db::calendar_feed::delete_feed_token(&state.db, id).await? {
+        Ok(Json(MessageResponse {
+            message: "Calendar feed disabled".into(),
+        }))
+    } else {
+        Err(AppError::NotFound("Calendar feed not found".into()))
+    }
+}
+
+async fn list_pricing_rules(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<Vec<PricingRule>>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    let rules = db::pricing_rule::get_writer_pricing_rules(&state.db, id).await?;
+
+    Ok(Json(rules))
+}
+
+async fn create_pricing_rule(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+    Json(input): Json<CreatePricingRule>,
+) -> AppResult<Json<PricingRule>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    let input = validation::validate_pricing_rule(input)?;
+
+    if let Some(placement_id) = input.placement_id {
+        get_writer_placement_or_404(&state, &writer, placement_id).await?;
+    }
+
+    if db::pricing_rule::has_duplicate_rule(&state.db, id, &input).await? {
+        return Err(AppError::Conflict(
+            "A pricing rule for this date or weekday already exists".into(),
+        ));
+    }
+
+    let rule = db::pricing_rule::create_pricing_rule(&state.db, id, &input).await?;
+
+    Ok(Json(rule))
+}
+
+async fn delete_pricing_rule(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path((id, rule_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<Json<MessageResponse>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    if db::pricing_rule::delete_pricing_rule(&state.db, id, rule_id).await? {
+        Ok(Json(MessageResponse {
+            message: "Pricing rule removed".into(),
+        }))
+    } else {
+        Err(AppError::NotFound("Pricing rule not found".into()))
+    }
+}
+
+async fn list_reviews(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(pagination): Query<PaginationParams>,
+) -> AppResult<Json<PaginatedResponse<Review>>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    let pagination = pagination.validated();
+
+    let rating = db::review::get_rating_summary(&state.db, writer.id).await?;
+    let reviews =
+        db::review::get_writer_reviews(&state.db, writer.id, pagination.limit, pagination.offset)
+            .await?;
+
+    Ok(Json(PaginatedResponse::new(
+        reviews,
+        rating.review_count,
+        pagination.limit,
+        pagination.offset,
+    )))
+}
+
+/// Replying again replaces the reply.
+async fn reply_to_review(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path((id, review_id)): Path<(Uuid, Uuid)>,
+    Json(input): Json<ReplyToReview>,
+) -> AppResult<Json<Review>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    let reply = validation::validate_review_reply(&input.reply)?;
+
+    let review = db::review::set_reply(&state.db, id, review_id, &reply)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Review not found".into()))?;
+
+    Ok(Json(review))
+}
+
+/// The previous slug stays reserved to the writer and redirects to the new one.
+async fn set_slug(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+    Json(input): Json<SetSlug>,
+) -> AppResult<Json<Writer>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    let slug = validation::validate_slug(&input.slug)?;
+    if writer.slug.as_deref() == Some(slug.as_str()) {
+        return Ok(Json(writer));
+    }
+
+    let taken = || AppError::Conflict("This slug is already taken".into());
+    if db::writer_slug::is_slug_taken(&state.db, &slug, id).await? {
+        return Err(taken());
+    }
+
+    match db::writer_slug::set_slug(&state.db, id, &slug).await {
+        Ok(updated) => Ok(Json(updated)),
+        // Claimed by someone else since the check above
+        Err(sqlx::Error::Database(e)) if e.is_unique_violation() => Err(taken()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ManualBookingsQuery {
+    from: Option<NaiveDate>,
+}
+
+async fn list_manual_bookings(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ManualBookingsQuery>,
+) -> AppResult<Json<Vec<ManualBooking>>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    let from = query
+        .from
+        .unwrap_or_else(|| calendar::today_in(&writer.timezone));
+    let bookings = db::manual_booking::get_writer_manual_bookings(&state.db, id, from).await?;
+
+    Ok(Json(bookings))
+}
+
+/// Takes a slot like any paid booking, under the same week lock as checkout.
+async fn create_manual_booking(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+    Json(input): Json<CreateManualBooking>,
+) -> AppResult<Json<ManualBooking>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    let input = validation::validate_manual_booking(input)?;
+    let placement = get_offered_placement(&state.db, &writer, input.placement_id).await?;
+
+    if input.slot_date < calendar::today_in(&writer.timezone) {
+        return Err(AppError::Validation(
+            "Slot date must not be in the past".into(),
+        ));
+    }
+    require_issue_date(&writer, input.slot_date)?;
+
+    let mut tx = state.db.begin().await?;
+
+    let (_, remaining) =
+        db::sponsor::lock_slots_remaining_tx(&mut tx, id, placement.id, input.slot_date).await?;
+    if remaining <= 0 {
+        return Err(AppError::Conflict(format!(
+            "No open {} slot on {}; it is booked, blacked out or inside a pause",
+            placement.name, input.slot_date
+        )));
+    }
+
+    let booking = db::manual_booking::create_manual_booking_tx(
+        &mut tx,
+        id,
+        placement.id,
+        &placement.currency,
+        &input,
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(Json(booking))
+}
+
+async fn delete_manual_booking(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path((id, booking_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<Json<MessageResponse>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    if db::manual_booking::delete_manual_booking(&state.db, id, booking_id).await? {
+        Ok(Json(MessageResponse {
+            message: "Manual booking removed".into(),
+        }))
+    } else {
+        Err(AppError::NotFound("Manual booking not found".into()))
+    }
+}
+
+/// Bookings already in the past are left to the overdue publication job.
+async fn pause_status(state: &AppState, writer: Writer) -> AppResult<PauseStatus> {
+    let conflicting_bookings = if writer.paused {
+        let today = calendar::today_in(&writer.timezone);
+        let from = writer
+            .pause_starts_on
+            .map_or(today, |start| start.max(today));
+        db::writer::get_paid_bookings_between(&state.db, writer.id, from, writer.pause_ends_on)
+            .await?
+    } else {
+        Vec::new()
+    };
+
+    Ok(PauseStatus {
+        paused: writer.paused,
+        pause_starts_on: writer.pause_starts_on,
+        pause_ends_on: writer.pause_ends_on,
+        conflicting_bookings,
+    })
+}
+
+async fn get_pause(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<PauseStatus>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    Ok(Json(pause_status(&state, writer).await?))
+}
+
+async fn set_pause(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+    Json(input): Json<SetPause>,
+) -> AppResult<Json<PauseStatus>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    if let (Some(start), Some(end)) = (input.starts_on, input.ends_on) {
+        if end < start {
+            return Err(AppError::Validation(
+                "Pause must not end before it starts".into(),
+            ));
+        }
+    }
+    if let Some(end) = input.ends_on {
+        if end < calendar::today_in(&writer.timezone) {
+            return Err(AppError::Validation(
+                "Pause end date must not be in the past".into(),
+            ));
+        }
+    }
+
+    let writer = db::writer::set_pause(&state.db, id, input.starts_on, input.ends_on).await?;
+    let status = pause_status(&state, writer).await?;
+
+    if !status.conflicting_bookings.is_empty() {
+        tracing::info!(
+            writer_id = %id,
+            bookings = status.conflicting_bookings.len(),
+            "pause overlaps paid bookings"
+        );
+    }
+
+    Ok(Json(status))
+}
+
+async fn clear_pause(
+    State(state): State<AppState>,
+    WriterAuth(user): WriterAuth,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<MessageResponse>> {
+    let writer = get_writer_or_404(&state.db, id).await?;
+    require_writer_ownership(&writer, user.id, user.is_admin())?;
+
+    db::writer::clear_pause(&state.db, id).await?;
+
+    Ok(Json(MessageResponse {
+        message: "Sponsorships resumed".into(),
+    }))
+}
+
 async fn get_stats(
     State(state): State<AppState>,
     WriterAuth(user): WriterAuth,
@@ -105,7 +105,8 @@ impl AuthService {
         }
     }
 
-    pub fn generate_reset_token(&self) -> String {
+    /// Random URL-safe token; store only its hash
+    pub fn generate_random_token(&self) -> String {
         use rand::Rng;
         let mut rng = rand::thread_rng();
         let token: String = (0..32)
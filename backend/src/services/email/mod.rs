@@ -4,6 +4,8 @@ mod types;
 
 pub use service::{EmailConfig, EmailService};
 pub use types::{
-    BookingConfirmationData, BookingPublishedData, BookingRejectedData, BookingStatusData,
-    NewBookingNotificationData, PasswordResetData, PayoutNotificationData, WelcomeData,
+    AdResubmittedData, BookingCancelledData, BookingConfirmationData, BookingMessageData,
+    BookingPublishedData, BookingRejectedData, BookingStatusData, ChangesRequestedData,
+    NewBookingNotificationData, PasswordResetData, PayoutNotificationData, PublicationOverdueData,
+    RescheduleProposedData, RescheduleResponseData, WaitlistOfferData, WelcomeData,
 };
@@ -118,6 +118,73 @@ impl EmailService {
         self.send(sponsor_email, &subject, &html).await
     }
 
+    pub async fn send_changes_requested(
+        &self,
+        sponsor_email: &str,
+        data: ChangesRequestedData,
+    ) -> AppResult<()> {
+        let subject = format!("Changes Requested - {}", data.newsletter_name);
+        let html = EmailTemplate::changes_requested(&data);
+        self.send(sponsor_email, &subject, &html).await
+    }
+
+    pub async fn send_ad_resubmitted(
+        &self,
+        writer_email: &str,
+        data: AdResubmittedData,
+    ) -> AppResult<()> {
+        let subject = format!("Ad Resubmitted by {}", data.sponsor_name);
+        let html = EmailTemplate::ad_resubmitted(&data);
+        self.send(writer_email, &subject, &html).await
+    }
+
+    pub async fn send_reschedule_proposed(
+        &self,
+        email: &str,
+        data: RescheduleProposedData,
+    ) -> AppResult<()> {
+        let subject = format!("New Date Proposed - {}", data.newsletter_name);
+        let html = EmailTemplate::reschedule_proposed(&data);
+        self.send(email, &subject, &html).await
+    }
+
+    pub async fn send_reschedule_response(
+        &self,
+        email: &str,
+        data: RescheduleResponseData,
+    ) -> AppResult<()> {
+        let subject = if data.accepted {
+            format!("Booking Rescheduled - {}", data.newsletter_name)
+        } else {
+            format!("Reschedule Declined - {}", data.newsletter_name)
+        };
+        let html = EmailTemplate::reschedule_response(&data);
+        self.send(email, &subject, &html).await
+    }
+
+    pub async fn send_booking_message(
+        &self,
+        email: &str,
+        data: BookingMessageData,
+    ) -> AppResult<()> {
+        let subject = format!(
+            "New Message from {} - {}",
+            data.sender_name, data.newsletter_name
+        );
+        let html = EmailTemplate::booking_message(&data);
+        self.send(email, &subject, &html).await
+    }
+
+    pub async fn send_waitlist_offer(
+        &self,
+        sponsor_email: &str,
+        data: WaitlistOfferData,
+    ) -> AppResult<()> {
+        let subject = format!("A Slot Opened Up - {}", data.newsletter_name);
+        let html = EmailTemplate::waitlist_offer(&data);
+        self.send(sponsor_email, &subject, &html).await
+    }
+
     pub async fn send_booking_published(
         &self,
         sponsor_email: &str,
@@ -128,6 +195,59 @@ impl EmailService {
         self.send(sponsor_email, &subject, &html).await
     }
 
+    pub async fn send_booking_cancelled(
+        &self,
+        sponsor_email: &str,
+        data: BookingCancelledData,
+    ) -> AppResult<()> {
+        let subject = format!("Booking Cancelled - {}", data.newsletter_name);
+        let html = EmailTemplate::booking_cancelled(&data);
+        self.send(sponsor_email, &subject, &html).await
+    }
+
+    pub async fn send_booking_cancelled_writer(
+        &self,
+        writer_email: &str,
+        data: BookingCancelledData,
+    ) -> AppResult<()> {
+        let subject = format!("Booking Cancelled by {}", data.sponsor_name);
+        let html = EmailTemplate::booking_cancelled_writer(&data);
+        self.send(writer_email, &subject, &html).await
+    }
+
+    pub async fn send_publication_reminder(
+        &self,
+        writer_email: &str,
+        data: PublicationOverdueData,
+    ) -> AppResult<()> {
+        let subject = format!(
+            "Reminder: Mark Your Sponsored Slot as Published - {}",
+            data.slot_date
+        );
+        let html = EmailTemplate::publication_reminder(&data);
+        self.send(writer_email, &subject, &html).await
+    }
+
+    pub async fn send_publication_escalation(
+        &self,
+        admin_email: &str,
+        data: PublicationOverdueData,
+    ) -> AppResult<()> {
+        let subject = format!("Overdue Publication - {}", data.newsletter_name);
+        let html = EmailTemplate::publication_escalation(&data);
+        self.send(admin_email, &subject, &html).await
+    }
+
+    pub async fn send_overdue_refund(
+        &self,
+        sponsor_email: &str,
+        data: PublicationOverdueData,
+    ) -> AppResult<()> {
+        let subject = format!("Your Ad Was Not Published - {}", data.newsletter_name);
+        let html = EmailTemplate::overdue_refund(&data);
+        self.send(sponsor_email, &subject, &html).await
+    }
+
     pub async fn send_payout_notification(
         &self,
         writer_email: &str,
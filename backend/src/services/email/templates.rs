@@ -2,6 +2,16 @@ use super::types::*;
 
 pub(super) struct EmailTemplate;
 
+/// For values not stored through `sanitize_text`, such as URLs.
+fn escape_attr(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#x27;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 impl EmailTemplate {
     pub fn base(content: &str, preheader: &str) -> String {
         format!(
@@ -596,6 +606,39 @@ impl EmailTemplate {
     }
 
     pub fn booking_published(data: &BookingPublishedData) -> String {
+        let mut proof_rows = String::new();
+        if let Some(url) = data.published_url.as_deref().map(escape_attr) {
+            proof_rows.push_str(&format!(
+                r##"
+        <div class="detail-row">
+            <span class="detail-label">Published Issue</span>
+            <span class="detail-value"><a href="{url}">View issue</a></span>
+        </div>"##
+            ));
+        }
+        if let Some(url) = data.publication_proof_url.as_deref().map(escape_attr) {
+            proof_rows.push_str(&format!(
+                r##"
+        <div class="detail-row">
+            <span class="detail-label">Screenshot</span>
+            <span class="detail-value"><a href="{url}">View screenshot</a></span>
+        </div>"##
+            ));
+        }
+        let proof_section = if proof_rows.is_empty() {
+            String::new()
+        } else {
+            format!(
+                r##"
+<div class="highlight-box">
+    <p style="font-weight: 600; margin-bottom: 8px;">Proof of publication</p>
+    <div class="detail-grid">{proof_rows}
+    </div>
+</div>
+"##
+            )
+        };
+
         let content = format!(
             r##"
 <p class="greeting">Live Now</p>
@@ -623,6 +666,8 @@ impl EmailTemplate {
     <a href="{ad_cta_url}" class="ad-cta">{ad_cta_text} →</a>
 </div>
 
+{proof_section}
+
 <div class="btn-wrapper">
     <a href="{dashboard_url}" class="btn btn-success">View Campaign Results</a>
 </div>
@@ -811,4 +856,523 @@ impl EmailTemplate {
 
         Self::base(&content, &format!("Welcome to Adsloty, {}!", data.name))
     }
+
+    pub fn booking_cancelled(data: &BookingCancelledData) -> String {
+        let refund = format!("{:.2}", data.refund_cents as f64 / 100.0);
+        let currency = data.currency.to_uppercase();
+        let refund_text = if data.refund_cents > 0 {
+            "Your refund has been initiated. Please allow 5-10 business days for the funds to appear \
+             in your account, depending on your payment method."
+        } else {
+            "No payment was taken for this booking, so there is nothing to refund."
+        };
+        let content = format!(
+            r##"
+<p class="greeting">Booking Cancelled</p>
+<h1 class="headline">Your booking has been cancelled</h1>
+<p class="text">
+    Your ad placement in <strong>{newsletter_name}</strong> for <strong>{slot_date}</strong>
+    has been cancelled and the slot has been released.
+</p>
+
+<div class="highlight-box">
+    <div class="detail-grid">
+        <div class="detail-row">
+            <span class="detail-label">Newsletter</span>
+            <span class="detail-value">{newsletter_name}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Publish Date</span>
+            <span class="detail-value">{slot_date}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Refund</span>
+            <span class="detail-value">${refund} {currency}</span>
+        </div>
+    </div>
+</div>
+
+<p class="text">{refund_text}</p>
+
+<div class="btn-wrapper">
+    <a href="{dashboard_url}" class="btn btn-primary">View Bookings</a>
+</div>
+"##,
+            newsletter_name = data.newsletter_name,
+            slot_date = data.slot_date,
+            refund = refund,
+            currency = currency,
+            refund_text = refund_text,
+            dashboard_url = data.dashboard_url
+        );
+
+        Self::base(&content, "Your booking has been cancelled.")
+    }
+
+    pub fn booking_cancelled_writer(data: &BookingCancelledData) -> String {
+        let reason_section = if let Some(reason) = &data.reason {
+            format!(
+                r##"
+<div class="highlight-box warning">
+    <p style="font-weight: 600; margin-bottom: 8px;">Reason provided:</p>
+    <p style="color: #64748b;">{}</p>
+</div>
+"##,
+                reason
+            )
+        } else {
+            String::new()
+        };
+
+        let content = format!(
+            r##"
+<p class="greeting">Booking Cancelled</p>
+<h1 class="headline">A sponsor cancelled their booking</h1>
+<p class="text">
+    <strong>{sponsor_name}</strong> has cancelled their ad placement for
+    <strong>{slot_date}</strong>. The slot is available for booking again.
+</p>
+
+{reason_section}
+
+<div class="btn-wrapper">
+    <a href="{dashboard_url}" class="btn btn-primary">View Schedule</a>
+</div>
+"##,
+            sponsor_name = data.sponsor_name,
+            slot_date = data.slot_date,
+            reason_section = reason_section,
+            dashboard_url = data.dashboard_url
+        );
+
+        let preheader = format!("{} cancelled their booking", data.sponsor_name);
+        Self::base(&content, &preheader)
+    }
+
+    pub fn publication_reminder(data: &PublicationOverdueData) -> String {
+        let content = format!(
+            r##"
+<p class="greeting">Action Needed</p>
+<h1 class="headline">Was this ad published?</h1>
+<p class="text">
+    The sponsored slot from <strong>{sponsor_name}</strong> in <strong>{newsletter_name}</strong>
+    was due on <strong>{slot_date}</strong>, but it hasn't been marked as published yet.
+</p>
+
+<div class="highlight-box">
+    <div class="detail-grid">
+        <div class="detail-row">
+            <span class="detail-label">Sponsor</span>
+            <span class="detail-value">{sponsor_name}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Ad</span>
+            <span class="detail-value">{ad_headline}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Slot Date</span>
+            <span class="detail-value">{slot_date}</span>
+        </div>
+    </div>
+</div>
+
+<div class="highlight-box warning">
+    <p style="color: #64748b;">
+        If the ad is not marked as published by <strong>{refund_date}</strong>, the sponsor
+        will be refunded automatically and you will not be paid for this slot.
+    </p>
+</div>
+
+<div class="btn-wrapper">
+    <a href="{dashboard_url}" class="btn btn-primary">Mark as Published</a>
+</div>
+"##,
+            newsletter_name = data.newsletter_name,
+            sponsor_name = data.sponsor_name,
+            ad_headline = data.ad_headline,
+            slot_date = data.slot_date,
+            refund_date = data.refund_date,
+            dashboard_url = data.dashboard_url
+        );
+
+        Self::base(
+            &content,
+            "A sponsored slot is waiting to be marked as published.",
+        )
+    }
+
+    pub fn publication_escalation(data: &PublicationOverdueData) -> String {
+        let amount = format!("{:.2}", data.amount_cents as f64 / 100.0);
+        let currency = data.currency.to_uppercase();
+        let content = format!(
+            r##"
+<p class="greeting">Overdue Publication</p>
+<h1 class="headline">An approved ad has not been published</h1>
+<p class="text">
+    <strong>{newsletter_name}</strong> approved an ad from <strong>{sponsor_name}</strong> for
+    <strong>{slot_date}</strong> but has not marked it as published, even after a reminder.
+</p>
+
+<div class="highlight-box">
+    <div class="detail-grid">
+        <div class="detail-row">
+            <span class="detail-label">Newsletter</span>
+            <span class="detail-value">{newsletter_name}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Sponsor</span>
+            <span class="detail-value">{sponsor_name}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Amount</span>
+            <span class="detail-value">${amount} {currency}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Automatic Refund</span>
+            <span class="detail-value">{refund_date}</span>
+        </div>
+    </div>
+</div>
+
+<div class="btn-wrapper">
+    <a href="{dashboard_url}" class="btn btn-primary">Review Booking</a>
+</div>
+"##,
+            newsletter_name = data.newsletter_name,
+            sponsor_name = data.sponsor_name,
+            slot_date = data.slot_date,
+            amount = amount,
+            currency = currency,
+            refund_date = data.refund_date,
+            dashboard_url = data.dashboard_url
+        );
+
+        Self::base(&content, "An approved ad is overdue for publication.")
+    }
+
+    pub fn overdue_refund(data: &PublicationOverdueData) -> String {
+        let amount = format!("{:.2}", data.amount_cents as f64 / 100.0);
+        let currency = data.currency.to_uppercase();
+        let content = format!(
+            r##"
+<p class="greeting">Booking Update</p>
+<h1 class="headline">Your ad wasn't published, so we refunded you</h1>
+<p class="text">
+    Your ad placement in <strong>{newsletter_name}</strong> for <strong>{slot_date}</strong>
+    was not published, so we have refunded your payment in full.
+</p>
+
+<div class="highlight-box">
+    <div class="detail-grid">
+        <div class="detail-row">
+            <span class="detail-label">Ad</span>
+            <span class="detail-value">{ad_headline}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Refund Status</span>
+            <span class="detail-value"><span class="badge badge-info">Processing</span></span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Amount</span>
+            <span class="detail-value">${amount} {currency}</span>
+        </div>
+    </div>
+</div>
+
+<p class="text">
+    Please allow 5-10 business days for the funds to appear in your account, depending on
+    your payment method.
+</p>
+
+<div class="btn-wrapper">
+    <a href="{dashboard_url}" class="btn btn-primary">View Bookings</a>
+</div>
+"##,
+            newsletter_name = data.newsletter_name,
+            slot_date = data.slot_date,
+            ad_headline = data.ad_headline,
+            amount = amount,
+            currency = currency,
+            dashboard_url = data.dashboard_url
+        );
+
+        Self::base(
+            &content,
+            "Your ad wasn't published. A full refund is on its way.",
+        )
+    }
+
+    pub fn changes_requested(data: &ChangesRequestedData) -> String {
+        let content = format!(
+            r##"
+<p class="greeting">Changes Requested</p>
+<h1 class="headline">Your ad needs a few edits</h1>
+<p class="text">
+    The owner of <strong>{newsletter_name}</strong> reviewed your ad for
+    <strong>{slot_date}</strong> and asked for some changes before approving it.
+</p>
+
+<div class="highlight-box warning">
+    <p style="font-weight: 600; margin-bottom: 8px;">Feedback:</p>
+    <p style="color: #64748b;">{feedback}</p>
+</div>
+
+<div class="highlight-box">
+    <div class="detail-grid">
+        <div class="detail-row">
+            <span class="detail-label">Ad</span>
+            <span class="detail-value">{ad_headline}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Publish Date</span>
+            <span class="detail-value">{slot_date}</span>
+        </div>
+    </div>
+</div>
+
+<p class="text">
+    Your payment and slot are safe. Edit the ad and resubmit it for review.
+</p>
+
+<div class="btn-wrapper">
+    <a href="{dashboard_url}" class="btn btn-primary">Edit Ad</a>
+</div>
+"##,
+            newsletter_name = data.newsletter_name,
+            slot_date = data.slot_date,
+            feedback = data.feedback,
+            ad_headline = data.ad_headline,
+            dashboard_url = data.dashboard_url
+        );
+
+        Self::base(
+            &content,
+            "The newsletter owner asked for changes to your ad.",
+        )
+    }
+
+    pub fn ad_resubmitted(data: &AdResubmittedData) -> String {
+        let content = format!(
+            r##"
+<p class="greeting">Ad Resubmitted</p>
+<h1 class="headline">{sponsor_name} updated their ad</h1>
+<p class="text">
+    <strong>{sponsor_name}</strong> made the changes you asked for and resubmitted their ad
+    for <strong>{slot_date}</strong>. It is back in your queue for review.
+</p>
+
+<div class="highlight-box">
+    <div class="detail-grid">
+        <div class="detail-row">
+            <span class="detail-label">Ad</span>
+            <span class="detail-value">{ad_headline}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Publish Date</span>
+            <span class="detail-value">{slot_date}</span>
+        </div>
+    </div>
+</div>
+
+<div class="btn-wrapper">
+    <a href="{dashboard_url}" class="btn btn-primary">Review Ad</a>
+</div>
+"##,
+            sponsor_name = data.sponsor_name,
+            slot_date = data.slot_date,
+            ad_headline = data.ad_headline,
+            dashboard_url = data.dashboard_url
+        );
+
+        Self::base(&content, "A sponsor resubmitted their ad for review.")
+    }
+
+    pub fn reschedule_proposed(data: &RescheduleProposedData) -> String {
+        let reason_section = if let Some(reason) = &data.reason {
+            format!(
+                r##"
+<div class="highlight-box warning">
+    <p style="font-weight: 600; margin-bottom: 8px;">Reason provided:</p>
+    <p style="color: #64748b;">{}</p>
+</div>
+"##,
+                reason
+            )
+        } else {
+            String::new()
+        };
+
+        let content = format!(
+            r##"
+<p class="greeting">Reschedule Request</p>
+<h1 class="headline">{proposed_by} would like to move a booking</h1>
+<p class="text">
+    <strong>{proposed_by}</strong> proposed moving the ad placement in
+    <strong>{newsletter_name}</strong> to a different date.
+</p>
+
+<div class="highlight-box">
+    <div class="detail-grid">
+        <div class="detail-row">
+            <span class="detail-label">Current Date</span>
+            <span class="detail-value">{current_date}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Proposed Date</span>
+            <span class="detail-value">{proposed_date}</span>
+        </div>
+    </div>
+</div>
+
+{reason_section}
+
+<p class="text">
+    The booking stays on its current date unless you accept the proposal.
+</p>
+
+<div class="btn-wrapper">
+    <a href="{dashboard_url}" class="btn btn-primary">Review Proposal</a>
+</div>
+"##,
+            proposed_by = data.proposed_by_name,
+            newsletter_name = data.newsletter_name,
+            current_date = data.current_date,
+            proposed_date = data.proposed_date,
+            reason_section = reason_section,
+            dashboard_url = data.dashboard_url
+        );
+
+        Self::base(&content, "A new date has been proposed for your booking.")
+    }
+
+    pub fn reschedule_response(data: &RescheduleResponseData) -> String {
+        let (headline, text) = if data.accepted {
+            (
+                "Your booking has been rescheduled",
+                "Your proposal was accepted and the booking has been moved to its new date.",
+            )
+        } else {
+            (
+                "Your reschedule proposal was declined",
+                "Your proposal was declined, so the booking stays on its current date.",
+            )
+        };
+
+        let content = format!(
+            r##"
+<p class="greeting">Reschedule Update</p>
+<h1 class="headline">{headline}</h1>
+<p class="text">{text}</p>
+
+<div class="highlight-box">
+    <div class="detail-grid">
+        <div class="detail-row">
+            <span class="detail-label">Newsletter</span>
+            <span class="detail-value">{newsletter_name}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Original Date</span>
+            <span class="detail-value">{current_date}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Proposed Date</span>
+            <span class="detail-value">{proposed_date}</span>
+        </div>
+    </div>
+</div>
+
+<div class="btn-wrapper">
+    <a href="{dashboard_url}" class="btn btn-primary">View Booking</a>
+</div>
+"##,
+            headline = headline,
+            text = text,
+            newsletter_name = data.newsletter_name,
+            current_date = data.current_date,
+            proposed_date = data.proposed_date,
+            dashboard_url = data.dashboard_url
+        );
+
+        Self::base(&content, headline)
+    }
+
+    pub fn booking_message(data: &BookingMessageData) -> String {
+        let content = format!(
+            r##"
+<p class="greeting">New Message</p>
+<h1 class="headline">{sender_name} sent you a message</h1>
+<p class="text">
+    <strong>{sender_name}</strong> wrote about the <strong>{newsletter_name}</strong>
+    placement on <strong>{slot_date}</strong>.
+</p>
+
+<div class="highlight-box">
+    <p style="color: #64748b; white-space: pre-line;">{message}</p>
+</div>
+
+<div class="btn-wrapper">
+    <a href="{dashboard_url}" class="btn btn-primary">Reply</a>
+</div>
+"##,
+            sender_name = data.sender_name,
+            newsletter_name = data.newsletter_name,
+            slot_date = data.slot_date,
+            message = data.message,
+            dashboard_url = data.dashboard_url
+        );
+
+        Self::base(&content, &format!("New message from {}", data.sender_name))
+    }
+
+    pub fn waitlist_offer(data: &WaitlistOfferData) -> String {
+        let price = format!("{:.2}", data.price_cents as f64 / 100.0);
+
+        let content = format!(
+            r##"
+<p class="greeting">Good News</p>
+<h1 class="headline">A slot opened up in {newsletter_name}</h1>
+<p class="text">
+    The <strong>{placement_name}</strong> slot on <strong>{slot_date}</strong> you were
+    waiting for is available, and we're holding it for you.
+</p>
+
+<div class="highlight-box">
+    <div class="detail-grid">
+        <div class="detail-row">
+            <span class="detail-label">Slot Date</span>
+            <span class="detail-value">{slot_date}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Placement</span>
+            <span class="detail-value">{placement_name}</span>
+        </div>
+        <div class="detail-row">
+            <span class="detail-label">Price</span>
+            <span class="detail-value">${price} {currency}</span>
+        </div>
+    </div>
+</div>
+
+<div class="highlight-box warning">
+    <p style="color: #64748b;">
+        This offer is reserved for you until <strong>{expires_at}</strong>. After that the
+        slot goes to the next sponsor on the waitlist.
+    </p>
+</div>
+
+<div class="btn-wrapper">
+    <a href="{offer_url}" class="btn btn-primary">Book This Slot</a>
+</div>
+"##,
+            newsletter_name = data.newsletter_name,
+            placement_name = data.placement_name,
+            slot_date = data.slot_date,
+            price = price,
+            currency = data.currency.to_uppercase(),
+            expires_at = data.expires_at,
+            offer_url = data.offer_url
+        );
+
+        Self::base(&content, "A slot you were waiting for is available.")
+    }
 }
@@ -40,6 +40,53 @@ pub struct BookingRejectedData {
     pub reason: Option<String>,
 }
 
+#[derive(Debug)]
+pub struct ChangesRequestedData {
+    pub newsletter_name: String,
+    pub slot_date: String,
+    pub ad_headline: String,
+    pub feedback: String,
+    pub dashboard_url: String,
+}
+
+#[derive(Debug)]
+pub struct AdResubmittedData {
+    pub sponsor_name: String,
+    pub slot_date: String,
+    pub ad_headline: String,
+    pub dashboard_url: String,
+}
+
+#[derive(Debug)]
+pub struct RescheduleProposedData {
+    pub newsletter_name: String,
+    pub proposed_by_name: String,
+    pub current_date: String,
+    pub proposed_date: String,
+    pub reason: Option<String>,
+    pub dashboard_url: String,
+}
+
+#[derive(Debug)]
+pub struct RescheduleResponseData {
+    pub newsletter_name: String,
+    pub current_date: String,
+    pub proposed_date: String,
+    pub accepted: bool,
+    pub dashboard_url: String,
+}
+
+#[derive(Debug)]
+pub struct BookingCancelledData {
+    pub newsletter_name: String,
+    pub sponsor_name: String,
+    pub slot_date: String,
+    pub refund_cents: i32,
+    pub currency: String,
+    pub reason: Option<String>,
+    pub dashboard_url: String,
+}
+
 #[derive(Debug)]
 pub struct BookingPublishedData {
     pub newsletter_name: String,
@@ -49,6 +96,20 @@ pub struct BookingPublishedData {
     pub ad_body: String,
     pub ad_cta_text: Option<String>,
     pub ad_cta_url: String,
+    pub published_url: Option<String>,
+    pub publication_proof_url: Option<String>,
+    pub dashboard_url: String,
+}
+
+#[derive(Debug)]
+pub struct PublicationOverdueData {
+    pub newsletter_name: String,
+    pub sponsor_name: String,
+    pub slot_date: String,
+    pub ad_headline: String,
+    pub amount_cents: i32,
+    pub currency: String,
+    pub refund_date: String,
     pub dashboard_url: String,
 }
 
@@ -71,3 +132,23 @@ pub struct WelcomeData {
     pub is_writer: bool,
     pub dashboard_url: String,
 }
+
+#[derive(Debug)]
+pub struct BookingMessageData {
+    pub newsletter_name: String,
+    pub sender_name: String,
+    pub slot_date: String,
+    pub message: String,
+    pub dashboard_url: String,
+}
+
+#[derive(Debug)]
+pub struct WaitlistOfferData {
+    pub newsletter_name: String,
+    pub placement_name: String,
+    pub slot_date: String,
+    pub price_cents: i32,
+    pub currency: String,
+    pub expires_at: String,
+    pub offer_url: String,
+}
@@ -65,17 +65,22 @@ impl LemonSqueezyService {
     }
 
     pub async fn create_checkout(&self, params: CreateCheckoutParams) -> AppResult<CheckoutResult> {
-        let custom_data: HashMap<String, String> = [
+        let mut custom_data: HashMap<String, String> = [
             ("booking_id".to_string(), params.booking_id.clone()),
             ("writer_id".to_string(), params.writer_id.clone()),
             ("sponsor_id".to_string(), params.sponsor_id.clone()),
         ]
         .into();
+        if let Some(bundle_id) = &params.bundle_id {
+            custom_data.insert("bundle_id".to_string(), bundle_id.clone());
+        }
 
         let request_body = serde_json::json!({
             "data": {
                 "type": "checkouts",
                 "attributes": {
+                    // Charged in the store's currency; placements are priced in the
+                    // writer's, which the store must match
                     "custom_price": params.amount_cents,
                     "product_options": {
                         "name": format!("Ad Slot: {}", params.newsletter_name),
@@ -206,6 +211,37 @@ impl LemonSqueezyService {
         Ok(())
     }
 
+    pub async fn refund_order_amount(&self, order_id: &str, amount_cents: i64) -> AppResult<()> {
+        let request_body = serde_json::json!({
+            "data": {
+                "type": "orders",
+                "id": order_id,
+                "attributes": {
+                    "amount": amount_cents
+                }
+            }
+        });
+
+        let response = self
+            .client
+            .post(format!("{}/orders/{}/refund", API_BASE_URL, order_id))
+            .headers(self.auth_headers())
+            .json(&request_body)
+            .send()
+            .await
+            .map_err(|e| AppError::Internal(format!("Lemon Squeezy request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AppError::Internal(format!(
+                "Partial refund failed: {}",
+                error_text
+            )));
+        }
+
+        Ok(())
+    }
+
     pub fn verify_webhook(&self, payload: &[u8], signature: &str) -> AppResult<()> {
         let mut mac = HmacSha256::new_from_slice(self.config.webhook_secret.as_bytes())
             .map_err(|_| AppError::Internal("Invalid webhook secret".into()))?;
@@ -230,6 +266,7 @@ impl LemonSqueezyService {
 #[derive(Debug)]
 pub struct CreateCheckoutParams {
     pub booking_id: String,
+    pub bundle_id: Option<String>,
     pub writer_id: String,
     pub sponsor_id: String,
     pub sponsor_email: String,
@@ -300,6 +337,8 @@ pub struct OrderAttributes {
     pub status: String,
     pub status_formatted: String,
     pub refunded: bool,
+    #[serde(default)]
+    pub refunded_amount: i64,
     pub refunded_at: Option<String>,
     pub created_at: String,
     pub updated_at: String,
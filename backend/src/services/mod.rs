@@ -5,8 +5,10 @@ pub mod storage;
 
 pub use auth::{AuthService, AuthenticatedUser};
 pub use email::{
-    BookingConfirmationData, BookingPublishedData, BookingRejectedData, BookingStatusData,
-    EmailConfig, EmailService, NewBookingNotificationData,
+    AdResubmittedData, BookingCancelledData, BookingConfirmationData, BookingMessageData,
+    BookingPublishedData, BookingRejectedData, BookingStatusData, ChangesRequestedData,
+    EmailConfig, EmailService, NewBookingNotificationData, PublicationOverdueData,
+    RescheduleProposedData, RescheduleResponseData, WaitlistOfferData,
 };
 pub use lemonsqueezy::{
     CreateCheckoutParams, LemonSqueezyConfig, LemonSqueezyService, WebhookEvent,
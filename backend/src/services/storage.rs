@@ -154,6 +154,16 @@ impl CloudinaryService {
         self.upload("logos", filename, content_type, data).await
     }
 
+    pub async fn upload_publication_proof(
+        &self,
+        filename: &str,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> AppResult<UploadResult> {
+        self.upload("publication-proofs", filename, content_type, data)
+            .await
+    }
+
     fn validate_image(&self, content_type: &str, data: &[u8]) -> AppResult<()> {
         let allowed_types = ["image/jpeg", "image/png", "image/gif", "image/webp"];
         if !allowed_types.contains(&content_type) {
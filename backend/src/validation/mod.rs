@@ -1,4 +1,13 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::calendar::PublishingSchedule;
+use crate::directory;
 use crate::error::{AppError, AppResult};
+use crate::models::{
+    AdPlacement, BlackoutRecurrence, CreateBlackout, CreateManualBooking, CreatePricingRule,
+    CreateReview, PricingRuleKind,
+};
 
 pub const MIN_PASSWORD_LENGTH: usize = 8;
 pub const MAX_PASSWORD_LENGTH: usize = 128;
@@ -54,6 +63,8 @@ pub const MAX_HEADLINE_LENGTH: usize = 100;
 pub const MAX_BODY_LENGTH: usize = 500;
 pub const MAX_CTA_TEXT_LENGTH: usize = 50;
 pub const MAX_URL_LENGTH: usize = 2048;
+pub const MAX_FEEDBACK_LENGTH: usize = 2000;
+pub const MAX_MESSAGE_LENGTH: usize = 5000;
 
 pub fn sanitize_text(input: &str) -> String {
     input
@@ -65,6 +76,15 @@ pub fn sanitize_text(input: &str) -> String {
         .to_string()
 }
 
+/// Reverses `sanitize_text`, for output that is not HTML such as calendar feeds.
+pub fn unescape_text(input: &str) -> String {
+    input
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#x27;", "'")
+}
+
 pub fn validate_url(url: &str) -> AppResult<String> {
     let url = url.trim();
 
@@ -185,6 +205,722 @@ pub fn validate_booking_ad_content(
     })
 }
 
+pub fn validate_placement_copy(
+    content: &SanitizedBookingInput,
+    placement: &AdPlacement,
+) -> AppResult<()> {
+    if content.ad_headline.len() > placement.max_headline_length as usize {
+        return Err(AppError::Validation(format!(
+            "Ad headline exceeds the {} limit of {} characters",
+            placement.name, placement.max_headline_length
+        )));
+    }
+
+    if content.ad_body.len() > placement.max_body_length as usize {
+        return Err(AppError::Validation(format!(
+            "Ad body exceeds the {} limit of {} characters",
+            placement.name, placement.max_body_length
+        )));
+    }
+
+    if content.ad_image_url.is_some() && !placement.allows_image {
+        return Err(AppError::Validation(format!(
+            "{} ads cannot include an image",
+            placement.name
+        )));
+    }
+
+    Ok(())
+}
+
+pub const MAX_PLACEMENT_NAME_LENGTH: usize = 100;
+
+pub fn validate_placement_name(name: &str) -> AppResult<String> {
+    let sanitized = sanitize_text(name);
+
+    if sanitized.is_empty() {
+        return Err(AppError::Validation(
+            "Placement name cannot be empty".into(),
+        ));
+    }
+
+    if sanitized.len() > MAX_PLACEMENT_NAME_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Placement name exceeds maximum length of {} characters",
+            MAX_PLACEMENT_NAME_LENGTH
+        )));
+    }
+
+    Ok(sanitized)
+}
+
+pub const MAX_PLACEMENT_DESCRIPTION_LENGTH: usize = 500;
+
+pub fn validate_placement_description(description: &str) -> AppResult<String> {
+    let sanitized = sanitize_text(description);
+
+    if sanitized.len() > MAX_PLACEMENT_DESCRIPTION_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Placement description exceeds maximum length of {} characters",
+            MAX_PLACEMENT_DESCRIPTION_LENGTH
+        )));
+    }
+
+    Ok(sanitized)
+}
+
+/// Placement copy limits may only tighten the platform-wide ones.
+pub fn validate_placement_limits(max_headline_length: i32, max_body_length: i32) -> AppResult<()> {
+    if !(1..=MAX_HEADLINE_LENGTH as i32).contains(&max_headline_length) {
+        return Err(AppError::Validation(format!(
+            "Headline limit must be between 1 and {} characters",
+            MAX_HEADLINE_LENGTH
+        )));
+    }
+
+    if !(1..=MAX_BODY_LENGTH as i32).contains(&max_body_length) {
+        return Err(AppError::Validation(format!(
+            "Body limit must be between 1 and {} characters",
+            MAX_BODY_LENGTH
+        )));
+    }
+
+    Ok(())
+}
+
+pub fn validate_change_feedback(feedback: &str) -> AppResult<String> {
+    let sanitized = sanitize_text(feedback);
+
+    if sanitized.is_empty() {
+        return Err(AppError::Validation("Feedback cannot be empty".into()));
+    }
+
+    if sanitized.len() > MAX_FEEDBACK_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Feedback exceeds maximum length of {} characters",
+            MAX_FEEDBACK_LENGTH
+        )));
+    }
+
+    Ok(sanitized)
+}
+
+/// Blank reasons are treated as none.
+pub fn validate_reason(reason: Option<&str>) -> AppResult<Option<String>> {
+    let reason = reason.map(sanitize_text).filter(|r| !r.is_empty());
+
+    if reason
+        .as_ref()
+        .is_some_and(|r| r.len() > MAX_FEEDBACK_LENGTH)
+    {
+        return Err(AppError::Validation(format!(
+            "Reason exceeds maximum length of {} characters",
+            MAX_FEEDBACK_LENGTH
+        )));
+    }
+
+    Ok(reason)
+}
+
+pub fn validate_message_body(body: &str) -> AppResult<String> {
+    let sanitized = sanitize_text(body);
+
+    if sanitized.is_empty() {
+        return Err(AppError::Validation("Message cannot be empty".into()));
+    }
+
+    if sanitized.len() > MAX_MESSAGE_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Message exceeds maximum length of {} characters",
+            MAX_MESSAGE_LENGTH
+        )));
+    }
+
+    Ok(sanitized)
+}
+
+pub const MAX_PERFORMANCE_NOTE_LENGTH: usize = 500;
+
+pub fn validate_review(input: CreateReview) -> AppResult<CreateReview> {
+    if !(1..=5).contains(&input.rating) {
+        return Err(AppError::Validation(
+            "Rating must be between 1 and 5".into(),
+        ));
+    }
+
+    let body = sanitize_text(&input.body);
+    if body.is_empty() {
+        return Err(AppError::Validation("Review cannot be empty".into()));
+    }
+    if body.len() > MAX_FEEDBACK_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Review exceeds maximum length of {} characters",
+            MAX_FEEDBACK_LENGTH
+        )));
+    }
+
+    let performance_note = input
+        .performance_note
+        .as_deref()
+        .map(sanitize_text)
+        .filter(|n| !n.is_empty());
+    if performance_note
+        .as_ref()
+        .is_some_and(|n| n.len() > MAX_PERFORMANCE_NOTE_LENGTH)
+    {
+        return Err(AppError::Validation(format!(
+            "Performance note exceeds maximum length of {} characters",
+            MAX_PERFORMANCE_NOTE_LENGTH
+        )));
+    }
+
+    Ok(CreateReview {
+        rating: input.rating,
+        body,
+        performance_note,
+    })
+}
+
+pub fn validate_review_reply(reply: &str) -> AppResult<String> {
+    let reply = sanitize_text(reply);
+    if reply.is_empty() {
+        return Err(AppError::Validation("Reply cannot be empty".into()));
+    }
+    if reply.len() > MAX_FEEDBACK_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Reply exceeds maximum length of {} characters",
+            MAX_FEEDBACK_LENGTH
+        )));
+    }
+    Ok(reply)
+}
+
+pub fn validate_issue_weekdays(weekdays: &[i16]) -> AppResult<Vec<i16>> {
+    if weekdays.is_empty() {
+        return Err(AppError::Validation(
+            "At least one issue weekday is required".into(),
+        ));
+    }
+
+    if weekdays.iter().any(|d| !(1..=7).contains(d)) {
+        return Err(AppError::Validation(
+            "Issue weekdays must be between 1 (Monday) and 7 (Sunday)".into(),
+        ));
+    }
+
+    let mut weekdays = weekdays.to_vec();
+    weekdays.sort();
+    weekdays.dedup();
+    Ok(weekdays)
+}
+
+pub const MIN_SLUG_LENGTH: usize = 3;
+pub const MAX_SLUG_LENGTH: usize = 50;
+
+const RESERVED_SLUGS: &[&str] = &[
+    "about",
+    "account",
+    "admin",
+    "adsloty",
+    "api",
+    "app",
+    "auth",
+    "billing",
+    "blog",
+    "bookings",
+    "calendar",
+    "categories",
+    "checkout",
+    "dashboard",
+    "help",
+    "login",
+    "logout",
+    "me",
+    "new",
+    "newsletters",
+    "payouts",
+    "pricing",
+    "privacy",
+    "register",
+    "settings",
+    "signup",
+    "sponsors",
+    "static",
+    "status",
+    "support",
+    "terms",
+    "uploads",
+    "waitlist",
+    "webhooks",
+    "widget",
+    "writers",
+    "www",
+];
+
+const BLOCKED_SLUG_WORDS: &[&str] = &[
+    "anal", "bastard", "bitch", "bollocks", "cock", "cunt", "dick", "dildo", "fag", "faggot",
+    "fuck", "fucker", "fucking", "nigga", "nigger", "porn", "pussy", "rape", "retard", "shit",
+    "slut", "twat", "wank", "whore",
+];
+
+pub fn validate_slug(slug: &str) -> AppResult<String> {
+    let slug = slug.trim().to_lowercase();
+
+    if !(MIN_SLUG_LENGTH..=MAX_SLUG_LENGTH).contains(&slug.len()) {
+        return Err(AppError::Validation(format!(
+            "Slug must be between {} and {} characters",
+            MIN_SLUG_LENGTH, MAX_SLUG_LENGTH
+        )));
+    }
+
+    let well_formed = slug
+        .split('-')
+        .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric()));
+    if !well_formed {
+        return Err(AppError::Validation(
+            "Slug may only contain letters, digits and single hyphens between them".into(),
+        ));
+    }
+
+    // A slug that parses as an id would be looked up as one
+    if uuid::Uuid::parse_str(&slug).is_ok() {
+        return Err(AppError::Validation("Slug cannot be an id".into()));
+    }
+
+    if RESERVED_SLUGS.contains(&slug.as_str()) {
+        return Err(AppError::Validation(format!(
+            "'{}' is reserved; please choose another slug",
+            slug
+        )));
+    }
+
+    let compact = slug.replace('-', "");
+    if BLOCKED_SLUG_WORDS
+        .iter()
+        .any(|word| compact == *word || slug.split('-').any(|part| part == *word))
+    {
+        return Err(AppError::Validation(
+            "Slug contains language that is not allowed".into(),
+        ));
+    }
+
+    Ok(slug)
+}
+
+pub const MAX_CATEGORIES: usize = 3;
+pub const MAX_TAGS: usize = 10;
+pub const MAX_TAG_LENGTH: usize = 30;
+pub const MAX_AUDIENCE_COUNTRIES: usize = 10;
+pub const MAX_JOB_ROLES: usize = 10;
+pub const MAX_JOB_ROLE_LENGTH: usize = 50;
+
+pub fn validate_categories(categories: &[String]) -> AppResult<Vec<String>> {
+    let mut normalized: Vec<String> = Vec::new();
+    for category in categories {
+        let slug = category.trim().to_lowercase();
+        if !directory::is_category(&slug) {
+            return Err(AppError::Validation(format!(
+                "Unknown category '{}'",
+                category.trim()
+            )));
+        }
+        if !normalized.contains(&slug) {
+            normalized.push(slug);
+        }
+    }
+
+    if normalized.len() > MAX_CATEGORIES {
+        return Err(AppError::Validation(format!(
+            "A newsletter can have at most {} categories",
+            MAX_CATEGORIES
+        )));
+    }
+    Ok(normalized)
+}
+
+fn normalize_labels(
+    labels: &[String],
+    what: &str,
+    max_count: usize,
+    max_length: usize,
+) -> AppResult<Vec<String>> {
+    let mut normalized: Vec<String> = Vec::new();
+    for label in labels {
+        let label = label
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        if label.is_empty() || normalized.contains(&label) {
+            continue;
+        }
+        if label.chars().count() > max_length {
+            return Err(AppError::Validation(format!(
+                "Each {} must be at most {} characters",
+                what, max_length
+            )));
+        }
+        if !label
+            .chars()
+            .all(|c| c.is_alphanumeric() || " -&+./".contains(c))
+        {
+            return Err(AppError::Validation(format!(
+                "{} '{}' contains characters that are not allowed",
+                what, label
+            )));
+        }
+        normalized.push(label);
+    }
+
+    if normalized.len() > max_count {
+        return Err(AppError::Validation(format!(
+            "At most {} of {} are allowed",
+            max_count, what
+        )));
+    }
+    Ok(normalized)
+}
+
+pub fn validate_tags(tags: &[String]) -> AppResult<Vec<String>> {
+    normalize_labels(tags, "tag", MAX_TAGS, MAX_TAG_LENGTH)
+}
+
+pub fn validate_job_roles(job_roles: &[String]) -> AppResult<Vec<String>> {
+    normalize_labels(job_roles, "job role", MAX_JOB_ROLES, MAX_JOB_ROLE_LENGTH)
+}
+
+pub fn validate_audience_countries(countries: &[String]) -> AppResult<Vec<String>> {
+    let mut normalized: Vec<String> = Vec::new();
+    for country in countries {
+        let code = country.trim().to_uppercase();
+        if code.len() != 2 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(AppError::Validation(format!(
+                "'{}' is not a two-letter country code",
+                country.trim()
+            )));
+        }
+        if !normalized.contains(&code) {
+            normalized.push(code);
+        }
+    }
+
+    if normalized.len() > MAX_AUDIENCE_COUNTRIES {
+        return Err(AppError::Validation(format!(
+            "At most {} audience countries are allowed",
+            MAX_AUDIENCE_COUNTRIES
+        )));
+    }
+    Ok(normalized)
+}
+
+pub fn validate_open_rate(open_rate_pct: Decimal) -> AppResult<()> {
+    if open_rate_pct < Decimal::ZERO || open_rate_pct > Decimal::ONE_HUNDRED {
+        return Err(AppError::Validation(
+            "Open rate must be between 0 and 100 percent".into(),
+        ));
+    }
+    Ok(())
+}
+
+pub fn validate_issue_week_of_month(week_of_month: i16) -> AppResult<()> {
+    if !(-1..=4).contains(&week_of_month) {
+        return Err(AppError::Validation(
+            "Week of month must be 0 (every week), 1-4, or -1 (last)".into(),
+        ));
+    }
+    Ok(())
+}
+
+pub fn validate_timezone(timezone: &str) -> AppResult<String> {
+    let timezone = timezone.trim();
+    timezone
+        .parse::<chrono_tz::Tz>()
+        .map(|tz| tz.name().to_string())
+        .map_err(|_| {
+            AppError::Validation(format!(
+                "Unknown timezone '{}'; use an IANA name such as Europe/Berlin",
+                timezone
+            ))
+        })
+}
+
+/// Returned lowercase, as it is stored.
+pub fn validate_currency(currency: &str) -> AppResult<String> {
+    let currency = currency.trim().to_lowercase();
+    if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_lowercase()) {
+        return Err(AppError::Validation(
+            "Currency must be a three-letter code such as usd".into(),
+        ));
+    }
+    Ok(currency)
+}
+
+pub const MAX_BLACKOUT_REASON_LENGTH: usize = 255;
+
+/// Recurring rules must end before their next occurrence starts.
+pub fn validate_blackout(input: &CreateBlackout) -> AppResult<()> {
+    let end_date = input.end_date.unwrap_or(input.start_date);
+
+    if end_date < input.start_date {
+        return Err(AppError::Validation(
+            "Blackout end date must not be before its start date".into(),
+        ));
+    }
+
+    if input
+        .repeat_until
+        .is_some_and(|until| until < input.start_date)
+    {
+        return Err(AppError::Validation(
+            "Blackout cannot stop repeating before it starts".into(),
+        ));
+    }
+
+    let span_days = (end_date - input.start_date).num_days();
+
+    match input.recurrence {
+        BlackoutRecurrence::Once => {}
+        BlackoutRecurrence::Weekly => {
+            if !(1..=52).contains(&input.interval_weeks) {
+                return Err(AppError::Validation(
+                    "Weekly blackouts must repeat every 1 to 52 weeks".into(),
+                ));
+            }
+            if span_days >= 7 * input.interval_weeks as i64 {
+                return Err(AppError::Validation(
+                    "A weekly blackout must end before it repeats".into(),
+                ));
+            }
+        }
+        BlackoutRecurrence::Yearly => {
+            if span_days >= 365 {
+                return Err(AppError::Validation(
+                    "A yearly blackout must be shorter than a year".into(),
+                ));
+            }
+        }
+    }
+
+    if let Some(reason) = &input.reason {
+        if reason.trim().chars().count() > MAX_BLACKOUT_REASON_LENGTH {
+            return Err(AppError::Validation(format!(
+                "Reason exceeds maximum length of {} characters",
+                MAX_BLACKOUT_REASON_LENGTH
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+pub const MAX_PRICING_LABEL_LENGTH: usize = 100;
+
+/// Clears the fields that only other rule kinds use.
+pub fn validate_pricing_rule(input: CreatePricingRule) -> AppResult<CreatePricingRule> {
+    let label = match input.label.as_deref().map(|l| sanitize_text(l.trim())) {
+        Some(label) if label.len() > MAX_PRICING_LABEL_LENGTH => {
+            return Err(AppError::Validation(format!(
+                "Label exceeds maximum length of {} characters",
+                MAX_PRICING_LABEL_LENGTH
+            )))
+        }
+        Some(label) if !label.is_empty() => Some(label),
+        _ => None,
+    };
+
+    let mut rule = CreatePricingRule {
+        kind: input.kind,
+        placement_id: input.placement_id,
+        label,
+        rule_date: None,
+        price_cents: None,
+        min_days_ahead: None,
+        max_days_ahead: None,
+        adjustment_pct: None,
+        weekday: None,
+        multiplier_pct: None,
+    };
+
+    match input.kind {
+        PricingRuleKind::DateOverride => {
+            let (Some(rule_date), Some(price_cents)) = (input.rule_date, input.price_cents) else {
+                return Err(AppError::Validation(
+                    "A date override needs a rule_date and a price_cents".into(),
+                ));
+            };
+            if price_cents <= 0 {
+                return Err(AppError::Validation("Price must be greater than 0".into()));
+            }
+            rule.rule_date = Some(rule_date);
+            rule.price_cents = Some(price_cents);
+        }
+        PricingRuleKind::LeadTime => {
+            let (Some(min_days), Some(adjustment)) = (input.min_days_ahead, input.adjustment_pct)
+            else {
+                return Err(AppError::Validation(
+                    "A lead time rule needs a min_days_ahead and an adjustment_pct".into(),
+                ));
+            };
+            if min_days < 0 || input.max_days_ahead.is_some_and(|max| max < min_days) {
+                return Err(AppError::Validation(
+                    "Lead time days must be a non-negative range".into(),
+                ));
+            }
+            if !(-90..=500).contains(&adjustment) {
+                return Err(AppError::Validation(
+                    "Lead time adjustment must be between -90% and +500%".into(),
+                ));
+            }
+            rule.min_days_ahead = Some(min_days);
+            rule.max_days_ahead = input.max_days_ahead;
+            rule.adjustment_pct = Some(adjustment);
+        }
+        PricingRuleKind::Weekday => {
+            let (Some(weekday), Some(multiplier)) = (input.weekday, input.multiplier_pct) else {
+                return Err(AppError::Validation(
+                    "A weekday rule needs a weekday and a multiplier_pct".into(),
+                ));
+            };
+            if !(1..=7).contains(&weekday) {
+                return Err(AppError::Validation(
+                    "Weekday must be between 1 (Monday) and 7 (Sunday)".into(),
+                ));
+            }
+            if !(10..=1000).contains(&multiplier) {
+                return Err(AppError::Validation(
+                    "Weekday multiplier must be between 10% and 1000%".into(),
+                ));
+            }
+            rule.weekday = Some(weekday);
+            rule.multiplier_pct = Some(multiplier);
+        }
+    }
+
+    Ok(rule)
+}
+
+pub const MAX_SPONSOR_NAME_LENGTH: usize = 255;
+
+pub fn validate_manual_booking(input: CreateManualBooking) -> AppResult<CreateManualBooking> {
+    let sponsor_name = sanitize_text(input.sponsor_name.trim());
+    if sponsor_name.is_empty() {
+        return Err(AppError::Validation("Sponsor name is required".into()));
+    }
+    if sponsor_name.len() > MAX_SPONSOR_NAME_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Sponsor name exceeds maximum length of {} characters",
+            MAX_SPONSOR_NAME_LENGTH
+        )));
+    }
+
+    if input.amount_cents.is_some_and(|amount| amount < 0) {
+        return Err(AppError::Validation("Amount must not be negative".into()));
+    }
+
+    let notes = input
+        .notes
+        .as_deref()
+        .map(|n| sanitize_text(n.trim()))
+        .filter(|n| !n.is_empty());
+    if notes
+        .as_ref()
+        .is_some_and(|n| n.len() > MAX_FEEDBACK_LENGTH)
+    {
+        return Err(AppError::Validation(format!(
+            "Notes exceed maximum length of {} characters",
+            MAX_FEEDBACK_LENGTH
+        )));
+    }
+
+    Ok(CreateManualBooking {
+        sponsor_name,
+        notes,
+        ..input
+    })
+}
+
+pub const MAX_LIST_NAME_LENGTH: usize = 100;
+pub const MAX_LIST_NOTE_LENGTH: usize = 1000;
+pub const MAX_LIST_MEMBERS: i64 = 100;
+
+pub fn validate_saved_list_name(name: &str) -> AppResult<String> {
+    let name = sanitize_text(name);
+    if name.is_empty() {
+        return Err(AppError::Validation("List name is required".into()));
+    }
+    if name.len() > MAX_LIST_NAME_LENGTH {
+        return Err(AppError::Validation(format!(
+            "List name exceeds maximum length of {} characters",
+            MAX_LIST_NAME_LENGTH
+        )));
+    }
+    Ok(name)
+}
+
+/// Blank text comes back empty, which clears the note.
+pub fn validate_saved_list_note(note: &str) -> AppResult<String> {
+    let note = sanitize_text(note);
+    if note.len() > MAX_LIST_NOTE_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Note exceeds maximum length of {} characters",
+            MAX_LIST_NOTE_LENGTH
+        )));
+    }
+    Ok(note)
+}
+
+pub const MAX_BUNDLE_DATES: usize = 12;
+
+/// Either `slot_date`, explicit `slot_dates`, or the next `issue_count` issues from `slot_date`.
+pub fn validate_slot_dates(
+    slot_date: Option<NaiveDate>,
+    slot_dates: Option<&[NaiveDate]>,
+    issue_count: Option<u32>,
+    schedule: &PublishingSchedule,
+) -> AppResult<Vec<NaiveDate>> {
+    let mut dates = match (slot_date, slot_dates, issue_count) {
+        (Some(date), None, None) => vec![date],
+        (None, Some(dates), None) => dates.to_vec(),
+        (Some(start), None, Some(count)) => {
+            // Capped one past the limit so oversized requests still fail below
+            let count = count.min(MAX_BUNDLE_DATES as u32 + 1) as usize;
+            let dates = schedule.next_issue_dates(start, count);
+            if dates.len() < count {
+                return Err(AppError::Validation(
+                    "Not enough upcoming issues for the requested issue_count".into(),
+                ));
+            }
+            dates
+        }
+        _ => {
+            return Err(AppError::Validation(
+                "Provide a slot_date, a list of slot_dates, or a slot_date with an issue_count"
+                    .into(),
+            ))
+        }
+    };
+
+    if dates.is_empty() {
+        return Err(AppError::Validation(
+            "At least one slot date is required".into(),
+        ));
+    }
+
+    if dates.len() > MAX_BUNDLE_DATES {
+        return Err(AppError::Validation(format!(
+            "Cannot book more than {} dates at once",
+            MAX_BUNDLE_DATES
+        )));
+    }
+
+    dates.sort();
+    if dates.windows(2).any(|pair| pair[0] == pair[1]) {
+        return Err(AppError::Validation("Slot dates must be unique".into()));
+    }
+
+    Ok(dates)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,6 +938,12 @@ mod tests {
         assert_eq!(sanitize_text("Hello World"), "Hello World");
     }
 
+    #[test]
+    fn test_unescape_text_reverses_sanitize_text() {
+        let text = r#"Writer's "Deep Dive" <weekly>"#;
+        assert_eq!(unescape_text(&sanitize_text(text)), text);
+    }
+
     #[test]
     fn test_validate_url_rejects_javascript() {
         assert!(validate_url("javascript:alert('xss')").is_err());
@@ -276,4 +1018,334 @@ mod tests {
         assert!(validate_password("MyP@ssw0rd").is_ok());
         assert!(validate_password("Str0ng#Pass").is_ok());
     }
+
+    #[test]
+    fn test_validate_change_feedback() {
+        assert!(validate_change_feedback("   ").is_err());
+        assert!(validate_change_feedback(&"a".repeat(MAX_FEEDBACK_LENGTH + 1)).is_err());
+        assert_eq!(
+            validate_change_feedback(" Please shorten the headline ").unwrap(),
+            "Please shorten the headline"
+        );
+    }
+
+    #[test]
+    fn test_validate_placement_copy() {
+        let placement = AdPlacement {
+            id: uuid::Uuid::nil(),
+            writer_id: uuid::Uuid::nil(),
+            name: "Classified".into(),
+            description: None,
+            price_cents: 2500,
+            currency: "usd".into(),
+            slots_per_issue: 3,
+            max_headline_length: 10,
+            max_body_length: 200,
+            allows_image: false,
+            is_active: true,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        };
+        let content =
+            validate_booking_ad_content("Short one", "Body", None, "https://example.com", None)
+                .unwrap();
+        assert!(validate_placement_copy(&content, &placement).is_ok());
+
+        let long = SanitizedBookingInput {
+            ad_headline: "A longer headline".into(),
+            ..content
+        };
+        assert!(validate_placement_copy(&long, &placement).is_err());
+
+        let with_image = SanitizedBookingInput {
+            ad_headline: "Short one".into(),
+            ad_image_url: Some("https://example.com/a.png".into()),
+            ..long
+        };
+        assert!(validate_placement_copy(&with_image, &placement).is_err());
+    }
+
+    #[test]
+    fn test_validate_timezone() {
+        assert_eq!(
+            validate_timezone(" Australia/Sydney ").unwrap(),
+            "Australia/Sydney"
+        );
+        assert!(validate_timezone("PST8PDT").is_ok());
+        assert!(validate_timezone("Mars/Olympus_Mons").is_err());
+    }
+
+    #[test]
+    fn test_validate_currency() {
+        assert_eq!(validate_currency(" EUR ").unwrap(), "eur");
+        assert!(validate_currency("usdt").is_err());
+        assert!(validate_currency("u$d").is_err());
+        assert!(validate_currency("").is_err());
+    }
+
+    #[test]
+    fn test_validate_placement_description() {
+        assert_eq!(
+            validate_placement_description(" Top of the <issue> ").unwrap(),
+            "Top of the &lt;issue&gt;"
+        );
+        assert!(
+            validate_placement_description(&"a".repeat(MAX_PLACEMENT_DESCRIPTION_LENGTH + 1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_blackout() {
+        let rule = |start: &str, end: &str, recurrence, interval_weeks| CreateBlackout {
+            start_date: date(start),
+            end_date: Some(date(end)),
+            recurrence,
+            interval_weeks,
+            repeat_until: None,
+            reason: None,
+        };
+
+        assert!(validate_blackout(&rule(
+            "2025-12-20",
+            "2026-01-03",
+            BlackoutRecurrence::Yearly,
+            1
+        ))
+        .is_ok());
+        assert!(validate_blackout(&rule(
+            "2025-03-07",
+            "2025-03-07",
+            BlackoutRecurrence::Weekly,
+            2
+        ))
+        .is_ok());
+        assert!(validate_blackout(&rule(
+            "2025-03-10",
+            "2025-03-07",
+            BlackoutRecurrence::Once,
+            1
+        ))
+        .is_err());
+        assert!(validate_blackout(&rule(
+            "2025-03-03",
+            "2025-03-10",
+            BlackoutRecurrence::Weekly,
+            1
+        ))
+        .is_err());
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn mondays() -> PublishingSchedule {
+        PublishingSchedule::new(&[1], 0)
+    }
+
+    #[test]
+    fn test_validate_pricing_rule() {
+        let rule = |kind| CreatePricingRule {
+            kind,
+            placement_id: None,
+            label: Some("  Holiday  ".into()),
+            rule_date: Some(NaiveDate::from_ymd_opt(2025, 12, 24).unwrap()),
+            price_cents: Some(20_000),
+            min_days_ahead: None,
+            max_days_ahead: None,
+            adjustment_pct: None,
+            weekday: Some(3),
+            multiplier_pct: Some(150),
+        };
+
+        let date_override = validate_pricing_rule(rule(PricingRuleKind::DateOverride)).unwrap();
+        assert_eq!(date_override.label.as_deref(), Some("Holiday"));
+        assert_eq!(date_override.weekday, None);
+
+        let weekday = validate_pricing_rule(rule(PricingRuleKind::Weekday)).unwrap();
+        assert_eq!(weekday.rule_date, None);
+        assert_eq!(weekday.multiplier_pct, Some(150));
+
+        assert!(validate_pricing_rule(rule(PricingRuleKind::LeadTime)).is_err());
+
+        let mut backwards = rule(PricingRuleKind::LeadTime);
+        backwards.min_days_ahead = Some(14);
+        backwards.max_days_ahead = Some(7);
+        backwards.adjustment_pct = Some(-10);
+        assert!(validate_pricing_rule(backwards).is_err());
+    }
+
+    #[test]
+    fn test_validate_review() {
+        let review = |rating, body: &str| CreateReview {
+            rating,
+            body: body.into(),
+            performance_note: Some("  ".into()),
+        };
+
+        let valid = validate_review(review(5, " Great <b>results</b> ")).unwrap();
+        assert_eq!(valid.body, "Great &lt;b&gt;results&lt;/b&gt;");
+        assert_eq!(valid.performance_note, None);
+
+        assert!(validate_review(review(0, "Fine")).is_err());
+        assert!(validate_review(review(6, "Fine")).is_err());
+        assert!(validate_review(review(3, "   ")).is_err());
+    }
+
+    #[test]
+    fn test_validate_slug() {
+        assert_eq!(
+            validate_slug("  The-Daily-Byte ").unwrap(),
+            "the-daily-byte"
+        );
+        assert!(validate_slug("ab").is_err());
+        assert!(validate_slug("daily--byte").is_err());
+        assert!(validate_slug("-daily").is_err());
+        assert!(validate_slug("daily_byte").is_err());
+        assert!(validate_slug("me").is_err());
+        assert!(validate_slug("admin").is_err());
+        assert!(validate_slug("holy-shit-news").is_err());
+        assert!(validate_slug("s-h-i-t").is_err());
+        // Only whole words are blocked
+        assert!(validate_slug("scunthorpe-weekly").is_ok());
+        assert!(validate_slug("0194e7a2-0000-7000-8000-000000000000").is_err());
+    }
+
+    #[test]
+    fn test_validate_newsletter_profile() {
+        let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            validate_categories(&strings(&[" Software", "software", "design"])).unwrap(),
+            strings(&["software", "design"])
+        );
+        assert!(validate_categories(&strings(&["astrology"])).is_err());
+        assert!(validate_categories(&strings(&["business", "design", "finance", "news"])).is_err());
+
+        assert_eq!(
+            validate_tags(&strings(&[
+                "  Machine   Learning ",
+                "machine learning",
+                "",
+                "C++"
+            ]))
+            .unwrap(),
+            strings(&["machine learning", "c++"])
+        );
+        assert!(validate_tags(&strings(&["<script>"])).is_err());
+        assert!(validate_job_roles(&strings(&[&"x".repeat(MAX_JOB_ROLE_LENGTH + 1)])).is_err());
+
+        assert_eq!(
+            validate_audience_countries(&strings(&["us", " DE ", "US"])).unwrap(),
+            strings(&["US", "DE"])
+        );
+        assert!(validate_audience_countries(&strings(&["USA"])).is_err());
+
+        assert!(validate_open_rate(Decimal::new(425, 1)).is_ok());
+        assert!(validate_open_rate(Decimal::new(1001, 1)).is_err());
+    }
+
+    #[test]
+    fn test_validate_manual_booking() {
+        let booking = |sponsor_name: &str, amount_cents| CreateManualBooking {
+            placement_id: None,
+            slot_date: date("2025-03-03"),
+            sponsor_name: sponsor_name.into(),
+            amount_cents,
+            notes: Some("   ".into()),
+        };
+
+        let valid = validate_manual_booking(booking("  Acme  ", Some(50_000))).unwrap();
+        assert_eq!(valid.sponsor_name, "Acme");
+        assert_eq!(valid.notes, None);
+
+        assert!(validate_manual_booking(booking("Acme", None)).is_ok());
+        assert!(validate_manual_booking(booking("   ", None)).is_err());
+        assert!(validate_manual_booking(booking("Acme", Some(-1))).is_err());
+    }
+
+    #[test]
+    fn test_validate_reason() {
+        assert_eq!(
+            validate_reason(Some("<b>Plans changed</b>"))
+                .unwrap()
+                .as_deref(),
+            Some("&lt;b&gt;Plans changed&lt;/b&gt;")
+        );
+        assert_eq!(validate_reason(Some("   ")).unwrap(), None);
+        assert_eq!(validate_reason(None).unwrap(), None);
+        assert!(validate_reason(Some(&"a".repeat(MAX_FEEDBACK_LENGTH + 1))).is_err());
+    }
+
+    #[test]
+    fn test_validate_message_body() {
+        assert_eq!(
+            validate_message_body("  Can we move to <Friday>?  ").unwrap(),
+            "Can we move to &lt;Friday&gt;?"
+        );
+        assert!(validate_message_body("   ").is_err());
+        assert!(validate_message_body(&"a".repeat(MAX_MESSAGE_LENGTH)).is_ok());
+        assert!(validate_message_body(&"a".repeat(MAX_MESSAGE_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_validate_saved_list() {
+        assert_eq!(
+            validate_saved_list_name("  Q3 <tech>  ").unwrap(),
+            "Q3 &lt;tech&gt;"
+        );
+        assert!(validate_saved_list_name("   ").is_err());
+        assert!(validate_saved_list_name(&"a".repeat(MAX_LIST_NAME_LENGTH + 1)).is_err());
+
+        assert_eq!(validate_saved_list_note("  ").unwrap(), "");
+        assert!(validate_saved_list_note(&"a".repeat(MAX_LIST_NOTE_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_validate_slot_dates_issue_count() {
+        let dates =
+            validate_slot_dates(Some(date("2025-03-03")), None, Some(3), &mondays()).unwrap();
+        assert_eq!(
+            dates,
+            vec![date("2025-03-03"), date("2025-03-10"), date("2025-03-17")]
+        );
+
+        // Follows the schedule rather than a fixed weekly step
+        let tue_fri = PublishingSchedule::new(&[2, 5], 0);
+        let dates = validate_slot_dates(Some(date("2025-03-04")), None, Some(3), &tue_fri).unwrap();
+        assert_eq!(
+            dates,
+            vec![date("2025-03-04"), date("2025-03-07"), date("2025-03-11")]
+        );
+    }
+
+    #[test]
+    fn test_validate_slot_dates_list_is_sorted_and_unique() {
+        let dates = validate_slot_dates(
+            None,
+            Some(&[date("2025-03-17"), date("2025-03-03")]),
+            None,
+            &mondays(),
+        )
+        .unwrap();
+        assert_eq!(dates, vec![date("2025-03-03"), date("2025-03-17")]);
+
+        assert!(validate_slot_dates(
+            None,
+            Some(&[date("2025-03-03"), date("2025-03-03")]),
+            None,
+            &mondays()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_slot_dates_rejects_ambiguous_or_oversized_requests() {
+        let schedule = mondays();
+        assert!(validate_slot_dates(None, None, None, &schedule).is_err());
+        assert!(validate_slot_dates(Some(date("2025-03-03")), Some(&[]), None, &schedule).is_err());
+        assert!(validate_slot_dates(Some(date("2025-03-03")), None, Some(0), &schedule).is_err());
+        assert!(validate_slot_dates(Some(date("2025-03-03")), None, Some(13), &schedule).is_err());
+    }
 }
@@ -0,0 +1,81 @@
+use crate::models::{WaitlistEntry, WaitlistStatus};
+
+/// Open offers hold a slot each, so every slot still remaining gets one.
+pub fn offers_to_make(remaining: i32) -> usize {
+    remaining.max(0) as usize
+}
+
+pub fn next_in_line(queue: &[WaitlistEntry], count: usize) -> Vec<&WaitlistEntry> {
+    let mut waiting: Vec<&WaitlistEntry> = queue
+        .iter()
+        .filter(|entry| entry.status == WaitlistStatus::Waiting)
+        .collect();
+    waiting.sort_by_key(|entry| (entry.created_at, entry.id));
+    waiting.truncate(count);
+    waiting
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration, NaiveDate, Utc};
+    use uuid::Uuid;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn entry(n: u128, joined: DateTime<Utc>, status: WaitlistStatus) -> WaitlistEntry {
+        WaitlistEntry {
+            id: Uuid::from_u128(n),
+            writer_id: Uuid::nil(),
+            placement_id: Uuid::nil(),
+            sponsor_id: Uuid::from_u128(100 + n),
+            slot_date: date("2025-03-04"),
+            status,
+            offered_at: None,
+            offer_expires_at: None,
+            claimed_booking_id: None,
+            created_at: joined,
+            updated_at: joined,
+        }
+    }
+
+    #[test]
+    fn test_one_offer_per_open_slot() {
+        assert_eq!(offers_to_make(2), 2);
+        assert_eq!(offers_to_make(0), 0);
+        // Overbooked after a capacity cut
+        assert_eq!(offers_to_make(-1), 0);
+    }
+
+    #[test]
+    fn test_queue_is_first_come_first_served() {
+        let now = Utc::now();
+        let queue = vec![
+            entry(1, now, WaitlistStatus::Waiting),
+            entry(2, now - Duration::hours(2), WaitlistStatus::Waiting),
+            entry(3, now - Duration::hours(1), WaitlistStatus::Waiting),
+        ];
+
+        let next: Vec<Uuid> = next_in_line(&queue, 2).iter().map(|e| e.id).collect();
+        assert_eq!(next, vec![queue[1].id, queue[2].id]);
+        assert_eq!(next_in_line(&queue, 5).len(), 3);
+    }
+
+    #[test]
+    fn test_lapsed_offer_rolls_on_to_the_next_sponsor() {
+        let now = Utc::now();
+        // The first sponsor let their offer expire, which freed the slot again
+        let queue = vec![
+            entry(4, now - Duration::days(3), WaitlistStatus::Expired),
+            entry(5, now - Duration::days(2), WaitlistStatus::Claimed),
+            entry(6, now - Duration::days(1), WaitlistStatus::Waiting),
+            entry(7, now, WaitlistStatus::Waiting),
+        ];
+
+        let next = next_in_line(&queue, offers_to_make(1));
+        assert_eq!(next.len(), 1);
+        assert_eq!(next[0].id, queue[2].id);
+    }
+}